@@ -31,6 +31,7 @@ fn main() {
         "deprecated" => v01_deprecated_api,
         "panicking-api" => panicking_api,
         "rand" => rand,
+        "arbitrary" => arbitrary,
         "serde" => serde,
         "__doc" => docs,
     };
@@ -75,6 +76,11 @@ fn main() {
         cfg_emit!(supports_non_exhaustive);
     }
 
+    // `if` and `match` are permitted in `const fn` beginning in 1.46.0.
+    if rustc::is_min_version("1.46.0").unwrap_or(false) {
+        cfg_emit!(const_if_match);
+    }
+
     // `(-5).abs()` is `const`-capable beginning in 1.39.0.
     if rustc::is_min_version("1.39.0").unwrap_or(false) {
         cfg_emit!(const_num_abs);
@@ -0,0 +1,82 @@
+//! Implementation of `Arbitrary` for various structs.
+
+use crate::internal_prelude::*;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for Duration {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        /// Values that are particularly likely to expose bugs.
+        const EDGE_CASES: [Duration; 9] = [
+            Duration::zero(),
+            Duration::nanosecond(),
+            Duration::nanoseconds(-1),
+            Duration::nanoseconds(999_999_999),
+            Duration::nanoseconds(-999_999_999),
+            Duration::second(),
+            Duration::seconds(-1),
+            Duration::max_value(),
+            Duration::min_value(),
+        ];
+
+        if u.ratio(1, 4)? {
+            return Ok(*u.choose(&EDGE_CASES)?);
+        }
+
+        let seconds: i64 = u.arbitrary()?;
+        let nanoseconds: i32 = u.int_in_range(0..=999_999_999)?;
+        // A zero number of seconds can have subsecond values of either sign.
+        let is_negative = if seconds == 0 {
+            u.arbitrary()?
+        } else {
+            seconds < 0
+        };
+
+        Ok(Duration {
+            seconds,
+            nanoseconds: if is_negative {
+                -nanoseconds
+            } else {
+                nanoseconds
+            },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for UtcOffset {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(UtcOffset {
+            seconds: u.int_in_range(-86_399..=86_399)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn duration() -> Result<()> {
+        let data: Vec<u8> = (0..=255).cycle().take(4_096).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let duration = Duration::arbitrary(&mut u)?;
+            assert!(duration.is_canonical());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn utc_offset() -> Result<()> {
+        let data: Vec<u8> = (0..=255).rev().cycle().take(4_096).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let offset = UtcOffset::arbitrary(&mut u)?;
+            assert!(offset.as_seconds().abs() < 86_400);
+        }
+
+        Ok(())
+    }
+}
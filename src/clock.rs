@@ -0,0 +1,190 @@
+use crate::{internal_prelude::*, Duration};
+use core::cell::Cell;
+
+/// A source of [`Instant`]s.
+///
+/// Code that measures time can be made generic over a `Clock`, allowing tests
+/// to substitute a [`MockClock`] for the [`SystemClock`] and control the
+/// passage of time deterministically.
+///
+/// ```rust
+/// # use time::{Clock, MockClock, prelude::*};
+/// fn is_expired(clock: &impl Clock, deadline: time::Instant) -> bool {
+///     clock.now() >= deadline
+/// }
+///
+/// let clock = MockClock::default();
+/// let deadline = clock.now() + 5.seconds();
+/// assert!(!is_expired(&clock, deadline));
+/// clock.advance(5.seconds());
+/// assert!(is_expired(&clock, deadline));
+/// ```
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+pub trait Clock {
+    /// Obtain the current instant, as measured by this clock.
+    fn now(&self) -> Instant;
+
+    /// Obtain the amount of time elapsed on this clock since `earlier`.
+    ///
+    /// ```rust
+    /// # use time::{Clock, MockClock, prelude::*};
+    /// let clock = MockClock::default();
+    /// let start = clock.now();
+    /// clock.advance(1.seconds());
+    /// assert_eq!(clock.elapsed(start), 1.seconds());
+    /// ```
+    #[inline(always)]
+    fn elapsed(&self, earlier: Instant) -> Duration {
+        self.now() - earlier
+    }
+}
+
+/// A [`Clock`] that uses the system's monotonic clock, via [`Instant::now`].
+///
+/// ```rust
+/// # use time::{Clock, SystemClock};
+/// let start = SystemClock.now();
+/// assert!(SystemClock.now() >= start);
+/// ```
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline(always)]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [`Clock`] that only moves when told to, for deterministic tests.
+///
+/// The clock can be advanced through a shared reference, so it can be handed
+/// to the code under test while the test retains control over it.
+///
+/// ```rust
+/// # use time::{Clock, MockClock, prelude::*};
+/// let clock = MockClock::default();
+/// let start = clock.now();
+/// assert_eq!(clock.now(), start);
+/// clock.advance(250.milliseconds());
+/// assert_eq!(clock.now() - start, 250.milliseconds());
+/// ```
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockClock {
+    /// The instant currently reported by the clock.
+    now: Cell<Instant>,
+}
+
+impl MockClock {
+    /// Create a `MockClock` that reports the provided instant until advanced.
+    ///
+    /// ```rust
+    /// # use time::{Clock, Instant, MockClock};
+    /// let instant = Instant::now();
+    /// assert_eq!(MockClock::new(instant).now(), instant);
+    /// ```
+    #[inline(always)]
+    pub const fn new(now: Instant) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+
+    /// Move the clock by the provided duration. A negative duration moves the
+    /// clock backwards.
+    ///
+    /// ```rust
+    /// # use time::{Clock, MockClock, prelude::*};
+    /// let clock = MockClock::default();
+    /// let start = clock.now();
+    /// clock.advance(1.minutes());
+    /// assert_eq!(clock.now(), start + 1.minutes());
+    /// clock.advance((-30).seconds());
+    /// assert_eq!(clock.now(), start + 30.seconds());
+    /// ```
+    #[inline(always)]
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+
+    /// Set the instant reported by the clock.
+    ///
+    /// ```rust
+    /// # use time::{Clock, Instant, MockClock, prelude::*};
+    /// let clock = MockClock::default();
+    /// let instant = Instant::now() + 1.hours();
+    /// clock.set(instant);
+    /// assert_eq!(clock.now(), instant);
+    /// ```
+    #[inline(always)]
+    pub fn set(&self, now: Instant) {
+        self.now.set(now);
+    }
+}
+
+impl Default for MockClock {
+    /// Create a `MockClock` starting at the current instant of the system
+    /// clock.
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Instant::now())
+    }
+}
+
+impl Clock for MockClock {
+    #[inline(always)]
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    #[inline(always)]
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn system_clock() {
+        let start = SystemClock.now();
+        assert!(SystemClock.now() >= start);
+        assert!(SystemClock.elapsed(start) >= Duration::zero());
+    }
+
+    #[test]
+    fn mock_clock() {
+        let start = Instant::now();
+        let clock = MockClock::new(start);
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.elapsed(start), Duration::zero());
+
+        clock.advance(5.seconds());
+        assert_eq!(clock.now(), start + 5.seconds());
+        assert_eq!(clock.elapsed(start), 5.seconds());
+
+        clock.advance((-2).seconds());
+        assert_eq!(clock.elapsed(start), 3.seconds());
+
+        clock.set(start);
+        assert_eq!(clock.elapsed(start), Duration::zero());
+    }
+
+    #[test]
+    fn clock_reference() {
+        fn now(clock: impl Clock) -> Instant {
+            clock.now()
+        }
+
+        let clock = MockClock::default();
+        assert_eq!(now(&clock), clock.now());
+        clock.advance(1.seconds());
+        assert_eq!(now(&clock), clock.now());
+    }
+}
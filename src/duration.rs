@@ -1,9 +1,17 @@
-use crate::internal_prelude::*;
+use crate::{
+    format, internal_prelude::*, units::Unit, DurationFormatItem, DurationParseError, Error,
+};
+#[cfg(std)]
+use crate::{Clock, SystemClock};
 use core::{
     cmp::Ordering::{self, Equal, Greater, Less},
+    fmt::{self, Display},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
     time::Duration as StdDuration,
 };
+#[cfg(std)]
+use std::time::SystemTime;
 
 /// A span of time with nanosecond precision.
 ///
@@ -15,6 +23,19 @@ use core::{
 ///
 /// This implementation allows for negative durations, unlike
 /// [`core::time::Duration`].
+///
+/// Multiplying or dividing a `Duration` by a floating point value saturates to
+/// [`Duration::max_value`] or [`Duration::min_value`] when the result is out of
+/// range, including when it is infinite. A NaN result is treated as zero.
+///
+/// A `Duration` can be compared to an `f64` representing a number of seconds,
+/// which is done using [`Duration::as_seconds_f64`]. Any comparison with NaN
+/// returns `None` (or `false`, for equality).
+///
+/// When displayed, a `Duration` is written as its nonzero components from
+/// days down to nanoseconds, such as `1d2h3m4s5ms6µs7ns`, preceded by a `-`
+/// if negative. A zero duration is written as `0s`. The formatter's width, fill,
+/// and alignment are respected.
 #[cfg_attr(serde, derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     serde,
@@ -41,7 +62,377 @@ const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
 /// The number of seconds in one week.
 const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
 
+/// Units accepted by [`Duration::parse_ascii`] and the number of nanoseconds
+/// in each. A unit must precede any shorter unit that is a prefix of it.
+const DURATION_UNITS: &[(&[u8], i128)] = &[
+    (b"ns", 1),
+    (b"us", 1_000),
+    (b"\xC2\xB5s", 1_000),
+    (b"ms", 1_000_000),
+    (b"s", 1_000_000_000),
+    (b"m", 1_000_000_000 * SECONDS_PER_MINUTE as i128),
+    (b"h", 1_000_000_000 * SECONDS_PER_HOUR as i128),
+    (b"d", 1_000_000_000 * SECONDS_PER_DAY as i128),
+    (b"w", 1_000_000_000 * SECONDS_PER_WEEK as i128),
+];
+
+/// How a [`Duration`] is rounded when converting it to a type with less
+/// precision.
+///
+/// `std::time::Duration` has the same precision as [`Duration`], so no rounding
+/// currently takes place. This is reserved for conversions to coarser types.
+#[cfg_attr(supports_non_exhaustive, non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Discard any precision that cannot be represented.
+    Truncate,
+    #[cfg(not(supports_non_exhaustive))]
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+/// How [`Duration::quantize`] rounds a duration that does not lie exactly on
+/// the grid.
+///
+/// ```rust
+/// # use time::{Rounding, prelude::*};
+/// let duration = 25.milliseconds();
+/// assert_eq!(duration.quantize(10.milliseconds(), Rounding::Nearest), 30.milliseconds());
+/// assert_eq!(duration.quantize(10.milliseconds(), Rounding::NearestEven), 20.milliseconds());
+/// ```
+#[cfg_attr(supports_non_exhaustive, non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest multiple, with ties rounding away from zero.
+    Nearest,
+    /// Round to the nearest multiple, with ties rounding to the even multiple.
+    /// This is also known as bankers' rounding.
+    NearestEven,
+    /// Round towards negative infinity.
+    Down,
+    /// Round towards positive infinity.
+    Up,
+    /// Round towards zero, discarding the remainder.
+    TowardZero,
+    #[cfg(not(supports_non_exhaustive))]
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+/// A duration broken into optional components, such as `1d 2h 30m`.
+///
+/// A [`Duration`] is created from the components via [`TryFrom`], summing
+/// those present. Components may be negative, and are not required to be
+/// less than the next larger unit.
+///
+/// ```rust
+/// # use time::{Duration, DurationComponents, prelude::*};
+/// # use standback::convert::TryFrom;
+/// let components = DurationComponents {
+///     days: Some(1),
+///     hours: Some(2),
+///     minutes: Some(30),
+///     ..Default::default()
+/// };
+/// assert_eq!(Duration::try_from(components), Ok(1.days() + 150.minutes()));
+/// ```
+#[cfg_attr(serde, derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DurationComponents {
+    /// The number of weeks.
+    pub weeks: Option<i64>,
+    /// The number of days.
+    pub days: Option<i64>,
+    /// The number of hours.
+    pub hours: Option<i64>,
+    /// The number of minutes.
+    pub minutes: Option<i64>,
+    /// The number of seconds.
+    pub seconds: Option<i64>,
+    /// The number of milliseconds.
+    pub milliseconds: Option<i64>,
+    /// The number of microseconds.
+    pub microseconds: Option<i64>,
+    /// The number of nanoseconds.
+    pub nanoseconds: Option<i64>,
+}
+
+/// Options controlling how [`Duration::humanize_with`] describes a duration.
+///
+/// The default options produce English phrases such as `in 3 minutes` and
+/// `2 hours ago`, with any duration shorter than a second described as `just
+/// now`.
+///
+/// ```rust
+/// # use time::{HumanizeOptions, prelude::*};
+/// let options = HumanizeOptions {
+///     just_now_threshold: 1.minutes(),
+///     ..Default::default()
+/// };
+/// assert_eq!(30.seconds().humanize_with(options), "just now");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HumanizeOptions {
+    /// Durations with a magnitude less than this are described using
+    /// `just_now`.
+    pub just_now_threshold: Duration,
+    /// The phrase used for durations shorter than `just_now_threshold`.
+    pub just_now: &'static str,
+    /// Placed before the amount of a positive duration.
+    pub future_prefix: &'static str,
+    /// Placed after the amount of a positive duration.
+    pub future_suffix: &'static str,
+    /// Placed before the amount of a negative duration.
+    pub past_prefix: &'static str,
+    /// Placed after the amount of a negative duration.
+    pub past_suffix: &'static str,
+    /// The labels for each unit. Only the singular and plural forms of weeks,
+    /// days, hours, minutes, and seconds are used.
+    pub labels: UnitLabels,
+}
+
+impl Default for HumanizeOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            just_now_threshold: Duration::second(),
+            just_now: "just now",
+            future_prefix: "in ",
+            future_suffix: "",
+            past_prefix: "",
+            past_suffix: " ago",
+            labels: UnitLabels::default(),
+        }
+    }
+}
+
+/// The labels used for a single unit by [`Duration::display_with_labels`] and
+/// [`Duration::humanize_with`].
+///
+/// ```rust
+/// # use time::UnitLabel;
+/// let hours = UnitLabel::new("h", "heure", "heures");
+/// assert_eq!(hours.short, "h");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitLabel {
+    /// The abbreviated form, such as `h`.
+    pub short: &'static str,
+    /// The long form used for a value of one, such as `hour`.
+    pub singular: &'static str,
+    /// The long form used for any other value, such as `hours`.
+    pub plural: &'static str,
+}
+
+impl UnitLabel {
+    /// Create a `UnitLabel` from its short, singular, and plural forms.
+    ///
+    /// ```rust
+    /// # use time::UnitLabel;
+    /// let label = UnitLabel::new("d", "day", "days");
+    /// assert_eq!(label.singular, "day");
+    /// assert_eq!(label.plural, "days");
+    /// ```
+    #[inline(always)]
+    pub const fn new(short: &'static str, singular: &'static str, plural: &'static str) -> Self {
+        Self {
+            short,
+            singular,
+            plural,
+        }
+    }
+}
+
+/// The labels for each unit used by [`Duration::display_with_labels`] and
+/// [`Duration::humanize_with`].
+///
+/// The default labels are English, and are the same abbreviations used by the
+/// [`Display`] implementation of [`Duration`]. No locale data is provided;
+/// other languages are supported by supplying the strings directly.
+///
+/// ```rust
+/// # use time::{UnitLabel, UnitLabels, prelude::*};
+/// let labels = UnitLabels {
+///     days: UnitLabel::new("j", "jour", "jours"),
+///     ..Default::default()
+/// };
+/// assert_eq!((1.days() + 2.hours()).display_with_labels(labels).to_string(), "1 j 2 h");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitLabels {
+    /// The labels for weeks. These are only used by
+    /// [`Duration::humanize_with`], as [`Duration::display_with_labels`] never
+    /// uses a unit larger than days.
+    pub weeks: UnitLabel,
+    /// The labels for days.
+    pub days: UnitLabel,
+    /// The labels for hours.
+    pub hours: UnitLabel,
+    /// The labels for minutes.
+    pub minutes: UnitLabel,
+    /// The labels for seconds.
+    pub seconds: UnitLabel,
+    /// The labels for milliseconds.
+    pub milliseconds: UnitLabel,
+    /// The labels for microseconds.
+    pub microseconds: UnitLabel,
+    /// The labels for nanoseconds.
+    pub nanoseconds: UnitLabel,
+}
+
+impl Default for UnitLabels {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            weeks: UnitLabel::new("w", "week", "weeks"),
+            days: UnitLabel::new("d", "day", "days"),
+            hours: UnitLabel::new("h", "hour", "hours"),
+            minutes: UnitLabel::new("m", "minute", "minutes"),
+            seconds: UnitLabel::new("s", "second", "seconds"),
+            milliseconds: UnitLabel::new("ms", "millisecond", "milliseconds"),
+            microseconds: UnitLabel::new("µs", "microsecond", "microseconds"),
+            nanoseconds: UnitLabel::new("ns", "nanosecond", "nanoseconds"),
+        }
+    }
+}
+
+/// A duration paired with the labels used to display it, created by
+/// [`Duration::display_with_labels`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DisplayWithLabels {
+    /// The duration being displayed.
+    duration: Duration,
+    /// The labels for each unit.
+    labels: UnitLabels,
+}
+
+impl Display for DisplayWithLabels {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(
+            &self
+                .duration
+                .labeled_components(&self.labels, f.alternate(), " "),
+        )
+    }
+}
+
+/// A style in which [`Duration::display_as`] renders a duration.
+///
+/// ```rust
+/// # use time::{DisplayStyle, prelude::*};
+/// assert_eq!(90.minutes().display_as(DisplayStyle::Compact).to_string(), "1h30m");
+/// assert_eq!(90.minutes().display_as(DisplayStyle::Iso8601).to_string(), "PT1H30M");
+/// ```
+#[cfg_attr(supports_non_exhaustive, non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisplayStyle {
+    /// Each nonzero unit followed by its abbreviation, such as `1h30m`. This
+    /// is identical to the [`Display`] implementation of [`Duration`].
+    Compact,
+    /// An ISO 8601 duration, such as `PT1H30M`, as produced by
+    /// [`Duration::to_iso8601`].
+    Iso8601,
+    /// Hours, minutes, and seconds separated by colons, with millisecond
+    /// precision, such as `01:30:00.000`.
+    Timecode,
+    /// A phrase relative to the present, such as `in 1 hour`, as produced by
+    /// [`Duration::humanize`].
+    Humanized,
+    #[cfg(not(supports_non_exhaustive))]
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+/// A duration paired with the style in which it is displayed, created by
+/// [`Duration::display_as`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DisplayAs {
+    /// The duration being displayed.
+    duration: Duration,
+    /// The style in which the duration is displayed.
+    style: DisplayStyle,
+}
+
+impl Display for DisplayAs {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use DurationFormatItem::*;
+
+        match self.style {
+            DisplayStyle::Compact => self.duration.fmt(f),
+            DisplayStyle::Iso8601 => f.pad(&self.duration.to_iso8601()),
+            DisplayStyle::Timecode => format::duration::format_duration(
+                f,
+                self.duration,
+                &[
+                    Sign,
+                    Hours,
+                    Literal(":"),
+                    Minutes,
+                    Literal(":"),
+                    Seconds,
+                    Literal("."),
+                    Subsecond(3),
+                ],
+            ),
+            DisplayStyle::Humanized => f.pad(&self.duration.humanize()),
+            #[cfg(not(supports_non_exhaustive))]
+            DisplayStyle::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+/// An iterator over durations separated by a fixed step, created by
+/// [`Duration::step_by`].
+#[derive(Debug, Clone)]
+struct StepBy {
+    /// The next duration to be yielded, if it is within bounds.
+    next: Option<Duration>,
+    /// The bound, which is never yielded.
+    end: Duration,
+    /// The difference between consecutive durations. This is never zero.
+    step: Duration,
+}
+
+impl Iterator for StepBy {
+    type Item = Duration;
+
+    #[inline]
+    fn next(&mut self) -> Option<Duration> {
+        let current = self.next?;
+
+        let in_bounds = if self.step.is_positive() {
+            current < self.end
+        } else {
+            current > self.end
+        };
+        if !in_bounds {
+            self.next = None;
+            return None;
+        }
+
+        self.next = current.checked_add(self.step);
+        Some(current)
+    }
+}
+
 impl Duration {
+    /// A sentinel value representing a duration that has not been set. This
+    /// allows a field to represent an absent duration without the overhead of
+    /// `Option<Duration>`.
+    ///
+    /// The sentinel is equal to [`Duration::min_value`], so a computation that
+    /// saturates to the minimum duration is indistinguishable from it. It is
+    /// _not_ the [`Default`] value, which remains zero.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert!(Duration::UNSET.is_unset());
+    /// assert!(!Duration::default().is_unset());
+    /// ```
+    pub const UNSET: Self = Self::min_value();
+
     /// Equivalent to `0.seconds()`.
     ///
     /// ```rust
@@ -171,6 +562,62 @@ impl Duration {
         (self.seconds == 0) & (self.nanoseconds == 0)
     }
 
+    /// Check if the duration is the [`Duration::UNSET`] sentinel.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert!(Duration::UNSET.is_unset());
+    /// assert!(!0.seconds().is_unset());
+    /// ```
+    #[inline(always)]
+    pub const fn is_unset(self) -> bool {
+        (self.seconds == i64::min_value()) & (self.nanoseconds == -999_999_999)
+    }
+
+    /// Check if the duration is less than `rhs`. This is equivalent to the
+    /// `<` operator, but is usable in `const` contexts.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// const TIMEOUT: Duration = Duration::seconds(30);
+    /// const IS_SHORT: bool = TIMEOUT.lt_const(Duration::minutes(1));
+    /// assert!(IS_SHORT);
+    /// assert!(!Duration::seconds(1).lt_const(Duration::seconds(1)));
+    /// ```
+    #[inline(always)]
+    pub const fn lt_const(self, rhs: Self) -> bool {
+        (self.seconds < rhs.seconds)
+            | ((self.seconds == rhs.seconds) & (self.nanoseconds < rhs.nanoseconds))
+    }
+
+    /// Check if the duration is less than or equal to `rhs`. This is
+    /// equivalent to the `<=` operator, but is usable in `const` contexts.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert!(Duration::seconds(1).le_const(Duration::seconds(1)));
+    /// assert!(Duration::seconds(-1).le_const(Duration::zero()));
+    /// assert!(!Duration::seconds(2).le_const(Duration::seconds(1)));
+    /// ```
+    #[inline(always)]
+    pub const fn le_const(self, rhs: Self) -> bool {
+        (self.seconds < rhs.seconds)
+            | ((self.seconds == rhs.seconds) & (self.nanoseconds <= rhs.nanoseconds))
+    }
+
+    /// Check if the duration is equal to `rhs`. This is equivalent to the `==`
+    /// operator, but is usable in `const` contexts.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert!(Duration::minutes(1).eq_const(Duration::seconds(60)));
+    /// assert!(!Duration::minutes(1).eq_const(Duration::seconds(61)));
+    /// ```
+    #[inline(always)]
+    pub const fn eq_const(self, rhs: Self) -> bool {
+        (self.seconds == rhs.seconds) & (self.nanoseconds == rhs.nanoseconds)
+    }
+
     /// Check if a duration is negative.
     ///
     /// ```rust
@@ -197,6 +644,91 @@ impl Duration {
         (self.seconds > 0) | (self.nanoseconds > 0)
     }
 
+    /// Check if a duration is zero or positive.
+    ///
+    /// Both the seconds and nanoseconds must be non-negative, so a duration
+    /// with a negative subsecond component, such as `(-1).nanoseconds()`, is
+    /// not zero-or-positive even though its whole seconds are zero.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert!(1.seconds().is_zero_or_positive());
+    /// assert!(0.seconds().is_zero_or_positive());
+    /// assert!(!(-1).nanoseconds().is_zero_or_positive());
+    /// ```
+    #[inline(always)]
+    pub const fn is_zero_or_positive(self) -> bool {
+        (self.seconds >= 0) & (self.nanoseconds >= 0)
+    }
+
+    /// Check if a duration is zero or negative.
+    ///
+    /// Both the seconds and nanoseconds must be non-positive, so a duration
+    /// with a positive subsecond component, such as `1.nanoseconds()`, is not
+    /// zero-or-negative even though its whole seconds are zero.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert!((-1).seconds().is_zero_or_negative());
+    /// assert!(0.seconds().is_zero_or_negative());
+    /// assert!(!1.nanoseconds().is_zero_or_negative());
+    /// ```
+    #[inline(always)]
+    pub const fn is_zero_or_negative(self) -> bool {
+        (self.seconds <= 0) & (self.nanoseconds <= 0)
+    }
+
+    /// Check if the duration is in its canonical form, where the nanoseconds
+    /// are less than one second in magnitude and do not have the opposite sign
+    /// of the seconds.
+    ///
+    /// Durations produced by arithmetic are always canonical. A non-canonical
+    /// duration can be created by [`Duration::new`] when the signs of its
+    /// arguments differ, and can be corrected with [`Duration::normalize`].
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert!(1.5.seconds().is_canonical());
+    /// assert!(Duration::new(0, -1).is_canonical());
+    /// assert!(!Duration::new(1, -1).is_canonical());
+    /// ```
+    #[inline(always)]
+    pub fn is_canonical(self) -> bool {
+        self.nanoseconds > -1_000_000_000
+            && self.nanoseconds < 1_000_000_000
+            && !(self.seconds > 0 && self.nanoseconds < 0)
+            && !(self.seconds < 0 && self.nanoseconds > 0)
+    }
+
+    /// Convert the duration to its canonical form, carrying nanoseconds into
+    /// seconds as necessary. The value of the duration is unchanged, except
+    /// that it saturates to [`Duration::min_value`] or [`Duration::max_value`]
+    /// if out of range.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::new(1, -1).normalize(), 999_999_999.nanoseconds());
+    /// assert_eq!(Duration::new(-1, 1).normalize(), (-999_999_999).nanoseconds());
+    /// assert_eq!(1.5.seconds().normalize(), 1.5.seconds());
+    /// ```
+    #[inline]
+    pub fn normalize(self) -> Self {
+        Self::saturating_nanoseconds_i128(self.whole_nanoseconds()).debug_assert_invariant()
+    }
+
+    /// Assert that the duration is canonical when debug assertions are
+    /// enabled, returning it unchanged.
+    ///
+    /// Every constructor that is not `const fn` calls this. Constructors that
+    /// are `const fn` cannot, as `debug_assert!` is not permitted in a
+    /// `const fn` on the minimum supported Rust version; their tests assert
+    /// that the result is canonical instead.
+    #[inline(always)]
+    fn debug_assert_invariant(self) -> Self {
+        debug_assert!(self.is_canonical(), "duration is not canonical");
+        self
+    }
+
     /// Get the sign of the duration.
     ///
     /// ```rust
@@ -230,6 +762,10 @@ impl Duration {
 
     /// Get the absolute value of the duration.
     ///
+    /// The absolute value of [`Duration::min_value`] is not representable.
+    /// Computing it panics when overflow checks are enabled, and wraps
+    /// otherwise. Use [`Duration::saturating_abs`] if this is a concern.
+    ///
     /// ```rust
     /// # use time::prelude::*;
     /// assert_eq!(1.seconds().abs(), 1.seconds());
@@ -249,6 +785,10 @@ impl Duration {
 
     /// Get the absolute value of the duration.
     ///
+    /// The absolute value of [`Duration::min_value`] is not representable.
+    /// Computing it panics when overflow checks are enabled, and wraps
+    /// otherwise. Use [`Duration::saturating_abs`] if this is a concern.
+    ///
     /// ```rust
     /// # use time::prelude::*;
     /// assert_eq!(1.seconds().abs(), 1.seconds());
@@ -266,13 +806,34 @@ impl Duration {
         }
     }
 
+    /// Get the absolute value of the duration, returning
+    /// [`Duration::max_value`] if the true value is not representable.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!((-1).seconds().saturating_abs(), 1.seconds());
+    /// assert_eq!(1.seconds().saturating_abs(), 1.seconds());
+    /// assert_eq!(Duration::min_value().saturating_abs(), Duration::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn saturating_abs(self) -> Self {
+        if self.seconds == i64::min_value() {
+            Self::max_value()
+        } else {
+            self.abs()
+        }
+    }
+
     /// Convert the existing `Duration` to a `std::time::Duration` and its sign.
     // This doesn't actually require the standard library, but is currently only
     // used when it's enabled.
     #[inline(always)]
     #[cfg(std)]
     pub(crate) fn abs_std(self) -> StdDuration {
-        StdDuration::new(self.seconds.abs() as u64, self.nanoseconds.abs() as u32)
+        StdDuration::new(
+            self.seconds.wrapping_abs() as u64,
+            self.nanoseconds.abs() as u32,
+        )
     }
 
     /// Create a new `Duration` with the provided seconds and nanoseconds. If
@@ -285,6 +846,10 @@ impl Duration {
     /// assert_eq!(Duration::new(-1, 0), (-1).seconds());
     /// assert_eq!(Duration::new(1, 2_000_000_000), 3.seconds());
     /// ```
+    ///
+    /// The signs of the seconds and nanoseconds are not reconciled, so the
+    /// result is only [canonical](Duration::is_canonical) if they do not
+    /// differ. Use [`Duration::normalize`] if they may.
     #[inline(always)]
     pub const fn new(seconds: i64, nanoseconds: i32) -> Self {
         Self {
@@ -305,7 +870,7 @@ impl Duration {
         Self::seconds(weeks * SECONDS_PER_WEEK)
     }
 
-    /// Get the number of whole weeks in the duration.
+    /// Get the number of whole weeks in the duration, truncating toward zero.
     ///
     /// ```rust
     /// # use time::prelude::*;
@@ -313,6 +878,7 @@ impl Duration {
     /// assert_eq!((-1).weeks().whole_weeks(), -1);
     /// assert_eq!(6.days().whole_weeks(), 0);
     /// assert_eq!((-6).days().whole_weeks(), 0);
+    /// assert_eq!((-8).days().whole_weeks(), -1);
     /// ```
     #[inline(always)]
     pub const fn whole_weeks(self) -> i64 {
@@ -331,7 +897,7 @@ impl Duration {
         Self::seconds(days * SECONDS_PER_DAY)
     }
 
-    /// Get the number of whole days in the duration.
+    /// Get the number of whole days in the duration, truncating toward zero.
     ///
     /// ```rust
     /// # use time::prelude::*;
@@ -357,7 +923,7 @@ impl Duration {
         Self::seconds(hours * SECONDS_PER_HOUR)
     }
 
-    /// Get the number of whole hours in the duration.
+    /// Get the number of whole hours in the duration, truncating toward zero.
     ///
     /// ```rust
     /// # use time::prelude::*;
@@ -383,7 +949,71 @@ impl Duration {
         Self::seconds(minutes * SECONDS_PER_MINUTE)
     }
 
-    /// Get the number of whole minutes in the duration.
+    /// Create a new `Duration` with the given number of minutes, saturating to
+    /// [`Duration::min_value`] or [`Duration::max_value`] if the number of
+    /// seconds would overflow an `i64`.
+    ///
+    /// Unlike [`Duration::minutes`], this never overflows.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_minutes_saturating(90), 90.minutes());
+    /// assert_eq!(
+    ///     Duration::from_minutes_saturating(i64::max_value()),
+    ///     Duration::max_value()
+    /// );
+    /// assert_eq!(
+    ///     Duration::from_minutes_saturating(i64::min_value()),
+    ///     Duration::min_value()
+    /// );
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.0.
+    #[inline]
+    #[cfg(const_if_match)]
+    pub const fn from_minutes_saturating(minutes: i64) -> Self {
+        if minutes > i64::max_value() / SECONDS_PER_MINUTE {
+            Self::max_value()
+        } else if minutes < i64::min_value() / SECONDS_PER_MINUTE {
+            Self::min_value()
+        } else {
+            Self::minutes(minutes)
+        }
+    }
+
+    /// Create a new `Duration` with the given number of minutes, saturating to
+    /// [`Duration::min_value`] or [`Duration::max_value`] if the number of
+    /// seconds would overflow an `i64`.
+    ///
+    /// Unlike [`Duration::minutes`], this never overflows.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_minutes_saturating(90), 90.minutes());
+    /// assert_eq!(
+    ///     Duration::from_minutes_saturating(i64::max_value()),
+    ///     Duration::max_value()
+    /// );
+    /// assert_eq!(
+    ///     Duration::from_minutes_saturating(i64::min_value()),
+    ///     Duration::min_value()
+    /// );
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.0.
+    #[inline]
+    #[cfg(not(const_if_match))]
+    pub fn from_minutes_saturating(minutes: i64) -> Self {
+        if minutes > i64::max_value() / SECONDS_PER_MINUTE {
+            Self::max_value()
+        } else if minutes < i64::min_value() / SECONDS_PER_MINUTE {
+            Self::min_value()
+        } else {
+            Self::minutes(minutes).debug_assert_invariant()
+        }
+    }
+
+    /// Get the number of whole minutes in the duration, truncating toward zero.
     ///
     /// ```rust
     /// # use time::prelude::*;
@@ -411,7 +1041,7 @@ impl Duration {
         }
     }
 
-    /// Get the number of whole seconds in the duration.
+    /// Get the number of whole seconds in the duration, truncating toward zero.
     ///
     /// ```rust
     /// # use time::prelude::*;
@@ -428,17 +1058,49 @@ impl Duration {
     /// Creates a new `Duration` from the specified number of seconds
     /// represented as `f64`.
     ///
+    /// Converting back with [`Duration::as_seconds_f64`] returns the original
+    /// value to within one nanosecond, or the precision of an `f64` if that is
+    /// coarser. Both the whole and fractional parts carry the sign of the
+    /// input.
+    ///
+    /// The fractional part is rounded to the nearest nanosecond, with ties
+    /// rounding away from zero. Values smaller in magnitude than half a
+    /// nanosecond, including subnormals, become zero.
+    ///
     /// ```rust
     /// # use time::{Duration, prelude::*};
     /// assert_eq!(Duration::seconds_f64(0.5), 0.5.seconds());
     /// assert_eq!(Duration::seconds_f64(-0.5), -0.5.seconds());
+    /// assert_eq!(Duration::seconds_f64(6e-10), 1.nanoseconds());
+    /// assert_eq!(Duration::seconds_f64(1e-12), Duration::zero());
     /// ```
-    #[inline(always)]
+    #[inline]
     pub fn seconds_f64(seconds: f64) -> Self {
+        let mut whole = seconds as i64;
+        let fraction = (seconds % 1.) * 1_000_000_000.;
+        // Casting truncates towards zero, so offsetting by half a nanosecond
+        // in the direction of the sign rounds to the nearest.
+        let mut nanoseconds = if fraction < 0. {
+            (fraction - 0.5) as i32
+        } else {
+            (fraction + 0.5) as i32
+        };
+
+        // The fractional part is only non-zero when the whole part is well
+        // within the range of an `i64`, so the carry cannot overflow.
+        if nanoseconds >= 1_000_000_000 {
+            whole += 1;
+            nanoseconds -= 1_000_000_000;
+        } else if nanoseconds <= -1_000_000_000 {
+            whole -= 1;
+            nanoseconds += 1_000_000_000;
+        }
+
         Self {
-            seconds: seconds as i64,
-            nanoseconds: ((seconds % 1.) * 1_000_000_000.) as i32,
+            seconds: whole,
+            nanoseconds,
         }
+        .debug_assert_invariant()
     }
 
     /// Get the number of fractional seconds in the duration.
@@ -453,6 +1115,139 @@ impl Duration {
         self.seconds as f64 + self.nanoseconds as f64 / 1_000_000_000.
     }
 
+    /// Get the number of fractional seconds in the duration, minimizing the
+    /// rounding error for durations with a large number of seconds.
+    ///
+    /// Beyond 2<sup>53</sup> seconds, `self.whole_seconds() as f64` is
+    /// already inexact, and [`Duration::as_seconds_f64`] rounds twice: once
+    /// when converting the seconds and again when adding the fraction. This
+    /// method rounds the seconds, then recovers the integer error of that
+    /// rounding and folds it into the fraction before the final addition. The
+    /// result is within one unit in the last place of the exact value, and
+    /// is typically the nearest `f64`. For smaller durations the two methods
+    /// agree.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// let duration = Duration::seconds((1 << 53) + 1) + 0.6.seconds();
+    /// assert_eq!(duration.as_seconds_f64(), 9_007_199_254_740_992.);
+    /// assert_eq!(duration.as_seconds_f64_high_precision(), 9_007_199_254_740_994.);
+    /// assert_eq!(1.5.seconds().as_seconds_f64_high_precision(), 1.5);
+    /// ```
+    #[inline]
+    pub fn as_seconds_f64_high_precision(self) -> f64 {
+        let seconds = self.seconds as f64;
+        // The error of the conversion is at most 2^10 seconds, so the remaining
+        // nanoseconds are exactly representable.
+        let remainder =
+            (self.seconds as i128 - seconds as i128) * 1_000_000_000 + self.nanoseconds as i128;
+        seconds + remainder as f64 / 1_000_000_000.
+    }
+
+    /// Compare the duration to a `std::time::Duration`, providing a total
+    /// ordering.
+    ///
+    /// A `std::time::Duration` is never negative, and may exceed
+    /// [`Duration::max_value`], so any negative duration is less than every
+    /// `std::time::Duration`. This is used by the [`PartialOrd`]
+    /// implementations between the two types.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// # use core::cmp::Ordering;
+    /// assert_eq!(1.seconds().cmp_std(&1.std_seconds()), Ordering::Equal);
+    /// assert_eq!((-1).seconds().cmp_std(&0.std_seconds()), Ordering::Less);
+    /// assert_eq!(1.minutes().cmp_std(&1.std_seconds()), Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn cmp_std(self, other: &StdDuration) -> Ordering {
+        if other.as_secs() > i64::max_value() as u64 {
+            return Less;
+        }
+
+        match self.seconds.cmp(&(other.as_secs() as i64)) {
+            Equal => self.nanoseconds.cmp(&(other.subsec_nanos() as i32)),
+            ordering => ordering,
+        }
+    }
+
+    /// Compare the duration to a number of seconds represented as `f64`,
+    /// providing a total ordering.
+    ///
+    /// NaN is treated as greater than every duration, so that it sorts last.
+    /// The comparison is exact: `other` is decomposed into its mantissa and
+    /// exponent and compared against the duration's nanoseconds as integers,
+    /// without converting the duration to a float or constructing a
+    /// `Duration` from `other`.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// # use core::cmp::Ordering;
+    /// assert_eq!(600.milliseconds().cmp_seconds_f64(0.5), Ordering::Greater);
+    /// assert_eq!(500.milliseconds().cmp_seconds_f64(0.5), Ordering::Equal);
+    /// assert_eq!(1.seconds().cmp_seconds_f64(std::f64::NAN), Ordering::Less);
+    /// // The `f64` nearest to 0.1 is slightly greater than 0.1.
+    /// assert_eq!(100.milliseconds().cmp_seconds_f64(0.1), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cmp_seconds_f64(self, other: f64) -> Ordering {
+        /// 2<sup>63</sup>, the smallest magnitude not representable by `i64`.
+        const TWO_POW_63: f64 = 9_223_372_036_854_775_808.;
+
+        if other.is_nan() || other >= TWO_POW_63 {
+            return Less;
+        }
+        // There is no `f64` between -2^63 - 1 and -2^63, so any value below
+        // -2^63 is less than every duration.
+        if other < -TWO_POW_63 {
+            return Greater;
+        }
+
+        // `other` is now finite and less than 2^63 in magnitude. It is exactly
+        // `mantissa * 2^exponent`, so its value in nanoseconds is
+        // `mantissa * 10^9 * 2^exponent`. That is split into a whole number of
+        // nanoseconds and whether there is a nonzero fractional part.
+        let bits = other.to_bits();
+        let is_negative = bits >> 63 != 0;
+        let biased_exponent = ((bits >> 52) & 0x7FF) as i32;
+        let mut mantissa = bits & ((1 << 52) - 1);
+        let exponent = if biased_exponent == 0 {
+            // Subnormal numbers have no implicit leading bit.
+            -1074
+        } else {
+            mantissa |= 1 << 52;
+            biased_exponent - 1075
+        };
+
+        // The product is less than 2^83, and shifting it left cannot exceed
+        // 2^63 * 10^9 < 2^93 due to the bounds checked above.
+        let scaled = mantissa as u128 * 1_000_000_000;
+        let (whole_nanoseconds, has_fraction) = if exponent >= 0 {
+            (scaled << exponent, false)
+        } else if exponent > -128 {
+            let shift = -exponent as u32;
+            (scaled >> shift, scaled & ((1 << shift) - 1) != 0)
+        } else {
+            (0, scaled != 0)
+        };
+        let whole_nanoseconds = whole_nanoseconds as i128;
+
+        let nanoseconds = self.whole_nanoseconds();
+        if is_negative {
+            // `other` is `-(whole_nanoseconds + fraction)` nanoseconds.
+            match nanoseconds.cmp(&-whole_nanoseconds) {
+                Equal if has_fraction => Greater,
+                ordering => ordering,
+            }
+        } else {
+            // `other` is `whole_nanoseconds + fraction` nanoseconds.
+            match nanoseconds.cmp(&whole_nanoseconds) {
+                Equal if has_fraction => Less,
+                ordering => ordering,
+            }
+        }
+    }
+
     /// Creates a new `Duration` from the specified number of seconds
     /// represented as `f32`.
     ///
@@ -467,6 +1262,7 @@ impl Duration {
             seconds: seconds as i64,
             nanoseconds: ((seconds % 1.) * 1_000_000_000.) as i32,
         }
+        .debug_assert_invariant()
     }
 
     /// Get the number of fractional seconds in the duration.
@@ -481,38 +1277,227 @@ impl Duration {
         self.seconds as f32 + self.nanoseconds as f32 / 1_000_000_000.
     }
 
-    /// Create a new `Duration` with the given number of milliseconds.
+    /// Get the number of fractional seconds in the duration. This is an alias
+    /// of [`Duration::as_seconds_f64`], matching the name used by
+    /// `std::time::Duration`.
     ///
     /// ```rust
-    /// # use time::{Duration, prelude::*};
-    /// assert_eq!(Duration::milliseconds(1), 1_000.microseconds());
-    /// assert_eq!(Duration::milliseconds(-1), (-1_000).microseconds());
+    /// # use time::prelude::*;
+    /// assert_eq!(1.5.seconds().as_secs_f64(), 1.5);
+    /// assert_eq!((-1.5).seconds().as_secs_f64(), -1.5);
     /// ```
     #[inline(always)]
-    pub const fn milliseconds(milliseconds: i64) -> Self {
-        Self {
-            seconds: milliseconds / 1_000,
-            nanoseconds: ((milliseconds % 1_000) * 1_000_000) as i32,
-        }
+    pub fn as_secs_f64(self) -> f64 {
+        self.as_seconds_f64()
     }
 
-    /// Get the number of whole milliseconds in the duration.
+    /// Get the number of fractional seconds in the duration. This is an alias
+    /// of [`Duration::as_seconds_f32`], matching the name used by
+    /// `std::time::Duration`.
     ///
     /// ```rust
     /// # use time::prelude::*;
-    /// assert_eq!(1.seconds().whole_milliseconds(), 1_000);
-    /// assert_eq!((-1).seconds().whole_milliseconds(), -1_000);
-    /// assert_eq!(1.milliseconds().whole_milliseconds(), 1);
-    /// assert_eq!((-1).milliseconds().whole_milliseconds(), -1);
+    /// assert_eq!(1.5.seconds().as_secs_f32(), 1.5);
+    /// assert_eq!((-1.5).seconds().as_secs_f32(), -1.5);
     /// ```
     #[inline(always)]
-    pub const fn whole_milliseconds(self) -> i128 {
-        self.seconds as i128 * 1_000 + self.nanoseconds as i128 / 1_000_000
+    pub fn as_secs_f32(self) -> f32 {
+        self.as_seconds_f32()
     }
 
-    /// Get the number of milliseconds past the number of whole seconds.
-    ///
-    /// Always in the range `-1_000..1_000`.
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f64`, saturating to [`Duration::max_value`] or
+    /// [`Duration::min_value`] if the value is infinite or out of range. NaN
+    /// results in a zero duration.
+    #[inline]
+    fn saturating_seconds_f64(seconds: f64) -> Self {
+        if seconds.is_nan() {
+            Self::zero()
+        } else if seconds >= i64::max_value() as f64 {
+            Self::max_value()
+        } else if seconds < i64::min_value() as f64 {
+            Self::min_value()
+        } else {
+            Self::seconds_f64(seconds).debug_assert_invariant()
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f32`, saturating to [`Duration::max_value`] or
+    /// [`Duration::min_value`] if the value is infinite or out of range. NaN
+    /// results in a zero duration.
+    #[inline]
+    fn saturating_seconds_f32(seconds: f32) -> Self {
+        if seconds.is_nan() {
+            Self::zero()
+        } else if seconds >= i64::max_value() as f32 {
+            Self::max_value()
+        } else if seconds < i64::min_value() as f32 {
+            Self::min_value()
+        } else {
+            Self::seconds_f32(seconds)
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of days
+    /// represented as `f64`, saturating to [`Duration::max_value`] or
+    /// [`Duration::min_value`] if the value is infinite or out of range. NaN
+    /// results in a zero duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_days_f64(1.5), 36.hours());
+    /// assert_eq!(Duration::from_days_f64(-1.5), -36.hours());
+    /// assert_eq!(Duration::from_days_f64(std::f64::INFINITY), Duration::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn from_days_f64(days: f64) -> Self {
+        Self::saturating_seconds_f64(days * SECONDS_PER_DAY as f64)
+    }
+
+    /// Creates a new `Duration` from the specified number of hours
+    /// represented as `f64`, saturating to [`Duration::max_value`] or
+    /// [`Duration::min_value`] if the value is infinite or out of range. NaN
+    /// results in a zero duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_hours_f64(1.5), 90.minutes());
+    /// assert_eq!(Duration::from_hours_f64(-1.5), -90.minutes());
+    /// assert_eq!(Duration::from_hours_f64(std::f64::INFINITY), Duration::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn from_hours_f64(hours: f64) -> Self {
+        Self::saturating_seconds_f64(hours * SECONDS_PER_HOUR as f64)
+    }
+
+    /// Creates a new `Duration` from the specified number of minutes
+    /// represented as `f64`, saturating to [`Duration::max_value`] or
+    /// [`Duration::min_value`] if the value is infinite or out of range. NaN
+    /// results in a zero duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_minutes_f64(1.5), 90.seconds());
+    /// assert_eq!(Duration::from_minutes_f64(-1.5), -90.seconds());
+    /// assert_eq!(Duration::from_minutes_f64(std::f64::INFINITY), Duration::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn from_minutes_f64(minutes: f64) -> Self {
+        Self::saturating_seconds_f64(minutes * SECONDS_PER_MINUTE as f64)
+    }
+
+    /// Creates a new `Duration` from the specified number of days
+    /// represented as `f32`, saturating to [`Duration::max_value`] or
+    /// [`Duration::min_value`] if the value is infinite or out of range. NaN
+    /// results in a zero duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_days_f32(1.5), 36.hours());
+    /// assert_eq!(Duration::from_days_f32(-1.5), -36.hours());
+    /// assert_eq!(Duration::from_days_f32(std::f32::INFINITY), Duration::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn from_days_f32(days: f32) -> Self {
+        Self::saturating_seconds_f64(days as f64 * SECONDS_PER_DAY as f64)
+    }
+
+    /// Creates a new `Duration` from the specified number of hours
+    /// represented as `f32`, saturating to [`Duration::max_value`] or
+    /// [`Duration::min_value`] if the value is infinite or out of range. NaN
+    /// results in a zero duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_hours_f32(1.5), 90.minutes());
+    /// assert_eq!(Duration::from_hours_f32(-1.5), -90.minutes());
+    /// assert_eq!(Duration::from_hours_f32(std::f32::INFINITY), Duration::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn from_hours_f32(hours: f32) -> Self {
+        Self::saturating_seconds_f64(hours as f64 * SECONDS_PER_HOUR as f64)
+    }
+
+    /// Creates a new `Duration` from the specified number of minutes
+    /// represented as `f32`, saturating to [`Duration::max_value`] or
+    /// [`Duration::min_value`] if the value is infinite or out of range. NaN
+    /// results in a zero duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_minutes_f32(1.5), 90.seconds());
+    /// assert_eq!(Duration::from_minutes_f32(-1.5), -90.seconds());
+    /// assert_eq!(Duration::from_minutes_f32(std::f32::INFINITY), Duration::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn from_minutes_f32(minutes: f32) -> Self {
+        Self::saturating_seconds_f64(minutes as f64 * SECONDS_PER_MINUTE as f64)
+    }
+
+    /// Create a new `Duration` with the given number of milliseconds.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::milliseconds(1), 1_000.microseconds());
+    /// assert_eq!(Duration::milliseconds(-1), (-1_000).microseconds());
+    /// ```
+    #[inline(always)]
+    pub const fn milliseconds(milliseconds: i64) -> Self {
+        Self {
+            seconds: milliseconds / 1_000,
+            nanoseconds: ((milliseconds % 1_000) * 1_000_000) as i32,
+        }
+    }
+
+    /// Get the number of whole milliseconds in the duration, truncating toward zero.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.seconds().whole_milliseconds(), 1_000);
+    /// assert_eq!((-1).seconds().whole_milliseconds(), -1_000);
+    /// assert_eq!(1.milliseconds().whole_milliseconds(), 1);
+    /// assert_eq!((-1).milliseconds().whole_milliseconds(), -1);
+    /// ```
+    #[inline(always)]
+    pub const fn whole_milliseconds(self) -> i128 {
+        self.seconds as i128 * 1_000 + self.nanoseconds as i128 / 1_000_000
+    }
+
+    /// Get the number of whole milliseconds in the duration as an `i64`,
+    /// saturating to `i64::max_value()` or `i64::min_value()` if the value
+    /// does not fit.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1.seconds().whole_milliseconds_i64(), 1_000);
+    /// assert_eq!((-1).milliseconds().whole_milliseconds_i64(), -1);
+    /// assert_eq!(Duration::max_value().whole_milliseconds_i64(), i64::max_value());
+    /// assert_eq!(Duration::min_value().whole_milliseconds_i64(), i64::min_value());
+    /// ```
+    #[inline(always)]
+    pub fn whole_milliseconds_i64(self) -> i64 {
+        saturating_i128_to_i64(self.whole_milliseconds())
+    }
+
+    /// Get the number of whole milliseconds in the duration as an `i64`,
+    /// saturating if the value does not fit. This is an alias of
+    /// [`Duration::whole_milliseconds_i64`], matching the naming commonly used
+    /// by tracing and metrics backends.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1_500.milliseconds().as_millis_i64_saturating(), 1_500);
+    /// assert_eq!(Duration::max_value().as_millis_i64_saturating(), i64::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn as_millis_i64_saturating(self) -> i64 {
+        self.whole_milliseconds_i64()
+    }
+
+    /// Get the number of milliseconds past the number of whole seconds.
+    ///
+    /// Always in the range `-1_000..1_000`.
     ///
     /// ```rust
     /// # use time::prelude::*;
@@ -525,6 +1510,19 @@ impl Duration {
         (self.nanoseconds / 1_000_000) as i16
     }
 
+    /// Get the magnitude of the number of milliseconds past the number of whole
+    /// seconds, regardless of the sign of the duration.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.4.seconds().subsec_milliseconds_abs(), 400);
+    /// assert_eq!((-1.4).seconds().subsec_milliseconds_abs(), 400);
+    /// ```
+    #[inline(always)]
+    pub fn subsec_milliseconds_abs(self) -> u16 {
+        (self.nanoseconds / 1_000_000).abs() as u16
+    }
+
     /// Create a new `Duration` with the given number of microseconds.
     ///
     /// ```rust
@@ -540,7 +1538,48 @@ impl Duration {
         }
     }
 
-    /// Get the number of whole microseconds in the duration.
+    /// Create a new `Duration` with the given number of milliseconds,
+    /// saturating to [`Duration::min_value`] or [`Duration::max_value`] if out
+    /// of range.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_millis_i128(1_500), 1.5.seconds());
+    /// assert_eq!(Duration::from_millis_i128(i128::max_value()), Duration::max_value());
+    /// ```
+    #[inline]
+    pub fn from_millis_i128(milliseconds: i128) -> Self {
+        Self::saturating_scaled_i128(milliseconds, 1_000_000)
+    }
+
+    /// Create a new `Duration` with the given number of microseconds,
+    /// saturating to [`Duration::min_value`] or [`Duration::max_value`] if out
+    /// of range.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_micros_i128(1_500), 1_500_000.nanoseconds());
+    /// assert_eq!(Duration::from_micros_i128(i128::max_value()), Duration::max_value());
+    /// ```
+    #[inline]
+    pub fn from_micros_i128(microseconds: i128) -> Self {
+        Self::saturating_scaled_i128(microseconds, 1_000)
+    }
+
+    /// Create a `Duration` from `value` units of `unit_nanoseconds` each,
+    /// saturating if out of range.
+    #[inline]
+    fn saturating_scaled_i128(value: i128, unit_nanoseconds: i128) -> Self {
+        match value.checked_mul(unit_nanoseconds) {
+            Some(nanoseconds) => {
+                Self::saturating_nanoseconds_i128(nanoseconds).debug_assert_invariant()
+            }
+            None if value > 0 => Self::max_value(),
+            None => Self::min_value(),
+        }
+    }
+
+    /// Get the number of whole microseconds in the duration, truncating toward zero.
     ///
     /// ```rust
     /// # use time::prelude::*;
@@ -554,6 +1593,22 @@ impl Duration {
         self.seconds as i128 * 1_000_000 + self.nanoseconds as i128 / 1_000
     }
 
+    /// Get the number of whole microseconds in the duration as an `i64`,
+    /// saturating to `i64::max_value()` or `i64::min_value()` if the value
+    /// does not fit.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1.milliseconds().whole_microseconds_i64(), 1_000);
+    /// assert_eq!((-1).microseconds().whole_microseconds_i64(), -1);
+    /// assert_eq!(Duration::max_value().whole_microseconds_i64(), i64::max_value());
+    /// assert_eq!(Duration::min_value().whole_microseconds_i64(), i64::min_value());
+    /// ```
+    #[inline(always)]
+    pub fn whole_microseconds_i64(self) -> i64 {
+        saturating_i128_to_i64(self.whole_microseconds())
+    }
+
     /// Get the number of microseconds past the number of whole seconds.
     ///
     /// Always in the range `-1_000_000..1_000_000`.
@@ -568,6 +1623,19 @@ impl Duration {
         self.nanoseconds / 1_000
     }
 
+    /// Get the magnitude of the number of microseconds past the number of whole
+    /// seconds, regardless of the sign of the duration.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.0004.seconds().subsec_microseconds_abs(), 400);
+    /// assert_eq!((-1.0004).seconds().subsec_microseconds_abs(), 400);
+    /// ```
+    #[inline(always)]
+    pub fn subsec_microseconds_abs(self) -> u32 {
+        (self.nanoseconds / 1_000).abs() as u32
+    }
+
     /// Create a new `Duration` with the given number of nanoseconds.
     ///
     /// ```rust
@@ -609,6 +1677,61 @@ impl Duration {
         self.seconds as i128 * 1_000_000_000 + self.nanoseconds as i128
     }
 
+    /// Get the number of nanoseconds in the duration as an `i64`, saturating
+    /// to `i64::max_value()` or `i64::min_value()` if the value does not fit.
+    /// An `i64` holds roughly 292 years of nanoseconds.
+    ///
+    /// This is useful for APIs that record nanoseconds as an `i64`, such as
+    /// tracing spans, and avoids the silent truncation of an `as` cast.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1.5.seconds().whole_nanoseconds_i64(), 1_500_000_000);
+    /// assert_eq!((-1).nanoseconds().whole_nanoseconds_i64(), -1);
+    /// assert_eq!((300 * 365).days().whole_nanoseconds_i64(), i64::max_value());
+    /// ```
+    #[inline(always)]
+    pub fn whole_nanoseconds_i64(self) -> i64 {
+        saturating_i128_to_i64(self.whole_nanoseconds())
+    }
+
+    /// Get the number of nanoseconds in the duration as an `i64`, saturating
+    /// if the value does not fit. This is an alias of
+    /// [`Duration::whole_nanoseconds_i64`], matching the naming commonly used
+    /// by tracing and metrics backends.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1.5.seconds().as_nanos_i64_saturating(), 1_500_000_000);
+    /// assert_eq!(Duration::min_value().as_nanos_i64_saturating(), i64::min_value());
+    /// ```
+    #[inline(always)]
+    pub fn as_nanos_i64_saturating(self) -> i64 {
+        self.whole_nanoseconds_i64()
+    }
+
+    /// Get the number of nanoseconds in the duration, returning `None` if it
+    /// cannot be represented by an `i128`.
+    ///
+    /// This cannot currently occur, and is identical to
+    /// [`Duration::whole_nanoseconds`]. It exists to guard against any future
+    /// widening of the range of `Duration`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1.microseconds().checked_whole_nanoseconds(), Some(1_000));
+    /// assert_eq!(
+    ///     Duration::max_value().checked_whole_nanoseconds(),
+    ///     Some(Duration::max_value().whole_nanoseconds())
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn checked_whole_nanoseconds(self) -> Option<i128> {
+        (self.seconds as i128)
+            .checked_mul(1_000_000_000)?
+            .checked_add(self.nanoseconds as i128)
+    }
+
     /// Get the number of nanoseconds past the number of whole seconds.
     ///
     /// The returned value will always be in the range
@@ -624,866 +1747,5849 @@ impl Duration {
         self.nanoseconds
     }
 
-    /// Computes `self + rhs`, returning `None` if an overflow occurred.
+    /// Get the magnitude of the number of nanoseconds past the number of whole
+    /// seconds, regardless of the sign of the duration.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.000_000_4.seconds().subsec_nanoseconds_abs(), 400);
+    /// assert_eq!((-1.000_000_4).seconds().subsec_nanoseconds_abs(), 400);
+    /// ```
+    #[inline(always)]
+    pub fn subsec_nanoseconds_abs(self) -> u32 {
+        self.nanoseconds.abs() as u32
+    }
+
+    /// Pack the `Duration` into a single `i128`, suitable for compact storage.
+    ///
+    /// The packed value is the total number of nanoseconds in the duration.
+    /// This representation is stable, and ordering of the packed values
+    /// matches ordering of the durations. Use [`Duration::from_packed_i128`]
+    /// to obtain the original value.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.5.seconds().to_packed_i128(), 1_500_000_000);
+    /// assert_eq!((-1).nanoseconds().to_packed_i128(), -1);
+    /// ```
+    #[inline(always)]
+    pub const fn to_packed_i128(self) -> i128 {
+        self.whole_nanoseconds()
+    }
+
+    /// Unpack a `Duration` previously packed with [`Duration::to_packed_i128`].
+    ///
+    /// An error is returned if the value is outside the range of a
+    /// `Duration`, which cannot occur for values produced by
+    /// `to_packed_i128`.
     ///
     /// ```rust
     /// # use time::{Duration, prelude::*};
-    /// assert_eq!(5.seconds().checked_add(5.seconds()), Some(10.seconds()));
-    /// assert_eq!(Duration::max_value().checked_add(1.nanoseconds()), None);
-    /// assert_eq!((-5).seconds().checked_add(5.seconds()), Some(0.seconds()));
+    /// assert_eq!(Duration::from_packed_i128(1_500_000_000), Ok(1.5.seconds()));
+    /// assert_eq!(Duration::from_packed_i128(-1), Ok((-1).nanoseconds()));
+    /// assert!(Duration::from_packed_i128(i128::max_value()).is_err());
     /// ```
     #[inline]
-    pub fn checked_add(self, rhs: Self) -> Option<Self> {
-        let mut seconds = self.seconds.checked_add(rhs.seconds)?;
-        let mut nanoseconds = self.nanoseconds + rhs.nanoseconds;
-
-        if nanoseconds >= 1_000_000_000 || seconds < 0 && nanoseconds > 0 {
-            nanoseconds -= 1_000_000_000;
-            seconds = seconds.checked_add(1)?;
-        } else if nanoseconds <= -1_000_000_000 || seconds > 0 && nanoseconds < 0 {
-            nanoseconds += 1_000_000_000;
-            seconds = seconds.checked_sub(1)?;
+    pub fn from_packed_i128(packed: i128) -> Result<Self, ConversionRangeError> {
+        Ok(Self {
+            seconds: (packed / 1_000_000_000)
+                .try_into()
+                .map_err(|_| ConversionRangeError::new())?,
+            nanoseconds: (packed % 1_000_000_000) as i32,
         }
+        .debug_assert_invariant())
+    }
 
-        // Ensure that the signs match _unless_ one of them is zero.
-        debug_assert_ne!(seconds.signum() * nanoseconds.signum() as i64, -1);
-        debug_assert!((-999_999_999..1_000_000_000).contains(&nanoseconds));
-
-        Some(Self {
-            seconds,
-            nanoseconds,
-        })
+    /// Encode the duration as 12 bytes, suitable for a fixed-size wire format.
+    ///
+    /// The first eight bytes are the whole seconds as a little-endian `i64`,
+    /// and the remaining four bytes are the subsecond nanoseconds as a
+    /// little-endian `i32`. Both fields carry the sign of the duration. Use
+    /// [`Duration::from_le_bytes`] to decode the value.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(
+    ///     1.5.seconds().to_le_bytes(),
+    ///     [1, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x65, 0xcd, 0x1d]
+    /// );
+    /// assert_eq!(
+    ///     (-1).nanoseconds().to_le_bytes(),
+    ///     [0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_le_bytes(self) -> [u8; 12] {
+        let mut bytes = [0; 12];
+        bytes[..8].copy_from_slice(&self.seconds.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.nanoseconds.to_le_bytes());
+        bytes
     }
 
-    /// Computes `self - rhs`, returning `None` if an overflow occurred.
+    /// Decode a duration previously encoded with [`Duration::to_le_bytes`].
+    ///
+    /// An error is returned if the nanoseconds are not less than one second
+    /// in magnitude, or if the seconds and nanoseconds have differing signs.
+    /// This cannot occur for values produced by `to_le_bytes`.
     ///
     /// ```rust
     /// # use time::{Duration, prelude::*};
-    /// assert_eq!(5.seconds().checked_sub(5.seconds()), Some(Duration::zero()));
-    /// assert_eq!(Duration::min_value().checked_sub(1.nanoseconds()), None);
-    /// assert_eq!(5.seconds().checked_sub(10.seconds()), Some((-5).seconds()));
+    /// assert_eq!(
+    ///     Duration::from_le_bytes([1, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x65, 0xcd, 0x1d]),
+    ///     Ok(1.5.seconds())
+    /// );
+    /// assert!(Duration::from_le_bytes([1, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]).is_err());
     /// ```
-    #[inline(always)]
-    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
-        self.checked_add(-rhs)
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 12]) -> Result<Self, ConversionRangeError> {
+        let mut seconds = [0; 8];
+        seconds.copy_from_slice(&bytes[..8]);
+        let mut nanoseconds = [0; 4];
+        nanoseconds.copy_from_slice(&bytes[8..]);
+
+        let duration = Self {
+            seconds: i64::from_le_bytes(seconds),
+            nanoseconds: i32::from_le_bytes(nanoseconds),
+        };
+
+        if duration.is_canonical() {
+            Ok(duration)
+        } else {
+            Err(ConversionRangeError::new())
+        }
     }
 
-    /// Computes `self * rhs`, returning `None` if an overflow occurred.
+    /// Create a new `Duration` from the fields of a POSIX `timespec`. The
+    /// nanoseconds are not required to be in any particular range, and will
+    /// be carried into the seconds as necessary.
+    ///
+    /// The result saturates to [`Duration::min_value`] or
+    /// [`Duration::max_value`] if carrying the nanoseconds would overflow the
+    /// seconds.
     ///
     /// ```rust
     /// # use time::{Duration, prelude::*};
-    /// assert_eq!(5.seconds().checked_mul(2), Some(10.seconds()));
-    /// assert_eq!(5.seconds().checked_mul(-2), Some((-10).seconds()));
-    /// assert_eq!(5.seconds().checked_mul(0), Some(0.seconds()));
-    /// assert_eq!(Duration::max_value().checked_mul(2), None);
-    /// assert_eq!(Duration::min_value().checked_mul(2), None);
+    /// assert_eq!(Duration::from_timespec(1, 500_000_000), 1.5.seconds());
+    /// assert_eq!(Duration::from_timespec(-2, 500_000_000), (-1.5).seconds());
+    /// assert_eq!(Duration::from_timespec(0, 2_500_000_000), 2.5.seconds());
+    /// assert_eq!(
+    ///     Duration::from_timespec(i64::max_value(), 1_000_000_000),
+    ///     Duration::max_value()
+    /// );
     /// ```
-    #[inline(always)]
-    pub fn checked_mul(self, rhs: i32) -> Option<Self> {
-        // Multiply nanoseconds as i64, because it cannot overflow that way.
-        let total_nanos = self.nanoseconds as i64 * rhs as i64;
-        let extra_secs = total_nanos / 1_000_000_000;
-        let nanoseconds = (total_nanos % 1_000_000_000) as i32;
-        let seconds = self
-            .seconds
-            .checked_mul(rhs as i64)?
-            .checked_add(extra_secs)?;
-
-        Some(Self {
-            seconds,
-            nanoseconds,
-        })
+    #[inline]
+    pub fn from_timespec(seconds: i64, nanoseconds: i64) -> Self {
+        // Both terms are less than 2^94 in magnitude, so the sum cannot
+        // overflow an `i128`.
+        Self::saturating_nanoseconds_i128(seconds as i128 * 1_000_000_000 + nanoseconds as i128)
+            .debug_assert_invariant()
     }
 
-    /// Computes `self / rhs`, returning `None` if `rhs == 0`.
+    /// Convert the `Duration` to the fields of a POSIX `timespec`.
+    ///
+    /// The nanoseconds are always in the range `0..1_000_000_000`, with the
+    /// seconds carrying the sign of the duration. Returns `None` if the
+    /// seconds do not fit in an `i64`, which is only the case for durations
+    /// less than `i64::min_value()` seconds, such as [`Duration::min_value`].
     ///
     /// ```rust
-    /// # use time::prelude::*;
-    /// assert_eq!(10.seconds().checked_div(2), Some(5.seconds()));
-    /// assert_eq!(10.seconds().checked_div(-2), Some((-5).seconds()));
-    /// assert_eq!(1.seconds().checked_div(0), None);
-    #[inline(always)]
-    pub fn checked_div(self, rhs: i32) -> Option<Self> {
-        if rhs == 0 {
-            return None;
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1.5.seconds().to_timespec(), Some((1, 500_000_000)));
+    /// assert_eq!((-1.5).seconds().to_timespec(), Some((-2, 500_000_000)));
+    /// assert_eq!((-1).seconds().to_timespec(), Some((-1, 0)));
+    /// assert_eq!(Duration::min_value().to_timespec(), None);
+    /// ```
+    #[inline]
+    pub fn to_timespec(self) -> Option<(i64, i64)> {
+        if self.nanoseconds < 0 {
+            Some((
+                self.seconds.checked_sub(1)?,
+                self.nanoseconds as i64 + 1_000_000_000,
+            ))
+        } else {
+            Some((self.seconds, self.nanoseconds as i64))
         }
-
-        let seconds = self.seconds / (rhs as i64);
-        let carry = self.seconds - seconds * (rhs as i64);
-        let extra_nanos = carry * 1_000_000_000 / (rhs as i64);
-        let nanoseconds = self.nanoseconds / rhs + (extra_nanos as i32);
-
-        Some(Self {
-            seconds,
-            nanoseconds,
-        })
     }
 
-    /// Runs a closure, returning the duration of time it took to run. The
-    /// return value of the closure is provided in the second part of the tuple.
-    #[inline(always)]
-    #[cfg(std)]
-    #[cfg_attr(docs, doc(cfg(feature = "std")))]
-    pub fn time_fn<T>(f: impl FnOnce() -> T) -> (Self, T) {
-        let start = Instant::now();
-        let return_value = f();
-        let end = Instant::now();
+    /// Decompose the duration into whole weeks, days, hours, minutes, seconds,
+    /// and nanoseconds. Every component carries the sign of the duration, and
+    /// all but the weeks are less than the next larger unit in magnitude.
+    ///
+    /// This is the inverse of [`Duration::from_parts`].
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// let duration = 1.weeks() + 2.days() + 3.hours() + 4.minutes() + 5.seconds() + 6.nanoseconds();
+    /// assert_eq!(duration.to_parts(), (1, 2, 3, 4, 5, 6));
+    /// assert_eq!((-duration).to_parts(), (-1, -2, -3, -4, -5, -6));
+    /// ```
+    #[inline]
+    pub const fn to_parts(self) -> (i64, i64, i64, i64, i64, i64) {
+        (
+            self.seconds / SECONDS_PER_WEEK,
+            self.seconds % SECONDS_PER_WEEK / SECONDS_PER_DAY,
+            self.seconds % SECONDS_PER_DAY / SECONDS_PER_HOUR,
+            self.seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE,
+            self.seconds % SECONDS_PER_MINUTE,
+            self.nanoseconds as i64,
+        )
+    }
+
+    /// Decompose the duration into whole weeks, days, hours, minutes, and
+    /// seconds, with the sign returned separately. Any fractional second is
+    /// truncated.
+    ///
+    /// The first element is `true` if the duration is negative after
+    /// truncation to whole seconds. The remaining components are magnitudes.
+    /// Every component except the weeks is less than the next larger unit. A
+    /// separate flag is used rather than signed weeks so that durations
+    /// shorter than a week keep their sign.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// let duration = 10.days() + 5.hours() + 30.minutes() + 15.5.seconds();
+    /// assert_eq!(duration.to_wdhms(), (false, 1, 3, 5, 30, 15));
+    /// assert_eq!((-duration).to_wdhms(), (true, 1, 3, 5, 30, 15));
+    /// assert_eq!((-90).minutes().to_wdhms(), (true, 0, 0, 1, 30, 0));
+    /// ```
+    #[inline]
+    pub fn to_wdhms(self) -> (bool, u64, u8, u8, u8, u8) {
+        let seconds = (self.seconds as i128).abs() as u64;
+        (
+            self.seconds < 0,
+            seconds / SECONDS_PER_WEEK as u64,
+            (seconds % SECONDS_PER_WEEK as u64 / SECONDS_PER_DAY as u64) as u8,
+            (seconds % SECONDS_PER_DAY as u64 / SECONDS_PER_HOUR as u64) as u8,
+            (seconds % SECONDS_PER_HOUR as u64 / SECONDS_PER_MINUTE as u64) as u8,
+            (seconds % SECONDS_PER_MINUTE as u64) as u8,
+        )
+    }
+
+    /// Create a `Duration` by summing the provided weeks, days, hours, minutes,
+    /// seconds, and nanoseconds, saturating to [`Duration::min_value`] or
+    /// [`Duration::max_value`] if the total is out of range.
+    ///
+    /// Components are not required to share a sign or to be less than the
+    /// next larger unit; they are summed literally. The components returned by
+    /// [`Duration::to_parts`] always round-trip losslessly.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(
+    ///     Duration::from_parts(1, 2, 3, 4, 5, 6),
+    ///     1.weeks() + 2.days() + 3.hours() + 4.minutes() + 5.seconds() + 6.nanoseconds()
+    /// );
+    /// assert_eq!(Duration::from_parts(0, 0, 1, -30, 0, 0), 30.minutes());
+    /// assert_eq!(Duration::from_parts(0, 0, 0, 0, 90, 0), 1.5.minutes());
+    /// assert_eq!(
+    ///     Duration::from_parts(i64::max_value(), 0, 0, 0, 0, 0),
+    ///     Duration::max_value()
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_parts(
+        weeks: i64,
+        days: i64,
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        nanoseconds: i64,
+    ) -> Self {
+        // Each term is less than 2^113 in magnitude, so the sum cannot overflow
+        // an `i128`.
+        let seconds = weeks as i128 * SECONDS_PER_WEEK as i128
+            + days as i128 * SECONDS_PER_DAY as i128
+            + hours as i128 * SECONDS_PER_HOUR as i128
+            + minutes as i128 * SECONDS_PER_MINUTE as i128
+            + seconds as i128;
+        Self::saturating_nanoseconds_i128(seconds * 1_000_000_000 + nanoseconds as i128)
+            .debug_assert_invariant()
+    }
+
+    /// Create a `Duration` by summing the provided hours, minutes, and seconds,
+    /// returning `None` if the total number of seconds does not fit in an
+    /// `i64`.
+    ///
+    /// As with [`Duration::from_parts`], components are not required to share
+    /// a sign or to be less than the next larger unit.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::checked_from_hms(1, 30, 15), Some(5_415.seconds()));
+    /// assert_eq!(Duration::checked_from_hms(1, -30, 0), Some(30.minutes()));
+    /// assert_eq!(Duration::checked_from_hms(i64::max_value(), 0, 0), None);
+    /// ```
+    #[inline]
+    pub fn checked_from_hms(hours: i64, minutes: i64, seconds: i64) -> Option<Self> {
+        // Each term is less than 2^76 in magnitude, so the sum cannot overflow
+        // an `i128`.
+        let seconds = hours as i128 * SECONDS_PER_HOUR as i128
+            + minutes as i128 * SECONDS_PER_MINUTE as i128
+            + seconds as i128;
+
+        if seconds > i64::max_value() as i128 || seconds < i64::min_value() as i128 {
+            None
+        } else {
+            Some(Self::seconds(seconds as i64).debug_assert_invariant())
+        }
+    }
 
-        (end - start, return_value)
+    /// Get the number of audio samples spanned by the duration at the provided
+    /// sample rate, rounded to the nearest sample. Halfway cases are rounded
+    /// away from zero.
+    ///
+    /// Returns `None` if `sample_rate_hz` is zero or the number of samples
+    /// does not fit in an `i64`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1.seconds().to_samples(44_100), Some(44_100));
+    /// assert_eq!((-10).milliseconds().to_samples(44_100), Some(-441));
+    /// assert_eq!(1.nanoseconds().to_samples(44_100), Some(0));
+    /// assert_eq!(1.seconds().to_samples(0), None);
+    /// assert_eq!(Duration::max_value().to_samples(48_000), None);
+    /// ```
+    #[inline]
+    pub fn to_samples(self, sample_rate_hz: u32) -> Option<i64> {
+        if sample_rate_hz == 0 {
+            return None;
+        }
+
+        // The magnitude of the product is less than 2^125, so it cannot
+        // overflow an `i128`.
+        div_round_i128(
+            self.whole_nanoseconds() * sample_rate_hz as i128,
+            1_000_000_000,
+        )
+        .try_into()
+        .ok()
     }
-}
 
-/// Functions that have been renamed or had signatures changed since v0.1. As
-/// such, they are deprecated.
-#[cfg(v01_deprecated_api)]
-#[cfg_attr(tarpaulin, skip)]
-#[allow(clippy::missing_docs_in_private_items, clippy::missing_const_for_fn)]
-impl Duration {
-    #[inline(always)]
-    #[deprecated(since = "0.2.0", note = "Use the `whole_weeks` function")]
-    pub fn num_weeks(&self) -> i64 {
-        self.whole_weeks()
+    /// Create a new `Duration` spanning the provided number of audio samples
+    /// at the provided sample rate, rounded to the nearest nanosecond. Halfway
+    /// cases are rounded away from zero.
+    ///
+    /// Returns `None` if `sample_rate_hz` is zero.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_samples(44_100, 44_100), Some(1.seconds()));
+    /// assert_eq!(Duration::from_samples(-441, 44_100), Some((-10).milliseconds()));
+    /// assert_eq!(Duration::from_samples(1, 44_100), Some(22_676.nanoseconds()));
+    /// assert_eq!(Duration::from_samples(1, 0), None);
+    /// ```
+    #[inline]
+    pub fn from_samples(samples: i64, sample_rate_hz: u32) -> Option<Self> {
+        if sample_rate_hz == 0 {
+            return None;
+        }
+
+        // A sample rate of at least 1 Hz guarantees that the result is in range.
+        Some(
+            Self::nanoseconds_i128(div_round_i128(
+                samples as i128 * 1_000_000_000,
+                sample_rate_hz as i128,
+            ))
+            .debug_assert_invariant(),
+        )
     }
 
-    #[inline(always)]
-    #[deprecated(since = "0.2.0", note = "Use the `whole_days` function")]
-    pub fn num_days(&self) -> i64 {
-        self.whole_days()
+    /// Get the number of video frames spanned by the duration at the frame rate
+    /// `fps_num / fps_den`, rounded to the nearest frame. Halfway cases are
+    /// rounded away from zero.
+    ///
+    /// Rational frame rates such as 24000/1001 (23.976 fps) are computed
+    /// exactly.
+    ///
+    /// Returns `None` if either part of the frame rate is zero or the number
+    /// of frames does not fit in an `i64`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(1.seconds().to_frames(25, 1), Some(25));
+    /// assert_eq!(1.seconds().to_frames(24_000, 1_001), Some(24));
+    /// assert_eq!(1.hours().to_frames(30_000, 1_001), Some(107_892));
+    /// assert_eq!(1.seconds().to_frames(24, 0), None);
+    /// assert_eq!(Duration::max_value().to_frames(60, 1), None);
+    /// ```
+    #[inline]
+    pub fn to_frames(self, fps_num: u32, fps_den: u32) -> Option<i64> {
+        if fps_num == 0 || fps_den == 0 {
+            return None;
+        }
+
+        // The magnitude of the product is less than 2^125, so it cannot
+        // overflow an `i128`.
+        div_round_i128(
+            self.whole_nanoseconds() * fps_num as i128,
+            fps_den as i128 * 1_000_000_000,
+        )
+        .try_into()
+        .ok()
     }
 
-    #[inline(always)]
-    #[deprecated(since = "0.2.0", note = "Use the `whole_hours` function")]
-    pub fn num_hours(&self) -> i64 {
-        self.whole_hours()
+    /// Create a new `Duration` spanning the provided number of video frames at
+    /// the frame rate `fps_num / fps_den`, rounded to the nearest nanosecond.
+    /// Halfway cases are rounded away from zero.
+    ///
+    /// Returns `None` if either part of the frame rate is zero or the
+    /// resulting duration is out of range.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::from_frames(25, 25, 1), Some(1.seconds()));
+    /// assert_eq!(Duration::from_frames(24, 24_000, 1_001), Some(1_001.milliseconds()));
+    /// assert_eq!(Duration::from_frames(1, 0, 1), None);
+    /// assert_eq!(Duration::from_frames(i64::max_value(), 1, 2), None);
+    /// ```
+    #[inline]
+    pub fn from_frames(frames: i64, fps_num: u32, fps_den: u32) -> Option<Self> {
+        if fps_num == 0 || fps_den == 0 {
+            return None;
+        }
+
+        // The product is less than 2^126 in magnitude, so it cannot overflow.
+        Self::from_packed_i128(div_round_i128(
+            frames as i128 * fps_den as i128 * 1_000_000_000,
+            fps_num as i128,
+        ))
+        .ok()
     }
 
-    #[inline(always)]
-    #[deprecated(since = "0.2.0", note = "Use the `whole_minutes` function")]
-    pub fn num_minutes(&self) -> i64 {
-        self.whole_minutes()
+    /// Obtain an iterator over `start`, `start + step`, `start + 2 * step`, and
+    /// so on, stopping before `end` is reached.
+    ///
+    /// A negative step produces a descending sequence, stopping before reaching
+    /// an `end` that is less than `start`. A zero step, or an `end` in the
+    /// opposite direction of the step, produces an empty iterator. Iteration
+    /// also stops if the next duration would overflow.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// let ticks: Vec<_> = Duration::step_by(0.seconds(), 1.seconds(), 250.milliseconds()).collect();
+    /// assert_eq!(
+    ///     ticks,
+    ///     [0.seconds(), 250.milliseconds(), 500.milliseconds(), 750.milliseconds()]
+    /// );
+    ///
+    /// let ticks: Vec<_> = Duration::step_by(1.seconds(), 0.seconds(), (-500).milliseconds()).collect();
+    /// assert_eq!(ticks, [1.seconds(), 500.milliseconds()]);
+    ///
+    /// assert_eq!(Duration::step_by(0.seconds(), 1.seconds(), 0.seconds()).count(), 0);
+    /// ```
+    #[inline]
+    pub fn step_by(start: Self, end: Self, step: Self) -> impl Iterator<Item = Self> {
+        StepBy {
+            next: if step.is_zero() { None } else { Some(start) },
+            end,
+            step,
+        }
     }
 
-    #[allow(clippy::missing_const_for_fn)]
-    #[inline(always)]
-    #[deprecated(since = "0.2.0", note = "Use the `whole_seconds` function")]
-    pub fn num_seconds(&self) -> i64 {
-        self.whole_seconds()
+    /// Get the largest of the provided durations, or `None` if there are none.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(
+    ///     Duration::max_of(vec![1.seconds(), (-5).seconds(), 3.seconds()]),
+    ///     Some(3.seconds())
+    /// );
+    /// assert_eq!(Duration::max_of(Vec::new()), None);
+    /// ```
+    #[inline]
+    pub fn max_of(durations: impl IntoIterator<Item = Self>) -> Option<Self> {
+        durations.into_iter().max()
     }
 
-    /// [`Duration::whole_milliseconds`] returns an `i128`, rather than
-    /// panicking on overflow. To avoid panicking, this method currently limits
-    /// the value to the range `i64::min_value()..=i64::max_value()`.
+    /// Get the smallest of the provided durations, or `None` if there are none.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(
+    ///     Duration::min_of(vec![1.seconds(), (-5).seconds(), 3.seconds()]),
+    ///     Some((-5).seconds())
+    /// );
+    /// assert_eq!(Duration::min_of(Vec::new()), None);
+    /// ```
     #[inline]
-    #[deprecated(
-        since = "0.2.0",
-        note = "Use the `whole_milliseconds` function. The value is clamped between \
-                `i64::min_value()` and `i64::max_value()`."
-    )]
-    pub fn num_milliseconds(&self) -> i64 {
-        let millis = self.whole_milliseconds();
+    pub fn min_of(durations: impl IntoIterator<Item = Self>) -> Option<Self> {
+        durations.into_iter().min()
+    }
 
-        if millis > i64::max_value() as i128 {
-            return i64::max_value();
+    /// Get the duration halfway between `a` and `b`, rounded towards zero to
+    /// the nearest nanosecond. This never overflows, regardless of the inputs.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::midpoint(1.seconds(), 3.seconds()), 2.seconds());
+    /// assert_eq!(Duration::midpoint((-1).seconds(), 2.seconds()), 500.milliseconds());
+    /// assert_eq!(
+    ///     Duration::midpoint(Duration::max_value(), Duration::max_value()),
+    ///     Duration::max_value()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn midpoint(a: Self, b: Self) -> Self {
+        // The sum of any two durations fits comfortably in an `i128`.
+        Self::nanoseconds_i128((a.whole_nanoseconds() + b.whole_nanoseconds()) / 2)
+    }
+
+    /// Split the duration into `n` parts that sum exactly to the original.
+    ///
+    /// Returns the duration of a base part and the number of parts that must
+    /// be one nanosecond longer than the base. The base is rounded towards
+    /// negative infinity, so the remainder is always in the range `0..n`.
+    ///
+    /// This method panics if `n` is zero.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.seconds().split(3), (333_333_333.nanoseconds(), 1));
+    /// assert_eq!((-1).seconds().split(3), ((-333_333_334).nanoseconds(), 2));
+    /// assert_eq!(1.seconds().split(4), (250.milliseconds(), 0));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use time::prelude::*;
+    /// 1.seconds().split(0); // panics
+    /// ```
+    #[inline]
+    pub fn split(self, n: u32) -> (Self, u32) {
+        assert!(n != 0, "cannot split a duration into zero parts");
+
+        let nanoseconds = self.whole_nanoseconds();
+        let n = n as i128;
+        let mut base = nanoseconds / n;
+        let mut remainder = nanoseconds % n;
+        if remainder < 0 {
+            base -= 1;
+            remainder += n;
         }
 
-        if millis < i64::min_value() as i128 {
-            return i64::min_value();
+        // The base is no larger in magnitude than the original duration, and
+        // the remainder is less than `n`, so neither can overflow.
+        (Self::nanoseconds_i128(base), remainder as u32)
+    }
+
+    /// Divide the duration into `rhs` parts of a whole number of seconds,
+    /// returning the length of each part and the duration left over.
+    ///
+    /// The quotient is truncated towards zero, so the remainder has the same
+    /// sign as `self` and is less than `rhs` seconds in magnitude. The
+    /// original duration is always equal to `quotient * rhs + remainder`.
+    ///
+    /// The only quotient that cannot be represented is that of
+    /// [`Duration::min_value`] divided by `-1`. It saturates to
+    /// `i64::max_value()` seconds, with the remainder adjusted to match.
+    ///
+    /// This method panics if `rhs` is zero.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(100.seconds().div_rem_i64(7), (14.seconds(), 2.seconds()));
+    /// assert_eq!((-100).seconds().div_rem_i64(7), ((-14).seconds(), (-2).seconds()));
+    /// assert_eq!(1.5.seconds().div_rem_i64(2), (0.seconds(), 1.5.seconds()));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use time::prelude::*;
+    /// 1.seconds().div_rem_i64(0); // panics
+    /// ```
+    #[inline]
+    pub fn div_rem_i64(self, rhs: i64) -> (Self, Self) {
+        assert!(rhs != 0, "attempt to divide a duration by zero");
+
+        let nanoseconds = self.whole_nanoseconds();
+        let divisor = rhs as i128 * 1_000_000_000;
+        let quotient = (nanoseconds / divisor).min(i64::max_value() as i128);
+        let remainder = nanoseconds - quotient * divisor;
+
+        // The remainder is no larger in magnitude than the original duration,
+        // except when the quotient saturates. Even then, it is less than two
+        // seconds in magnitude.
+        (
+            Self::seconds(quotient as i64),
+            Self::nanoseconds_i128(remainder),
+        )
+    }
+
+    /// Convert the duration to a whole number of the provided unit, returning
+    /// the whole number and the duration left over.
+    ///
+    /// The whole number is truncated towards zero, so the remainder has the
+    /// same sign as `self` and is less than one unit in magnitude. If the
+    /// whole number does not fit in an `i64`, it saturates, with the remainder
+    /// holding the rest of the duration.
+    ///
+    /// ```rust
+    /// # use time::{units::Unit, prelude::*};
+    /// assert_eq!(150.seconds().convert_with_remainder(Unit::Minute), (2, 30.seconds()));
+    /// assert_eq!(
+    ///     (-1_500).milliseconds().convert_with_remainder(Unit::Second),
+    ///     (-1, (-500).milliseconds())
+    /// );
+    /// ```
+    #[inline]
+    pub fn convert_with_remainder(self, unit: Unit) -> (i64, Self) {
+        let nanoseconds = self.whole_nanoseconds();
+        let unit = unit.nanoseconds();
+        let whole = (nanoseconds / unit)
+            .max(i64::min_value() as i128)
+            .min(i64::max_value() as i128);
+
+        // The remainder is never larger in magnitude than the original
+        // duration, so it cannot overflow.
+        (
+            whole as i64,
+            Self::nanoseconds_i128(nanoseconds - whole * unit),
+        )
+    }
+
+    /// Convert the `Duration` to a `std::time::Duration`, rounding according
+    /// to `mode`.
+    ///
+    /// Unlike the [`TryFrom`] implementation, a negative duration results in
+    /// [`Error::NegativeDuration`](crate::Error::NegativeDuration) rather than
+    /// a generic [`ConversionRangeError`], allowing the two to be
+    /// distinguished.
+    ///
+    /// ```rust
+    /// # use time::{Error, RoundMode, prelude::*};
+    /// assert_eq!(1.5.seconds().to_std_rounded(RoundMode::Truncate), Ok(1.5.std_seconds()));
+    /// match (-1).nanoseconds().to_std_rounded(RoundMode::Truncate) {
+    ///     Err(Error::NegativeDuration(_)) => {}
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[inline]
+    pub fn to_std_rounded(self, mode: RoundMode) -> Result<StdDuration, Error> {
+        // `std::time::Duration` has the same precision, so no mode has any
+        // effect.
+        let _ = mode;
+
+        if self.is_negative() {
+            return Err(NegativeDurationError::new().into());
         }
 
-        millis as i64
+        Ok(StdDuration::try_from(self)?)
     }
 
-    /// [`Duration::whole_microseconds`] returns an `i128` rather than returning
-    /// `None` on `i64` overflow.
-    #[inline(always)]
-    #[deprecated(since = "0.2.0", note = "Use the `whole_microseconds` function")]
-    pub fn num_microseconds(&self) -> Option<i64> {
-        let micros = self.whole_microseconds();
+    /// Convert the `Duration` to a `std::time::Duration`, clamping it to the
+    /// range from zero to `max`. This is suitable for passing to APIs that
+    /// cannot accept a negative or arbitrarily large duration, such as
+    /// timeouts.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!((-1).seconds().to_std_clamped(1.std_hours()), 0.std_seconds());
+    /// assert_eq!(1.seconds().to_std_clamped(1.std_hours()), 1.std_seconds());
+    /// assert_eq!(2.hours().to_std_clamped(1.std_hours()), 1.std_hours());
+    /// ```
+    #[inline]
+    pub fn to_std_clamped(self, max: StdDuration) -> StdDuration {
+        if self.is_negative() {
+            return StdDuration::from_secs(0);
+        }
 
-        if micros.abs() > i64::max_value() as i128 {
-            None
+        // A nonnegative duration is always in range of `std::time::Duration`.
+        StdDuration::new(self.seconds as u64, self.nanoseconds as u32).min(max)
+    }
+
+    /// Convert the `Duration` to a `std::time::Duration`, returning zero if
+    /// the duration is negative. This never panics, and is intended for
+    /// passing a computed delay to functions such as `std::thread::sleep`.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.5.seconds().into_std_or_zero(), 1.5.std_seconds());
+    /// assert_eq!((-1).seconds().into_std_or_zero(), 0.std_seconds());
+    /// ```
+    #[inline]
+    pub fn into_std_or_zero(self) -> StdDuration {
+        StdDuration::try_from(self).unwrap_or_else(|_| StdDuration::from_secs(0))
+    }
+
+    /// Get the duration, or `floor` if the duration is zero or negative.
+    ///
+    /// This is useful for delays that are computed at runtime, such as in a
+    /// rate limiter, where a delay that collapses to zero would otherwise
+    /// cause a busy loop. A positive duration is returned unchanged, even if
+    /// it is less than `floor`.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(0.seconds().min_nonzero(10.milliseconds()), 10.milliseconds());
+    /// assert_eq!((-1).seconds().min_nonzero(10.milliseconds()), 10.milliseconds());
+    /// assert_eq!(1.seconds().min_nonzero(10.milliseconds()), 1.seconds());
+    /// ```
+    #[inline]
+    pub fn min_nonzero(self, floor: Self) -> Self {
+        if self.is_positive() {
+            self
         } else {
-            Some(micros as i64)
+            floor
         }
     }
 
-    /// [`Duration::whole_nanoseconds`] returns an `i128` rather than returning
-    /// `None` on `i64` overflow.
-    #[inline(always)]
-    #[deprecated(since = "0.2.0", note = "Use the `whole_nanoseconds` function")]
-    pub fn num_nanoseconds(&self) -> Option<i64> {
-        let nanos = self.whole_nanoseconds();
-
-        if nanos.abs() > i64::max_value() as i128 {
-            None
+    /// Restrict the magnitude of the duration to at most that of `max`,
+    /// preserving its sign.
+    ///
+    /// Unlike [`Ord::clamp`], which bounds the signed value, this bounds the
+    /// absolute value, so the result always lies between `-max.abs()` and
+    /// `max.abs()`. This is useful for bounding jitter that may be applied in
+    /// either direction. The sign of `max` is ignored.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(5.seconds().clamp_magnitude(2.seconds()), 2.seconds());
+    /// assert_eq!((-5).seconds().clamp_magnitude(2.seconds()), (-2).seconds());
+    /// assert_eq!((-1).seconds().clamp_magnitude(2.seconds()), (-1).seconds());
+    /// ```
+    #[inline]
+    pub fn clamp_magnitude(self, max: Self) -> Self {
+        let max = max.saturating_abs();
+        if self > max {
+            max
+        } else if self < -max {
+            -max
         } else {
-            Some(nanos as i64)
+            self
         }
     }
 
+    /// Convert a `std::time::Duration` to a `Duration`, returning an error if
+    /// the number of seconds exceeds `i64::max_value()`.
+    ///
+    /// This is equivalent to the [`TryFrom`](core::convert::TryFrom)
+    /// implementation, and is provided for convenience.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// # use core::time::Duration as StdDuration;
+    /// assert_eq!(Duration::try_from_std(1.5.std_seconds()), Ok(1.5.seconds()));
+    /// assert!(Duration::try_from_std(StdDuration::from_secs(u64::max_value())).is_err());
+    /// ```
     #[inline(always)]
-    #[cfg(std)]
-    #[deprecated(since = "0.2.0", note = "Use the `time_fn` function")]
-    pub fn span<F: FnOnce()>(f: F) -> Self {
-        Self::time_fn(f).0
+    pub fn try_from_std(duration: StdDuration) -> Result<Self, ConversionRangeError> {
+        Self::try_from(duration)
     }
 
-    #[inline(always)]
-    #[allow(deprecated)]
-    #[deprecated(
-        since = "0.2.0",
-        note = "Use `Duration::try_from(value)` or `value.try_into()`"
-    )]
-    pub fn from_std(std: StdDuration) -> Result<Self, ConversionRangeError> {
-        std.try_into()
+    /// Convert a `std::time::Duration` to a `Duration`, saturating to
+    /// [`Duration::max_value`] if the number of seconds exceeds
+    /// `i64::max_value()`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// # use core::time::Duration as StdDuration;
+    /// assert_eq!(Duration::from_std_saturating(1.5.std_seconds()), 1.5.seconds());
+    /// assert_eq!(
+    ///     Duration::from_std_saturating(StdDuration::from_secs(u64::max_value())),
+    ///     Duration::max_value()
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_std_saturating(duration: StdDuration) -> Self {
+        Self::try_from(duration)
+            .unwrap_or_else(|_| Self::max_value())
+            .debug_assert_invariant()
+    }
+
+    /// Computes `self + rhs`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(5.seconds().checked_add(5.seconds()), Some(10.seconds()));
+    /// assert_eq!(Duration::max_value().checked_add(1.nanoseconds()), None);
+    /// assert_eq!((-5).seconds().checked_add(5.seconds()), Some(0.seconds()));
+    /// ```
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let mut seconds = self.seconds.checked_add(rhs.seconds)?;
+        let mut nanoseconds = self.nanoseconds + rhs.nanoseconds;
+
+        if nanoseconds >= 1_000_000_000 || seconds < 0 && nanoseconds > 0 {
+            nanoseconds -= 1_000_000_000;
+            seconds = seconds.checked_add(1)?;
+        } else if nanoseconds <= -1_000_000_000 || seconds > 0 && nanoseconds < 0 {
+            nanoseconds += 1_000_000_000;
+            seconds = seconds.checked_sub(1)?;
+        }
+
+        Some(
+            Self {
+                seconds,
+                nanoseconds,
+            }
+            .debug_assert_invariant(),
+        )
+    }
+
+    /// Computes `self - rhs`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(5.seconds().checked_sub(5.seconds()), Some(Duration::zero()));
+    /// assert_eq!(Duration::min_value().checked_sub(1.nanoseconds()), None);
+    /// assert_eq!(5.seconds().checked_sub(10.seconds()), Some((-5).seconds()));
+    /// ```
+    #[inline(always)]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    /// Computes `self + rhs`, where `rhs` is a `std::time::Duration`, returning
+    /// `None` if the result is out of range.
+    ///
+    /// The `std::time::Duration` is not required to be representable as a
+    /// `Duration` on its own, so long as the result is.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// # use core::time::Duration as StdDuration;
+    /// assert_eq!(5.seconds().checked_add_std(5.std_seconds()), Some(10.seconds()));
+    /// assert_eq!(Duration::max_value().checked_add_std(1.std_nanoseconds()), None);
+    /// assert_eq!(
+    ///     Duration::min_value().checked_add_std(StdDuration::from_secs(u64::max_value())),
+    ///     Some(Duration::seconds(i64::max_value()) - 999_999_999.nanoseconds())
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_add_std(self, rhs: StdDuration) -> Option<Self> {
+        Self::checked_nanoseconds_i128(self.whole_nanoseconds() + std_whole_nanoseconds(rhs))
+    }
+
+    /// Computes `self - rhs`, where `rhs` is a `std::time::Duration`, returning
+    /// `None` if the result is out of range.
+    ///
+    /// The `std::time::Duration` is not required to be representable as a
+    /// `Duration` on its own, so long as the result is.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(5.seconds().checked_sub_std(10.std_seconds()), Some((-5).seconds()));
+    /// assert_eq!(Duration::min_value().checked_sub_std(1.std_nanoseconds()), None);
+    /// ```
+    #[inline]
+    pub fn checked_sub_std(self, rhs: StdDuration) -> Option<Self> {
+        Self::checked_nanoseconds_i128(self.whole_nanoseconds() - std_whole_nanoseconds(rhs))
+    }
+
+    /// Computes `self + rhs`, where `rhs` is a `std::time::Duration`,
+    /// saturating if the result is out of range.
+    ///
+    /// For results in range, this is identical to `self + rhs`. Where the
+    /// operator would panic, this returns [`Duration::max_value()`] instead.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// # use core::time::Duration as StdDuration;
+    /// assert_eq!(5.seconds().saturating_add_std(5.std_seconds()), 10.seconds());
+    /// assert_eq!(
+    ///     Duration::max_value().saturating_add_std(1.std_nanoseconds()),
+    ///     Duration::max_value()
+    /// );
+    /// assert_eq!(
+    ///     0.seconds().saturating_add_std(StdDuration::from_secs(u64::max_value())),
+    ///     Duration::max_value()
+    /// );
+    /// ```
+    #[inline]
+    pub fn saturating_add_std(self, rhs: StdDuration) -> Self {
+        Self::saturating_nanoseconds_i128(self.whole_nanoseconds() + std_whole_nanoseconds(rhs))
+    }
+
+    /// Computes `self - rhs`, where `rhs` is a `std::time::Duration`,
+    /// saturating if the result is out of range.
+    ///
+    /// For results in range, this is identical to `self - rhs`. Where the
+    /// operator would panic, this returns [`Duration::min_value()`] instead.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(5.seconds().saturating_sub_std(10.std_seconds()), (-5).seconds());
+    /// assert_eq!(
+    ///     Duration::min_value().saturating_sub_std(1.std_nanoseconds()),
+    ///     Duration::min_value()
+    /// );
+    /// ```
+    #[inline]
+    pub fn saturating_sub_std(self, rhs: StdDuration) -> Self {
+        Self::saturating_nanoseconds_i128(self.whole_nanoseconds() - std_whole_nanoseconds(rhs))
+    }
+
+    /// Create a `Duration` from a number of nanoseconds, returning `None` if
+    /// it is out of range.
+    #[inline]
+    fn checked_nanoseconds_i128(nanoseconds: i128) -> Option<Self> {
+        if nanoseconds < Self::min_value().whole_nanoseconds()
+            || nanoseconds > Self::max_value().whole_nanoseconds()
+        {
+            None
+        } else {
+            Some(Self::nanoseconds_i128(nanoseconds))
+        }
+    }
+
+    /// Computes the sum of all durations in the iterator, returning `None` if
+    /// the running total overflows at any point.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::checked_sum(vec![1.seconds(), 2.seconds()]), Some(3.seconds()));
+    /// assert_eq!(Duration::checked_sum(vec![]), Some(Duration::zero()));
+    /// assert_eq!(
+    ///     Duration::checked_sum(vec![Duration::max_value(), 1.nanoseconds()]),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_sum(iter: impl IntoIterator<Item = Self>) -> Option<Self> {
+        iter.into_iter().try_fold(Self::zero(), Self::checked_add)
+    }
+
+    /// Computes `self + rhs`, wrapping around the representable range if an
+    /// overflow occurred.
+    ///
+    /// The representable range is treated as a ring of nanoseconds, so one
+    /// nanosecond past [`Duration::max_value`] is [`Duration::min_value`] and
+    /// vice versa. This is rarely desirable; the `+` operator panics on
+    /// overflow, and [`Duration::checked_add`] returns `None`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(5.seconds().wrapping_add(5.seconds()), 10.seconds());
+    /// assert_eq!(
+    ///     Duration::max_value().wrapping_add(1.nanoseconds()),
+    ///     Duration::min_value()
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self::wrapping_nanoseconds_i128(self.whole_nanoseconds() + rhs.whole_nanoseconds())
+    }
+
+    /// Computes `self - rhs`, wrapping around the representable range if an
+    /// overflow occurred.
+    ///
+    /// The representable range is treated as a ring of nanoseconds, so one
+    /// nanosecond before [`Duration::min_value`] is [`Duration::max_value`]
+    /// and vice versa. This is rarely desirable; the `-` operator panics on
+    /// overflow, and [`Duration::checked_sub`] returns `None`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(5.seconds().wrapping_sub(10.seconds()), (-5).seconds());
+    /// assert_eq!(
+    ///     Duration::min_value().wrapping_sub(1.nanoseconds()),
+    ///     Duration::max_value()
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::wrapping_nanoseconds_i128(self.whole_nanoseconds() - rhs.whole_nanoseconds())
+    }
+
+    /// Create a `Duration` from the provided number of nanoseconds, wrapping
+    /// around the representable range if necessary.
+    #[inline]
+    fn wrapping_nanoseconds_i128(nanoseconds: i128) -> Self {
+        let min = Self::min_value().whole_nanoseconds();
+        let range = Self::max_value().whole_nanoseconds() - min + 1;
+
+        let mut offset = (nanoseconds - min) % range;
+        if offset < 0 {
+            offset += range;
+        }
+
+        Self::nanoseconds_i128(min + offset)
+    }
+
+    /// Create a `Duration` from the provided number of nanoseconds, saturating
+    /// to [`Duration::min_value`] or [`Duration::max_value`] if necessary.
+    #[inline]
+    fn saturating_nanoseconds_i128(nanoseconds: i128) -> Self {
+        if nanoseconds > Self::max_value().whole_nanoseconds() {
+            Self::max_value()
+        } else if nanoseconds < Self::min_value().whole_nanoseconds() {
+            Self::min_value()
+        } else {
+            Self::nanoseconds_i128(nanoseconds).debug_assert_invariant()
+        }
+    }
+
+    /// Multiply the duration by a floating point factor.
+    ///
+    /// A factor of zero always results in exactly [`Duration::zero`], with no
+    /// sign, even when multiplying a negative duration. A result that is out
+    /// of range, including one from an infinite factor, saturates to
+    /// [`Duration::max_value`] or [`Duration::min_value`]. A NaN factor
+    /// results in a zero duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(2.seconds().scale(1.5), 3.seconds());
+    /// assert_eq!((-1).seconds().scale(0.), Duration::zero());
+    /// assert_eq!(1.seconds().scale(std::f64::INFINITY), Duration::max_value());
+    /// ```
+    #[inline]
+    pub fn scale(self, factor: f64) -> Self {
+        if factor == 0. {
+            Self::zero()
+        } else {
+            self * factor
+        }
+    }
+
+    /// Multiply the duration by a floating point factor, returning an error
+    /// rather than saturating if the factor is not finite or the result is out
+    /// of range.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(2.seconds().try_mul_f64(1.5), Ok(3.seconds()));
+    /// assert!(1.seconds().try_mul_f64(std::f64::INFINITY).is_err());
+    /// assert!(1.seconds().try_mul_f64(std::f64::NAN).is_err());
+    /// assert!(Duration::max_value().try_mul_f64(2.).is_err());
+    /// ```
+    #[inline]
+    pub fn try_mul_f64(self, rhs: f64) -> Result<Self, ConversionRangeError> {
+        if !rhs.is_finite() {
+            return Err(ConversionRangeError::new());
+        }
+
+        // A finite factor may still produce an infinite product, which is
+        // rejected along with any other out of range value.
+        let seconds = self.as_seconds_f64() * rhs;
+        if seconds >= i64::max_value() as f64 || seconds < i64::min_value() as f64 {
+            return Err(ConversionRangeError::new());
+        }
+
+        Ok(Self::seconds_f64(seconds))
+    }
+
+    /// Get the `n`th term of the geometric sequence `base`, `base * ratio`,
+    /// `base * ratio^2`, and so on, such as for a retry schedule.
+    ///
+    /// The zeroth term is exactly `base`. Other terms are computed using
+    /// [`Duration::scale`], so they saturate to [`Duration::min_value`] or
+    /// [`Duration::max_value`] if out of range. A NaN ratio results in a zero
+    /// duration for any term other than the zeroth.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::geometric(100.milliseconds(), 2., 0), 100.milliseconds());
+    /// assert_eq!(Duration::geometric(1.seconds(), 2., 3), 8.seconds());
+    /// assert_eq!(Duration::geometric(1.seconds(), 0.5, 2), 250.milliseconds());
+    /// assert_eq!(Duration::geometric(1.seconds(), 10., 100), Duration::max_value());
+    /// ```
+    #[inline]
+    #[allow(clippy::float_cmp)]
+    pub fn geometric(base: Self, ratio: f64, n: u32) -> Self {
+        // Exponentiation by squaring, as `f64::powi` requires the standard
+        // library. Once the factor is infinite, zero, or NaN, further
+        // multiplication cannot change it.
+        let mut factor = 1.;
+        let mut square = ratio;
+        let mut exponent = n;
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                factor *= square;
+            }
+            square *= square;
+            exponent >>= 1;
+        }
+
+        if factor.is_nan() {
+            Self::zero()
+        } else if n == 0 || factor == 1. {
+            // Avoid any loss of precision from floating point arithmetic.
+            base
+        } else {
+            base.scale(factor)
+        }
+    }
+
+    /// Iterate over the delays of a capped exponential backoff, such as for
+    /// retrying a network request.
+    ///
+    /// The `n`th delay, for `n` in `0..max_retries`, is
+    /// `min(base * factor^n, cap)`, with each term computed by
+    /// [`Duration::geometric`]. If provided, `jitter` is then applied to each
+    /// delay, typically to add or remove a random amount so that many clients
+    /// do not retry in lockstep. The result of `jitter` is yielded as-is, and
+    /// is not capped again.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// let delays = Duration::backoff(100.milliseconds(), 2., 1.seconds(), 6, None);
+    /// assert_eq!(
+    ///     delays.collect::<Vec<_>>(),
+    ///     [
+    ///         100.milliseconds(),
+    ///         200.milliseconds(),
+    ///         400.milliseconds(),
+    ///         800.milliseconds(),
+    ///         1.seconds(),
+    ///         1.seconds(),
+    ///     ]
+    /// );
+    ///
+    /// let halved = Duration::backoff(1.seconds(), 3., 5.seconds(), 3, Some(|delay| delay / 2));
+    /// assert_eq!(
+    ///     halved.collect::<Vec<_>>(),
+    ///     [500.milliseconds(), 1_500.milliseconds(), 2_500.milliseconds()]
+    /// );
+    /// ```
+    #[inline]
+    pub fn backoff(
+        base: Self,
+        factor: f64,
+        cap: Self,
+        max_retries: u32,
+        jitter: Option<fn(Self) -> Self>,
+    ) -> impl Iterator<Item = Self> {
+        (0..max_retries).map(move |n| {
+            let delay = Self::geometric(base, factor, n).min(cap);
+            match jitter {
+                Some(jitter) => jitter(delay),
+                None => delay,
+            }
+        })
+    }
+
+    /// Linearly interpolate between two durations, computing
+    /// `a + (b - a) * t`. The factor `t` is clamped to the range `0.0..=1.0`,
+    /// so the result is always between `a` and `b`. A NaN factor is treated
+    /// as zero.
+    ///
+    /// The endpoints are exact, and the difference between the durations is
+    /// computed without loss of precision; only the product with `t` uses
+    /// floating point arithmetic.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::lerp(1.seconds(), 3.seconds(), 0.5), 2.seconds());
+    /// assert_eq!(Duration::lerp(1.seconds(), 3.seconds(), 2.), 3.seconds());
+    /// assert_eq!(Duration::lerp(1.seconds(), 3.seconds(), -1.), 1.seconds());
+    /// ```
+    #[inline]
+    pub fn lerp(a: Self, b: Self, t: f64) -> Self {
+        let t = if t < 0. {
+            0.
+        } else if t > 1. {
+            1.
+        } else {
+            t
+        };
+
+        Self::lerp_unclamped(a, b, t)
+    }
+
+    /// Linearly interpolate between two durations, computing
+    /// `a + (b - a) * t`. Unlike [`Duration::lerp`], the factor `t` is not
+    /// clamped, allowing extrapolation beyond either duration. The result
+    /// saturates to [`Duration::min_value`] or [`Duration::max_value`] if out
+    /// of range. A NaN factor is treated as zero.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::lerp_unclamped(1.seconds(), 3.seconds(), 0.5), 2.seconds());
+    /// assert_eq!(Duration::lerp_unclamped(1.seconds(), 3.seconds(), 2.), 5.seconds());
+    /// assert_eq!(Duration::lerp_unclamped(1.seconds(), 3.seconds(), -1.), (-1).seconds());
+    /// ```
+    #[inline]
+    #[allow(clippy::float_cmp)]
+    pub fn lerp_unclamped(a: Self, b: Self, t: f64) -> Self {
+        // The span of all durations is less than 2^95 nanoseconds, so the
+        // endpoints can be represented exactly regardless of `t`.
+        if t == 0. || t.is_nan() {
+            return a;
+        }
+        if t == 1. {
+            return b;
+        }
+
+        let start = a.whole_nanoseconds();
+        let offset = (b.whole_nanoseconds() - start) as f64 * t;
+
+        // Any offset this large saturates, and avoids converting an
+        // out-of-range float to an integer.
+        if offset >= 4e28 {
+            return Self::max_value();
+        } else if offset <= -4e28 {
+            return Self::min_value();
+        }
+
+        // Round to the nearest nanosecond, with ties away from zero.
+        let offset = if offset < 0. {
+            offset - 0.5
+        } else {
+            offset + 0.5
+        } as i128;
+
+        Self::saturating_nanoseconds_i128(start + offset)
+    }
+
+    /// Convert a duration measured at one tempo to the equivalent duration at
+    /// another, computing `self * from_bpm / to_bpm` exactly.
+    ///
+    /// The result is rounded to the nearest nanosecond, and saturates to
+    /// [`Duration::min_value`] or [`Duration::max_value`] if out of range. A
+    /// `to_bpm` of zero saturates in the direction of the duration's sign, and
+    /// leaves a zero duration unchanged.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(500.milliseconds().with_tempo_ratio(120, 240), 250.milliseconds());
+    /// assert_eq!(1.seconds().with_tempo_ratio(100, 90), 1_111_111_111.nanoseconds());
+    /// ```
+    #[inline]
+    pub fn with_tempo_ratio(self, from_bpm: u32, to_bpm: u32) -> Self {
+        // The number of nanoseconds is less than 2^93 in magnitude, so the
+        // product is less than 2^125 and cannot overflow an `i128`.
+        let nanoseconds = self.whole_nanoseconds() * from_bpm as i128;
+
+        if to_bpm == 0 {
+            return match nanoseconds.signum() {
+                0 => Self::zero(),
+                1 => Self::max_value(),
+                _ => Self::min_value(),
+            };
+        }
+
+        Self::saturating_nanoseconds_i128(div_round_i128(nanoseconds, to_bpm as i128))
+    }
+
+    /// Double the duration `attempt` times, computing `self * 2^attempt`. This
+    /// is useful for exponential backoff.
+    ///
+    /// The result saturates to [`Duration::max_value`] or
+    /// [`Duration::min_value`] rather than overflowing.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(100.milliseconds().saturating_shl(0), 100.milliseconds());
+    /// assert_eq!(100.milliseconds().saturating_shl(3), 800.milliseconds());
+    /// assert_eq!(1.seconds().saturating_shl(100), Duration::max_value());
+    /// assert_eq!((-1).seconds().saturating_shl(100), Duration::min_value());
+    /// ```
+    #[inline]
+    pub fn saturating_shl(self, attempt: u32) -> Self {
+        let nanoseconds = self.whole_nanoseconds();
+
+        if nanoseconds == 0 {
+            return self;
+        }
+
+        // Shifting by 127 or more bits is out of range for any nonzero value,
+        // so only smaller shifts need to be checked.
+        let shifted = if attempt < 127 {
+            nanoseconds.checked_mul(1 << attempt)
+        } else {
+            None
+        };
+
+        match shifted {
+            Some(shifted) => Self::saturating_nanoseconds_i128(shifted),
+            None if nanoseconds > 0 => Self::max_value(),
+            None => Self::min_value(),
+        }
+    }
+
+    /// Computes `self * rhs`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(5.seconds().checked_mul(2), Some(10.seconds()));
+    /// assert_eq!(5.seconds().checked_mul(-2), Some((-10).seconds()));
+    /// assert_eq!(5.seconds().checked_mul(0), Some(0.seconds()));
+    /// assert_eq!(Duration::max_value().checked_mul(2), None);
+    /// assert_eq!(Duration::min_value().checked_mul(2), None);
+    /// ```
+    #[inline(always)]
+    pub fn checked_mul(self, rhs: i32) -> Option<Self> {
+        // Multiply nanoseconds as i64, because it cannot overflow that way.
+        let total_nanos = self.nanoseconds as i64 * rhs as i64;
+        let extra_secs = total_nanos / 1_000_000_000;
+        let nanoseconds = (total_nanos % 1_000_000_000) as i32;
+        let seconds = self
+            .seconds
+            .checked_mul(rhs as i64)?
+            .checked_add(extra_secs)?;
+
+        Some(
+            Self {
+                seconds,
+                nanoseconds,
+            }
+            .debug_assert_invariant(),
+        )
+    }
+
+    /// Computes `self / rhs`, returning `None` if `rhs == 0`.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(10.seconds().checked_div(2), Some(5.seconds()));
+    /// assert_eq!(10.seconds().checked_div(-2), Some((-5).seconds()));
+    /// assert_eq!(1.seconds().checked_div(0), None);
+    #[inline(always)]
+    pub fn checked_div(self, rhs: i32) -> Option<Self> {
+        if rhs == 0 {
+            return None;
+        }
+
+        let seconds = self.seconds / (rhs as i64);
+        let carry = self.seconds - seconds * (rhs as i64);
+        let extra_nanos = carry * 1_000_000_000 / (rhs as i64);
+        let nanoseconds = self.nanoseconds / rhs + (extra_nanos as i32);
+
+        Some(
+            Self {
+                seconds,
+                nanoseconds,
+            }
+            .debug_assert_invariant(),
+        )
+    }
+
+    /// Computes the number of whole times `rhs` fits into `self`, returning
+    /// `None` if `rhs` is zero.
+    ///
+    /// The quotient is truncated towards zero. Unlike dividing by a
+    /// `Duration`, which returns an `f64`, the result is exact.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.seconds().checked_div_duration(100.milliseconds()), Some(10));
+    /// assert_eq!(1.seconds().checked_div_duration(300.milliseconds()), Some(3));
+    /// assert_eq!((-1).seconds().checked_div_duration(300.milliseconds()), Some(-3));
+    /// assert_eq!(1.seconds().checked_div_duration(0.seconds()), None);
+    /// ```
+    #[inline]
+    pub fn checked_div_duration(self, rhs: Self) -> Option<i128> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        Some(self.whole_nanoseconds() / rhs.whole_nanoseconds())
+    }
+
+    /// Get the duration as a percentage of `whole`, such as for a progress
+    /// bar. The result is not limited to `0.0..=100.0`, and is negative if
+    /// exactly one of the durations is negative.
+    ///
+    /// If `whole` is zero, the result is `0.0` rather than NaN or infinity, so
+    /// that it can always be displayed.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(15.seconds().percent_of(1.minutes()), 25.);
+    /// assert_eq!(90.seconds().percent_of(1.minutes()), 150.);
+    /// assert_eq!(1.seconds().percent_of(0.seconds()), 0.);
+    /// ```
+    #[inline]
+    pub fn percent_of(self, whole: Self) -> f64 {
+        if whole.is_zero() {
+            0.
+        } else {
+            self / whole * 100.
+        }
+    }
+
+    /// Snap the duration to a multiple of `grid`, rounding as specified. Only
+    /// the magnitude of `grid` is considered. A result that is out of range
+    /// saturates to [`Duration::min_value`] or [`Duration::max_value`].
+    ///
+    /// This method panics if `grid` is zero.
+    ///
+    /// ```rust
+    /// # use time::{Rounding, prelude::*};
+    /// let grid = 10.milliseconds();
+    /// assert_eq!(14.milliseconds().quantize(grid, Rounding::Nearest), 10.milliseconds());
+    /// assert_eq!(15.milliseconds().quantize(grid, Rounding::Nearest), 20.milliseconds());
+    /// assert_eq!(15.milliseconds().quantize(grid, Rounding::NearestEven), 20.milliseconds());
+    /// assert_eq!(25.milliseconds().quantize(grid, Rounding::NearestEven), 20.milliseconds());
+    /// assert_eq!((-14).milliseconds().quantize(grid, Rounding::Down), (-20).milliseconds());
+    /// assert_eq!((-14).milliseconds().quantize(grid, Rounding::Up), (-10).milliseconds());
+    /// assert_eq!(19.milliseconds().quantize(grid, Rounding::TowardZero), 10.milliseconds());
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use time::{Rounding, prelude::*};
+    /// 1.seconds().quantize(0.seconds(), Rounding::Nearest); // panics
+    /// ```
+    #[inline]
+    pub fn quantize(self, grid: Self, rounding: Rounding) -> Self {
+        let grid = grid.whole_nanoseconds().abs();
+        assert!(grid != 0, "grid must be nonzero");
+
+        let nanoseconds = self.whole_nanoseconds();
+        let quotient = nanoseconds / grid;
+        // The remainder has the same sign as `self`, so rounding away from
+        // zero moves the quotient in the direction of that sign.
+        let remainder = nanoseconds % grid;
+
+        let away_from_zero = remainder != 0
+            && match rounding {
+                Rounding::Nearest => remainder.abs() * 2 >= grid,
+                Rounding::NearestEven => match (remainder.abs() * 2).cmp(&grid) {
+                    Less => false,
+                    Equal => quotient % 2 != 0,
+                    Greater => true,
+                },
+                Rounding::Down => remainder < 0,
+                Rounding::Up => remainder > 0,
+                Rounding::TowardZero => false,
+                #[cfg(not(supports_non_exhaustive))]
+                Rounding::__NonExhaustive => unreachable!(),
+            };
+
+        let quotient = if away_from_zero {
+            quotient + nanoseconds.signum()
+        } else {
+            quotient
+        };
+
+        Self::saturating_nanoseconds_i128(quotient * grid)
+    }
+
+    /// Parse a `Duration` from a sequence of components, each of which is a
+    /// whole number immediately followed by a unit.
+    ///
+    /// The grammar accepted is
+    ///
+    /// ```text
+    /// duration  = *WSP [ sign ] component *( *WSP component ) *WSP
+    /// sign      = "+" / "-"
+    /// component = number unit
+    /// number    = 1*DIGIT *( "_" 1*DIGIT )
+    /// unit      = "w" / "d" / "h" / "m" / "s" / "ms" / "us" / "µs" / "ns"
+    /// ```
+    ///
+    /// The sign applies to the duration as a whole, and is positive if
+    /// omitted. Components are summed, and may be repeated or appear in any
+    /// order. A sign without any components is not a valid duration.
+    ///
+    /// Units are matched without regard to ASCII case, so `1H30M` is
+    /// equivalent to `1h30m`. ASCII whitespace is permitted around the
+    /// duration and between components, but not within a component; `1h 30m`
+    /// is valid, while `1 h` is not.
+    ///
+    /// Digits may be grouped using single underscores, such as `1_000ms`. An
+    /// underscore must have a digit on either side. Commas are not accepted;
+    /// use [`Duration::parse_ascii_with_commas`] to accept them as well.
+    ///
+    /// This is also available via [`str::parse`].
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::parse_ascii("5s"), Ok(5.seconds()));
+    /// assert_eq!(Duration::parse_ascii("+1h30m"), Ok(90.minutes()));
+    /// assert_eq!(Duration::parse_ascii("-1m500ms"), Ok((-60_500).milliseconds()));
+    /// assert_eq!(Duration::parse_ascii(" 1H 30M "), Ok(90.minutes()));
+    /// assert!(Duration::parse_ascii("+").is_err());
+    /// assert!(Duration::parse_ascii("1 h").is_err());
+    /// assert_eq!(Duration::parse_ascii("1_000ms"), Ok(1.seconds()));
+    /// assert!(Duration::parse_ascii("1__000ms").is_err());
+    /// ```
+    #[inline]
+    pub fn parse_ascii(bytes: impl AsRef<[u8]>) -> ParseResult<Self> {
+        Self::parse_ascii_grouped(bytes.as_ref(), b"_")
+    }
+
+    /// Parse a `Duration` in the same manner as [`Duration::parse_ascii`],
+    /// additionally accepting single commas between digits, such as
+    /// `1,000s`. Underscores and commas may be mixed, though each must have a
+    /// digit on either side.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::parse_ascii_with_commas("1,000s"), Ok(1_000.seconds()));
+    /// assert_eq!(Duration::parse_ascii_with_commas("1_000ms"), Ok(1.seconds()));
+    /// assert!(Duration::parse_ascii_with_commas("1,,000s").is_err());
+    /// assert!(Duration::parse_ascii("1,000s").is_err());
+    /// ```
+    #[inline]
+    pub fn parse_ascii_with_commas(bytes: impl AsRef<[u8]>) -> ParseResult<Self> {
+        Self::parse_ascii_grouped(bytes.as_ref(), b"_,")
+    }
+
+    /// Parse a `Duration` from a strict subset of the syntax accepted by
+    /// [`Duration::parse_ascii`], without allocating. This is suitable for
+    /// configuration values on embedded targets.
+    ///
+    /// The grammar accepted is
+    ///
+    /// ```text
+    /// duration  = [ "-" ] 1*component
+    /// component = 1*DIGIT unit
+    /// unit      = "w" / "d" / "h" / "m" / "s" / "ms" / "us" / "ns"
+    /// ```
+    ///
+    /// Units must be lowercase, and each must be smaller than the one before
+    /// it, so every unit appears at most once. No whitespace, digit
+    /// separators, or `+` sign are permitted. On failure, the error reports
+    /// the kind of problem and the index of the byte at which it was found.
+    ///
+    /// ```rust
+    /// # use time::{Duration, DurationParseError, prelude::*};
+    /// assert_eq!(Duration::from_ascii_strict(b"1h30m"), Ok(90.minutes()));
+    /// assert_eq!(Duration::from_ascii_strict(b"-250ms"), Ok((-250).milliseconds()));
+    /// assert_eq!(
+    ///     Duration::from_ascii_strict(b"1h 30m"),
+    ///     Err(DurationParseError::InvalidDigit { position: 2 })
+    /// );
+    /// assert_eq!(
+    ///     Duration::from_ascii_strict(b"30m1h"),
+    ///     Err(DurationParseError::UnitOutOfOrder { position: 4 })
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_ascii_strict(bytes: &[u8]) -> Result<Self, DurationParseError> {
+        /// The units that are accepted and the number of nanoseconds in each.
+        /// Longer units are listed first so that `ms` is not mistaken for `m`.
+        const UNITS: &[(&[u8], i128)] = &[
+            (b"ms", 1_000_000),
+            (b"us", 1_000),
+            (b"ns", 1),
+            (b"w", 1_000_000_000 * SECONDS_PER_WEEK as i128),
+            (b"d", 1_000_000_000 * SECONDS_PER_DAY as i128),
+            (b"h", 1_000_000_000 * SECONDS_PER_HOUR as i128),
+            (b"m", 1_000_000_000 * SECONDS_PER_MINUTE as i128),
+            (b"s", 1_000_000_000),
+        ];
+
+        let (is_negative, mut position) = match bytes.first() {
+            Some(b'-') => (true, 1),
+            _ => (false, 0),
+        };
+        // The magnitude of the most negative duration is one second greater
+        // than that of the most positive.
+        let limit = if is_negative {
+            -Self::min_value().whole_nanoseconds()
+        } else {
+            Self::max_value().whole_nanoseconds()
+        };
+
+        let mut nanoseconds: i128 = 0;
+        let mut previous_unit_nanoseconds = None;
+        loop {
+            let start = position;
+            let mut value: i128 = 0;
+            while let Some(&byte) = bytes.get(position) {
+                if !byte.is_ascii_digit() {
+                    break;
+                }
+                value = value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add((byte - b'0') as i128))
+                    .ok_or(DurationParseError::Overflow { position: start })?;
+                position += 1;
+            }
+            if position == start {
+                return Err(DurationParseError::InvalidDigit { position });
+            }
+
+            let &(unit, unit_nanoseconds) = UNITS
+                .iter()
+                .find(|(unit, _)| bytes[position..].starts_with(unit))
+                .ok_or(DurationParseError::UnknownUnit { position })?;
+            if previous_unit_nanoseconds.map_or(false, |previous| unit_nanoseconds >= previous) {
+                return Err(DurationParseError::UnitOutOfOrder { position });
+            }
+            previous_unit_nanoseconds = Some(unit_nanoseconds);
+            position += unit.len();
+
+            nanoseconds = value
+                .checked_mul(unit_nanoseconds)
+                .and_then(|value| nanoseconds.checked_add(value))
+                .filter(|&nanoseconds| nanoseconds <= limit)
+                .ok_or(DurationParseError::Overflow { position: start })?;
+
+            if position == bytes.len() {
+                break;
+            }
+        }
+
+        if is_negative {
+            nanoseconds = -nanoseconds;
+        }
+
+        Ok(Self::nanoseconds_i128(nanoseconds).debug_assert_invariant())
+    }
+
+    /// Parse a `Duration` as described by [`Duration::parse_ascii`], with
+    /// digits optionally grouped by any of the provided separators.
+    #[inline]
+    fn parse_ascii_grouped(mut bytes: &[u8], separators: &[u8]) -> ParseResult<Self> {
+        while let Some((byte, rest)) = bytes.split_first() {
+            if !byte.is_ascii_whitespace() {
+                break;
+            }
+            bytes = rest;
+        }
+        while let Some((byte, rest)) = bytes.split_last() {
+            if !byte.is_ascii_whitespace() {
+                break;
+            }
+            bytes = rest;
+        }
+
+        let (is_negative, mut remaining) = match bytes.split_first() {
+            Some((b'-', remaining)) => (true, remaining),
+            Some((b'+', remaining)) => (false, remaining),
+            _ => (false, bytes),
+        };
+
+        if remaining.is_empty() {
+            return Err(ParseError::InvalidDuration);
+        }
+
+        let mut nanoseconds: i128 = 0;
+        while !remaining.is_empty() {
+            let value = consume_grouped_digits_i128(&mut remaining, separators)
+                .ok_or(ParseError::InvalidDuration)?;
+
+            let &(unit, unit_nanoseconds) = DURATION_UNITS
+                .iter()
+                .find(|(unit, _)| {
+                    remaining.len() >= unit.len()
+                        && remaining[..unit.len()].eq_ignore_ascii_case(unit)
+                })
+                .ok_or(ParseError::InvalidDuration)?;
+            remaining = &remaining[unit.len()..];
+
+            while let Some((byte, rest)) = remaining.split_first() {
+                if !byte.is_ascii_whitespace() {
+                    break;
+                }
+                remaining = rest;
+            }
+
+            nanoseconds = value
+                .checked_mul(unit_nanoseconds)
+                .and_then(|value| nanoseconds.checked_add(value))
+                .ok_or(ParseError::InvalidDuration)?;
+        }
+
+        if is_negative {
+            nanoseconds = -nanoseconds;
+        }
+
+        Self::from_packed_i128(nanoseconds).map_err(|_| ParseError::InvalidDuration)
+    }
+
+    /// Parse a `Duration` from a plain number of seconds, such as `3600` or
+    /// `-1.5`, without any unit.
+    ///
+    /// The number may have a leading `+` or `-` sign, and may have a
+    /// fractional part of up to nine digits following a `.`. The value is
+    /// parsed exactly, without any intermediate floating point value.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::parse_seconds_str("3600"), Ok(1.hours()));
+    /// assert_eq!(Duration::parse_seconds_str("-1.5"), Ok((-1.5).seconds()));
+    /// assert!(Duration::parse_seconds_str("abc").is_err());
+    /// assert!(Duration::parse_seconds_str("5s").is_err());
+    /// ```
+    #[inline]
+    pub fn parse_seconds_str(s: impl AsRef<str>) -> ParseResult<Self> {
+        let bytes = s.as_ref().as_bytes();
+
+        let (is_negative, mut remaining) = match bytes.split_first() {
+            Some((b'-', remaining)) => (true, remaining),
+            Some((b'+', remaining)) => (false, remaining),
+            _ => (false, bytes),
+        };
+
+        let mut nanoseconds = consume_digits_i128(&mut remaining)
+            .and_then(|seconds| seconds.checked_mul(1_000_000_000))
+            .ok_or(ParseError::InvalidDuration)?;
+
+        if let Some((b'.', rest)) = remaining.split_first() {
+            remaining = rest;
+            // Both values are nonnegative and the fraction is less than one
+            // second, so this can only overflow if the seconds already did.
+            nanoseconds +=
+                consume_fraction_nanoseconds(&mut remaining).ok_or(ParseError::InvalidDuration)?;
+        }
+
+        if !remaining.is_empty() {
+            return Err(ParseError::InvalidDuration);
+        }
+
+        if is_negative {
+            nanoseconds = -nanoseconds;
+        }
+
+        Self::from_packed_i128(nanoseconds).map_err(|_| ParseError::InvalidDuration)
+    }
+
+    /// Parse a `Duration` from a clock-style string, such as `01:02:03` or
+    /// `1:30`, as produced by stopwatches and timecode displays.
+    ///
+    /// Three colon-separated fields are read as hours, minutes, and seconds,
+    /// while two fields are read as minutes and seconds. The leading field
+    /// may have any number of digits; each following field must be less than
+    /// 60. The seconds may have a fractional part of up to nine digits
+    /// following a `.`. The string may have a leading `+` or `-` sign, which
+    /// applies to the duration as a whole.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::parse_clock("01:02:03"), Ok(3_723.seconds()));
+    /// assert_eq!(Duration::parse_clock("1:30"), Ok(90.seconds()));
+    /// assert_eq!(Duration::parse_clock("-0:30.5"), Ok((-30.5).seconds()));
+    /// assert!(Duration::parse_clock("1:2:3:4").is_err());
+    /// assert!(Duration::parse_clock("1:60").is_err());
+    /// ```
+    #[inline]
+    pub fn parse_clock(s: impl AsRef<str>) -> ParseResult<Self> {
+        let mut remaining = s.as_ref().as_bytes();
+        let is_negative = consume_sign(&mut remaining);
+
+        let mut fields = [0; 3];
+        let mut num_fields = 0;
+        loop {
+            if num_fields == fields.len() {
+                return Err(ParseError::InvalidDuration);
+            }
+            let value = consume_digits_i128(&mut remaining).ok_or(ParseError::InvalidDuration)?;
+            if num_fields != 0 && value >= 60 {
+                return Err(ParseError::InvalidDuration);
+            }
+            fields[num_fields] = value;
+            num_fields += 1;
+
+            match remaining.split_first() {
+                Some((b':', rest)) => remaining = rest,
+                _ => break,
+            }
+        }
+        if num_fields == 1 {
+            return Err(ParseError::InvalidDuration);
+        }
+
+        let mut fraction = 0;
+        if let Some((b'.', rest)) = remaining.split_first() {
+            remaining = rest;
+            fraction =
+                consume_fraction_nanoseconds(&mut remaining).ok_or(ParseError::InvalidDuration)?;
+        }
+
+        if !remaining.is_empty() {
+            return Err(ParseError::InvalidDuration);
+        }
+
+        let (hours, minutes, seconds) = match num_fields {
+            2 => (0, fields[0], fields[1]),
+            _ => (fields[0], fields[1], fields[2]),
+        };
+        let mut nanoseconds = hours
+            .checked_mul(SECONDS_PER_HOUR as i128)
+            .and_then(|value| {
+                minutes
+                    .checked_mul(SECONDS_PER_MINUTE as i128)
+                    .and_then(|minutes| value.checked_add(minutes))
+            })
+            .and_then(|value| value.checked_add(seconds))
+            .and_then(|value| value.checked_mul(1_000_000_000))
+            .and_then(|value| value.checked_add(fraction))
+            .ok_or(ParseError::InvalidDuration)?;
+
+        if is_negative {
+            nanoseconds = -nanoseconds;
+        }
+
+        Self::from_packed_i128(nanoseconds).map_err(|_| ParseError::InvalidDuration)
+    }
+
+    /// Parse a `Duration` from an ISO 8601 duration, such as `P1DT2H30M` or
+    /// `-PT1.5S`.
+    ///
+    /// The date portion may contain either a number of weeks (`PnW`) or a
+    /// number of days (`PnD`). Weeks cannot be combined with any other
+    /// component. Years and months are not accepted, as their length varies.
+    /// The time portion, introduced by `T`, may contain hours, minutes, and
+    /// seconds in that order. Only the seconds may have a fractional part,
+    /// of at most nine digits, separated by either `.` or `,`. At least one
+    /// component must be present.
+    ///
+    /// The duration may be preceded by a sign, which applies to the duration
+    /// as a whole. Each component of the time portion may also be preceded by
+    /// a sign, which applies to that component and to every following
+    /// component that has no sign of its own, so `-PT1H30M`, `PT-1H30M`, and
+    /// `PT-1H-30M` are all the same duration. The sign of the date component
+    /// applies to it alone. When both an overall sign and a component sign are
+    /// present, the signs are combined. [`Duration::to_iso8601`] only ever
+    /// places a sign before the `P`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::parse_iso8601("P1DT2H30M"), Ok(1.days() + 150.minutes()));
+    /// assert_eq!(Duration::parse_iso8601("-PT1.5S"), Ok((-1.5).seconds()));
+    /// assert_eq!(Duration::parse_iso8601("PT-1.5S"), Ok((-1.5).seconds()));
+    /// assert_eq!(Duration::parse_iso8601("PT-1H30M"), Ok((-90).minutes()));
+    /// assert_eq!(Duration::parse_iso8601("P2W"), Ok(14.days()));
+    /// assert!(Duration::parse_iso8601("P2W1D").is_err());
+    /// ```
+    #[inline]
+    pub fn parse_iso8601(bytes: impl AsRef<[u8]>) -> ParseResult<Self> {
+        /// The time designators, in the order they must appear, and the number
+        /// of nanoseconds in each.
+        const TIME_DESIGNATORS: &[(u8, i128)] = &[
+            (b'H', 1_000_000_000 * SECONDS_PER_HOUR as i128),
+            (b'M', 1_000_000_000 * SECONDS_PER_MINUTE as i128),
+            (b'S', 1_000_000_000),
+        ];
+
+        let mut remaining = bytes.as_ref();
+
+        let is_negative = consume_sign(&mut remaining);
+        remaining = match remaining.split_first() {
+            Some((b'P', remaining)) => remaining,
+            _ => return Err(ParseError::InvalidDuration),
+        };
+
+        let mut nanoseconds: i128 = 0;
+        let mut has_date_component = false;
+        let mut has_time_component = false;
+
+        if !remaining.is_empty() && remaining[0] != b'T' {
+            let is_component_negative = consume_sign(&mut remaining);
+            let value = consume_digits_i128(&mut remaining).ok_or(ParseError::InvalidDuration)?;
+            let unit_nanoseconds = match remaining.split_first() {
+                // Weeks may not be combined with any other component.
+                Some((b'W', [])) => SECONDS_PER_WEEK,
+                Some((b'D', _)) => SECONDS_PER_DAY,
+                _ => return Err(ParseError::InvalidDuration),
+            } as i128
+                * 1_000_000_000;
+            remaining = &remaining[1..];
+
+            nanoseconds = value
+                .checked_mul(unit_nanoseconds)
+                .ok_or(ParseError::InvalidDuration)?;
+            if is_component_negative {
+                nanoseconds = -nanoseconds;
+            }
+            has_date_component = true;
+        }
+
+        if let Some((b'T', rest)) = remaining.split_first() {
+            remaining = rest;
+            let mut designators = TIME_DESIGNATORS;
+            let mut is_component_negative = false;
+
+            while !remaining.is_empty() {
+                // A sign carries over to the following components until
+                // another sign is present.
+                match remaining.first() {
+                    Some(b'+') | Some(b'-') => is_component_negative = consume_sign(&mut remaining),
+                    _ => {}
+                }
+                let value =
+                    consume_digits_i128(&mut remaining).ok_or(ParseError::InvalidDuration)?;
+
+                let mut fraction = 0;
+                match remaining.split_first() {
+                    Some((&separator, rest)) if separator == b'.' || separator == b',' => {
+                        remaining = rest;
+                        fraction = consume_fraction_nanoseconds(&mut remaining)
+                            .ok_or(ParseError::InvalidDuration)?;
+
+                        if remaining.first() != Some(&b'S') {
+                            return Err(ParseError::InvalidDuration);
+                        }
+                    }
+                    _ => {}
+                }
+
+                // Each designator may only appear once, and only after those
+                // preceding it.
+                let index = match remaining.first() {
+                    Some(byte) => designators
+                        .iter()
+                        .position(|&(designator, _)| designator == *byte)
+                        .ok_or(ParseError::InvalidDuration)?,
+                    None => return Err(ParseError::InvalidDuration),
+                };
+                let unit_nanoseconds = designators[index].1;
+                designators = &designators[index + 1..];
+                remaining = &remaining[1..];
+
+                nanoseconds = value
+                    .checked_mul(unit_nanoseconds)
+                    .and_then(|value| value.checked_add(fraction))
+                    .map(|value| if is_component_negative { -value } else { value })
+                    .and_then(|value| nanoseconds.checked_add(value))
+                    .ok_or(ParseError::InvalidDuration)?;
+                has_time_component = true;
+            }
+
+            if !has_time_component {
+                return Err(ParseError::InvalidDuration);
+            }
+        }
+
+        if !remaining.is_empty() || !(has_date_component || has_time_component) {
+            return Err(ParseError::InvalidDuration);
+        }
+
+        if is_negative {
+            nanoseconds = -nanoseconds;
+        }
+
+        Self::from_packed_i128(nanoseconds).map_err(|_| ParseError::InvalidDuration)
+    }
+
+    /// Obtain a value that displays the duration in the provided style. No
+    /// formatting takes place until the value is written.
+    ///
+    /// ```rust
+    /// # use time::{DisplayStyle, prelude::*};
+    /// let duration = (-1.5).hours();
+    /// assert_eq!(duration.display_as(DisplayStyle::Compact).to_string(), "-1h30m");
+    /// assert_eq!(duration.display_as(DisplayStyle::Iso8601).to_string(), "-PT1H30M");
+    /// assert_eq!(duration.display_as(DisplayStyle::Timecode).to_string(), "-01:30:00.000");
+    /// assert_eq!(duration.display_as(DisplayStyle::Humanized).to_string(), "1 hour ago");
+    /// ```
+    #[inline(always)]
+    pub fn display_as(self, style: DisplayStyle) -> impl Display {
+        DisplayAs {
+            duration: self,
+            style,
+        }
+    }
+
+    /// Obtain a value that displays each nonzero unit of the duration followed
+    /// by the provided labels, such as `1 j 2 h`. The short labels are used by
+    /// default; the alternate flag (`{:#}`) selects the long labels instead. A
+    /// zero duration is written as zero seconds.
+    ///
+    /// ```rust
+    /// # use time::{UnitLabel, UnitLabels, prelude::*};
+    /// let labels = UnitLabels {
+    ///     days: UnitLabel::new("j", "jour", "jours"),
+    ///     hours: UnitLabel::new("h", "heure", "heures"),
+    ///     ..Default::default()
+    /// };
+    /// let duration = 1.days() + 2.hours();
+    /// assert_eq!(duration.display_with_labels(labels).to_string(), "1 j 2 h");
+    /// assert_eq!(format!("{:#}", duration.display_with_labels(labels)), "1 jour 2 heures");
+    /// ```
+    #[inline(always)]
+    pub fn display_with_labels(self, labels: UnitLabels) -> impl Display {
+        DisplayWithLabels {
+            duration: self,
+            labels,
+        }
+    }
+
+    /// Write each nonzero unit of the duration followed by its label, with
+    /// `separator` placed between each value and label and between units.
+    #[inline]
+    fn labeled_components(self, labels: &UnitLabels, long: bool, separator: &str) -> String {
+        // Widen before taking the absolute value, as `i64::min_value()` has no
+        // positive counterpart.
+        let seconds = (self.seconds as i128).abs();
+        let nanoseconds = self.nanoseconds.abs() as i128;
+        let components = [
+            (seconds / 86_400, labels.days),
+            (seconds / 3_600 % 24, labels.hours),
+            (seconds / 60 % 60, labels.minutes),
+            (seconds % 60, labels.seconds),
+            (nanoseconds / 1_000_000, labels.milliseconds),
+            (nanoseconds / 1_000 % 1_000, labels.microseconds),
+            (nanoseconds % 1_000, labels.nanoseconds),
+        ];
+
+        let label = |value: i128, label: UnitLabel| match (long, value) {
+            (false, _) => label.short,
+            (true, 1) => label.singular,
+            (true, _) => label.plural,
+        };
+
+        if self.is_zero() {
+            return format!("0{}{}", separator, label(0, labels.seconds));
+        }
+
+        let mut output = String::new();
+        if self.is_negative() {
+            output.push('-');
+        }
+        let start = output.len();
+        for &(value, unit) in components.iter().filter(|&&(value, _)| value != 0) {
+            if output.len() > start {
+                output.push_str(separator);
+            }
+            output.push_str(&value.to_string());
+            output.push_str(separator);
+            output.push_str(label(value, unit));
+        }
+
+        output
+    }
+
+    /// Format the `Duration` as an ISO 8601 duration, such as `P1DT2H30M`.
+    ///
+    /// Only days, hours, minutes, and seconds are used; weeks are always
+    /// written as a number of days. Components that are zero are omitted,
+    /// and a zero duration is written as `PT0S`. Negative durations are
+    /// written with a single `-` before the `P`, such as `-PT1H30M`, rather
+    /// than a sign on each component. The output is accepted by
+    /// [`Duration::parse_iso8601`].
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!((1.days() + 150.minutes()).to_iso8601(), "P1DT2H30M");
+    /// assert_eq!((-1.5).seconds().to_iso8601(), "-PT1.5S");
+    /// assert_eq!(14.days().to_iso8601(), "P14D");
+    /// assert_eq!(0.seconds().to_iso8601(), "PT0S");
+    /// ```
+    #[inline]
+    pub fn to_iso8601(self) -> String {
+        if self.is_zero() {
+            return String::from("PT0S");
+        }
+
+        // Widen before taking the absolute value, as `i64::min_value()` has no
+        // positive counterpart.
+        let seconds = (self.seconds as i128).abs();
+        let nanoseconds = self.nanoseconds.abs();
+
+        let days = seconds / SECONDS_PER_DAY as i128;
+        let hours = seconds / SECONDS_PER_HOUR as i128 % 24;
+        let minutes = seconds / SECONDS_PER_MINUTE as i128 % 60;
+        let seconds = seconds % 60;
+
+        let mut s = String::new();
+        if self.is_negative() {
+            s.push('-');
+        }
+        s.push('P');
+
+        if days != 0 {
+            s.push_str(&days.to_string());
+            s.push('D');
+        }
+
+        if hours != 0 || minutes != 0 || seconds != 0 || nanoseconds != 0 {
+            s.push('T');
+            if hours != 0 {
+                s.push_str(&hours.to_string());
+                s.push('H');
+            }
+            if minutes != 0 {
+                s.push_str(&minutes.to_string());
+                s.push('M');
+            }
+            if seconds != 0 || nanoseconds != 0 {
+                s.push_str(&Self::new(seconds as i64, nanoseconds).format_seconds(None));
+                s.push('S');
+            }
+        }
+
+        s
+    }
+
+    /// Format the duration using a sequence of [`DurationFormatItem`]s. This
+    /// is a structured alternative to a format string, with each item writing
+    /// a single component of the duration.
+    ///
+    /// ```rust
+    /// # use time::{DurationFormatItem::*, prelude::*};
+    /// let items = [Hours, Literal("h"), Minutes, Literal("m")];
+    /// assert_eq!(90.minutes().format(&items), "01h30m");
+    /// assert_eq!(
+    ///     (-1.25).seconds().format(&[Sign, Seconds, Literal("."), Subsecond(2)]),
+    ///     "-01.25"
+    /// );
+    /// ```
+    #[inline]
+    pub fn format(self, items: &[DurationFormatItem<'_>]) -> String {
+        let mut s = String::new();
+        format::duration::format_duration(&mut s, self, items)
+            .expect("writing to a `String` cannot fail");
+        s
+    }
+
+    /// Render the duration as a number of seconds, with the fractional part
+    /// written in decimal.
+    ///
+    /// When `decimals` is `None`, trailing zeros are trimmed from the
+    /// fractional part, omitting the decimal point entirely for a whole number
+    /// of seconds. Otherwise, exactly that many decimal places are written, up
+    /// to a maximum of nine. Any precision that is not written is truncated.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.5.seconds().format_seconds(None), "1.5");
+    /// assert_eq!(1.5.seconds().format_seconds(Some(3)), "1.500");
+    /// assert_eq!((-1.5).seconds().format_seconds(Some(0)), "-1");
+    /// ```
+    #[inline]
+    pub fn format_seconds(self, decimals: Option<u8>) -> String {
+        // Widen before taking the absolute value, as `i64::min_value()` has no
+        // positive counterpart.
+        let seconds = (self.seconds as i128).abs();
+        let mut fraction = self.nanoseconds.abs() as u32;
+
+        let mut num_decimals = 9;
+        match decimals {
+            Some(decimals) => {
+                num_decimals = decimals.min(9) as u32;
+                fraction /= 10_u32.pow(9 - num_decimals);
+            }
+            None => {
+                while num_decimals > 0 && fraction % 10 == 0 {
+                    fraction /= 10;
+                    num_decimals -= 1;
+                }
+            }
+        }
+
+        // A duration that is truncated to zero is written without a sign.
+        let sign = if self.is_negative() && (seconds != 0 || fraction != 0) {
+            "-"
+        } else {
+            ""
+        };
+
+        if num_decimals == 0 {
+            format!("{}{}", sign, seconds)
+        } else {
+            format!(
+                "{}{}.{:0width$}",
+                sign,
+                seconds,
+                fraction,
+                width = num_decimals as usize
+            )
+        }
+    }
+
+    /// Describe the duration relative to the present, such as `in 3 minutes`
+    /// or `2 hours ago`. Only the largest whole unit is used, so `90.minutes()`
+    /// is described as `in 1 hour`. Any duration shorter than a second is
+    /// described as `just now`.
+    ///
+    /// This is equivalent to [`Duration::humanize_with`] using the default
+    /// options.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(3.minutes().humanize(), "in 3 minutes");
+    /// assert_eq!((-2).hours().humanize(), "2 hours ago");
+    /// assert_eq!(1.days().humanize(), "in 1 day");
+    /// assert_eq!(500.milliseconds().humanize(), "just now");
+    /// ```
+    #[inline]
+    pub fn humanize(self) -> String {
+        self.humanize_with(HumanizeOptions::default())
+    }
+
+    /// Describe the duration relative to the present, using the provided
+    /// thresholds and labels. Only the largest whole unit is used.
+    ///
+    /// ```rust
+    /// # use time::{HumanizeOptions, UnitLabel, UnitLabels, prelude::*};
+    /// let options = HumanizeOptions {
+    ///     just_now_threshold: 0.seconds(),
+    ///     future_prefix: "dans ",
+    ///     past_prefix: "il y a ",
+    ///     past_suffix: "",
+    ///     labels: UnitLabels {
+    ///         hours: UnitLabel::new("h", "heure", "heures"),
+    ///         days: UnitLabel::new("j", "jour", "jours"),
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(3.minutes().humanize_with(options), "dans 3 minutes");
+    /// assert_eq!((-2).days().humanize_with(options), "il y a 2 jours");
+    /// ```
+    #[inline]
+    pub fn humanize_with(self, options: HumanizeOptions) -> String {
+        // Widen before taking the absolute value, as `i64::min_value()` has no
+        // positive counterpart.
+        let seconds = (self.seconds as i128).abs();
+        if self.saturating_abs() < options.just_now_threshold {
+            return options.just_now.to_owned();
+        }
+
+        let labels = &options.labels;
+        let (count, label) = [
+            (SECONDS_PER_WEEK, labels.weeks),
+            (SECONDS_PER_DAY, labels.days),
+            (SECONDS_PER_HOUR, labels.hours),
+            (SECONDS_PER_MINUTE, labels.minutes),
+        ]
+        .iter()
+        .map(|&(unit, label)| (seconds / unit as i128, label))
+        .find(|&(count, _)| count != 0)
+        .unwrap_or((seconds, labels.seconds));
+
+        let (prefix, suffix) = if self.is_negative() {
+            (options.past_prefix, options.past_suffix)
+        } else {
+            (options.future_prefix, options.future_suffix)
+        };
+
+        format!(
+            "{}{} {}{}",
+            prefix,
+            count,
+            if count == 1 {
+                label.singular
+            } else {
+                label.plural
+            },
+            suffix
+        )
+    }
+
+    /// Runs a closure, returning the duration of time it took to run. The
+    /// return value of the closure is provided in the second part of the tuple.
+    #[inline(always)]
+    #[cfg(std)]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
+    pub fn time_fn<T>(f: impl FnOnce() -> T) -> (Self, T) {
+        Self::time_fn_with(&SystemClock, f)
+    }
+
+    /// Runs a closure, returning the duration of time it took to run as
+    /// measured by the provided [`Clock`]. The return value of the closure is
+    /// provided in the second part of the tuple.
+    ///
+    /// ```rust
+    /// # use time::{Duration, MockClock, prelude::*};
+    /// let clock = MockClock::default();
+    /// let (duration, value) = Duration::time_fn_with(&clock, || {
+    ///     clock.advance(2.seconds());
+    ///     42
+    /// });
+    /// assert_eq!(duration, 2.seconds());
+    /// assert_eq!(value, 42);
+    /// ```
+    #[inline(always)]
+    #[cfg(std)]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
+    pub fn time_fn_with<T>(clock: &impl Clock, f: impl FnOnce() -> T) -> (Self, T) {
+        let start = clock.now();
+        let return_value = f();
+        let end = clock.now();
+
+        (end - start, return_value)
+    }
+
+    /// Obtain the signed duration from `earlier` until now, as measured by the
+    /// system clock. The result is negative if `earlier` is in the future.
+    ///
+    /// An error is returned if the duration is out of range.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// # use std::time::SystemTime;
+    /// let earlier = SystemTime::now() - 1.std_hours();
+    /// assert!(Duration::since_system_time(earlier)? >= 1.hours());
+    /// # Ok::<_, time::ConversionRangeError>(())
+    /// ```
+    #[inline]
+    #[cfg(std)]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
+    pub fn since_system_time(earlier: SystemTime) -> Result<Self, ConversionRangeError> {
+        Self::between(earlier, SystemTime::now())
+    }
+
+    /// Obtain the signed duration from `a` until `b`, as measured by the
+    /// system clock. Unlike [`SystemTime::duration_since`], this does not fail
+    /// when `a` is later than `b`, instead returning a negative duration.
+    ///
+    /// An error is returned if the duration is out of range.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// # use std::time::SystemTime;
+    /// let a = SystemTime::UNIX_EPOCH;
+    /// let b = a + 90.std_seconds();
+    /// assert_eq!(Duration::between(a, b), Ok(90.seconds()));
+    /// assert_eq!(Duration::between(b, a), Ok((-90).seconds()));
+    /// ```
+    #[inline]
+    #[cfg(std)]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
+    pub fn between(a: SystemTime, b: SystemTime) -> Result<Self, ConversionRangeError> {
+        match b.duration_since(a) {
+            Ok(duration) => Self::try_from(duration),
+            // The negation cannot overflow, as the positive value is in range.
+            Err(error) => Self::try_from(error.duration()).map(|duration| -duration),
+        }
+    }
+}
+
+/// Functions that have been renamed or had signatures changed since v0.1. As
+/// such, they are deprecated.
+#[cfg(v01_deprecated_api)]
+#[cfg_attr(tarpaulin, skip)]
+#[allow(clippy::missing_docs_in_private_items, clippy::missing_const_for_fn)]
+impl Duration {
+    #[inline(always)]
+    #[deprecated(since = "0.2.0", note = "Use the `whole_weeks` function")]
+    pub fn num_weeks(&self) -> i64 {
+        self.whole_weeks()
+    }
+
+    #[inline(always)]
+    #[deprecated(since = "0.2.0", note = "Use the `whole_days` function")]
+    pub fn num_days(&self) -> i64 {
+        self.whole_days()
+    }
+
+    #[inline(always)]
+    #[deprecated(since = "0.2.0", note = "Use the `whole_hours` function")]
+    pub fn num_hours(&self) -> i64 {
+        self.whole_hours()
+    }
+
+    #[inline(always)]
+    #[deprecated(since = "0.2.0", note = "Use the `whole_minutes` function")]
+    pub fn num_minutes(&self) -> i64 {
+        self.whole_minutes()
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline(always)]
+    #[deprecated(since = "0.2.0", note = "Use the `whole_seconds` function")]
+    pub fn num_seconds(&self) -> i64 {
+        self.whole_seconds()
+    }
+
+    /// [`Duration::whole_milliseconds`] returns an `i128`, rather than
+    /// panicking on overflow. To avoid panicking, this method currently limits
+    /// the value to the range `i64::min_value()..=i64::max_value()`.
+    #[inline]
+    #[deprecated(
+        since = "0.2.0",
+        note = "Use the `whole_milliseconds` function. The value is clamped between \
+                `i64::min_value()` and `i64::max_value()`."
+    )]
+    pub fn num_milliseconds(&self) -> i64 {
+        let millis = self.whole_milliseconds();
+
+        if millis > i64::max_value() as i128 {
+            return i64::max_value();
+        }
+
+        if millis < i64::min_value() as i128 {
+            return i64::min_value();
+        }
+
+        millis as i64
+    }
+
+    /// [`Duration::whole_microseconds`] returns an `i128` rather than returning
+    /// `None` on `i64` overflow.
+    #[inline(always)]
+    #[deprecated(since = "0.2.0", note = "Use the `whole_microseconds` function")]
+    pub fn num_microseconds(&self) -> Option<i64> {
+        let micros = self.whole_microseconds();
+
+        if micros.abs() > i64::max_value() as i128 {
+            None
+        } else {
+            Some(micros as i64)
+        }
+    }
+
+    /// [`Duration::whole_nanoseconds`] returns an `i128` rather than returning
+    /// `None` on `i64` overflow.
+    #[inline(always)]
+    #[deprecated(since = "0.2.0", note = "Use the `whole_nanoseconds` function")]
+    pub fn num_nanoseconds(&self) -> Option<i64> {
+        let nanos = self.whole_nanoseconds();
+
+        if nanos.abs() > i64::max_value() as i128 {
+            None
+        } else {
+            Some(nanos as i64)
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(std)]
+    #[deprecated(since = "0.2.0", note = "Use the `time_fn` function")]
+    pub fn span<F: FnOnce()>(f: F) -> Self {
+        Self::time_fn(f).0
+    }
+
+    #[inline(always)]
+    #[allow(deprecated)]
+    #[deprecated(
+        since = "0.2.0",
+        note = "Use `Duration::try_from(value)` or `value.try_into()`"
+    )]
+    pub fn from_std(std: StdDuration) -> Result<Self, ConversionRangeError> {
+        std.try_into()
+    }
+
+    #[inline(always)]
+    #[allow(deprecated)]
+    #[deprecated(
+        since = "0.2.0",
+        note = "Use `std::time::Duration::try_from(value)` or `value.try_into()`"
+    )]
+    pub fn to_std(&self) -> Result<StdDuration, ConversionRangeError> {
+        (*self).try_into()
+    }
+}
+
+/// Consume an optional `+` or `-` from the start of `remaining`, returning
+/// whether it was `-`.
+#[inline]
+fn consume_sign(remaining: &mut &[u8]) -> bool {
+    match remaining.split_first() {
+        Some((b'-', rest)) => {
+            *remaining = rest;
+            true
+        }
+        Some((b'+', rest)) => {
+            *remaining = rest;
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Consume a nonempty sequence of ASCII digits from the start of `remaining`,
+/// returning their value. `None` is returned if there are no digits or the
+/// value overflows.
+#[inline]
+fn consume_digits_i128(remaining: &mut &[u8]) -> Option<i128> {
+    let num_digits = remaining
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if num_digits == 0 {
+        return None;
+    }
+
+    let mut value: i128 = 0;
+    for &digit in &remaining[..num_digits] {
+        value = value.checked_mul(10)?.checked_add((digit - b'0') as i128)?;
+    }
+    *remaining = &remaining[num_digits..];
+
+    Some(value)
+}
+
+/// Consume a nonempty sequence of ASCII digits from the start of `remaining`,
+/// which may be divided into groups by single bytes from `separators`,
+/// returning their value. A separator is only consumed if it has a digit on
+/// either side. `None` is returned if there are no digits or the value
+/// overflows.
+#[inline]
+fn consume_grouped_digits_i128(remaining: &mut &[u8], separators: &[u8]) -> Option<i128> {
+    let starts_with_digit = |bytes: &[u8]| bytes.first().map_or(false, u8::is_ascii_digit);
+
+    if !starts_with_digit(remaining) {
+        return None;
+    }
+
+    let mut value: i128 = 0;
+    while let Some((&byte, rest)) = remaining.split_first() {
+        if byte.is_ascii_digit() {
+            value = value.checked_mul(10)?.checked_add((byte - b'0') as i128)?;
+        } else if !separators.contains(&byte) || !starts_with_digit(rest) {
+            // Any separator that is consumed is followed by a digit, so the
+            // preceding byte is always a digit as well.
+            break;
+        }
+        *remaining = rest;
+    }
+
+    Some(value)
+}
+
+/// Consume between one and nine ASCII digits representing a fraction of a
+/// second, returning the equivalent number of nanoseconds. `None` is returned
+/// if there are no digits or more than nine.
+#[inline]
+fn consume_fraction_nanoseconds(remaining: &mut &[u8]) -> Option<i128> {
+    let num_digits = remaining
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if num_digits == 0 || num_digits > 9 {
+        return None;
+    }
+
+    Some(consume_digits_i128(remaining)? * 10_i128.pow(9 - num_digits as u32))
+}
+
+/// Convert an `i128` to an `i64`, saturating at the bounds of `i64`.
+#[inline]
+fn saturating_i128_to_i64(value: i128) -> i64 {
+    if value > i64::max_value() as i128 {
+        i64::max_value()
+    } else if value < i64::min_value() as i128 {
+        i64::min_value()
+    } else {
+        value as i64
+    }
+}
+
+/// Get the number of whole nanoseconds in a `std::time::Duration`.
+#[inline(always)]
+const fn std_whole_nanoseconds(duration: StdDuration) -> i128 {
+    duration.as_secs() as i128 * 1_000_000_000 + duration.subsec_nanos() as i128
+}
+
+/// Divide `lhs` by the positive `rhs`, rounding to the nearest integer. Halfway
+/// cases are rounded away from zero.
+#[inline]
+fn div_round_i128(lhs: i128, rhs: i128) -> i128 {
+    let quotient = lhs / rhs;
+    let remainder = lhs % rhs;
+
+    if remainder.abs() * 2 >= rhs {
+        quotient + lhs.signum()
+    } else {
+        quotient
+    }
+}
+
+impl TryFrom<StdDuration> for Duration {
+    type Error = ConversionRangeError;
+
+    #[inline(always)]
+    fn try_from(original: StdDuration) -> Result<Self, ConversionRangeError> {
+        Ok(Self::new(
+            original
+                .as_secs()
+                .try_into()
+                .map_err(|_| ConversionRangeError::new())?,
+            original
+                .subsec_nanos()
+                .try_into()
+                .map_err(|_| ConversionRangeError::new())?,
+        )
+        .debug_assert_invariant())
+    }
+}
+
+impl TryFrom<Duration> for StdDuration {
+    type Error = ConversionRangeError;
+
+    #[inline(always)]
+    fn try_from(duration: Duration) -> Result<Self, ConversionRangeError> {
+        Ok(Self::new(
+            duration
+                .seconds
+                .try_into()
+                .map_err(|_| ConversionRangeError::new())?,
+            duration
+                .nanoseconds
+                .try_into()
+                .map_err(|_| ConversionRangeError::new())?,
+        ))
+    }
+}
+
+impl TryFrom<DurationComponents> for Duration {
+    type Error = ConversionRangeError;
+
+    #[inline]
+    fn try_from(components: DurationComponents) -> Result<Self, ConversionRangeError> {
+        let units = [
+            (components.weeks, 1_000_000_000 * SECONDS_PER_WEEK as i128),
+            (components.days, 1_000_000_000 * SECONDS_PER_DAY as i128),
+            (components.hours, 1_000_000_000 * SECONDS_PER_HOUR as i128),
+            (
+                components.minutes,
+                1_000_000_000 * SECONDS_PER_MINUTE as i128,
+            ),
+            (components.seconds, 1_000_000_000),
+            (components.milliseconds, 1_000_000),
+            (components.microseconds, 1_000),
+            (components.nanoseconds, 1),
+        ];
+
+        // Each term is less than 2^113 in magnitude, so the sum cannot overflow
+        // an `i128`.
+        let nanoseconds: i128 = units
+            .iter()
+            .map(|&(value, unit)| value.unwrap_or(0) as i128 * unit)
+            .sum();
+
+        if nanoseconds < Self::min_value().whole_nanoseconds()
+            || nanoseconds > Self::max_value().whole_nanoseconds()
+        {
+            return Err(ConversionRangeError::new());
+        }
+
+        Ok(Self::nanoseconds_i128(nanoseconds).debug_assert_invariant())
+    }
+}
+
+impl Add for Duration {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding durations")
+    }
+}
+
+impl Add<StdDuration> for Duration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, std_duration: StdDuration) -> Self::Output {
+        self + Self::try_from(std_duration)
+            .expect("overflow converting `std::time::Duration` to `time::Duration`")
+    }
+}
+
+impl Add<Duration> for StdDuration {
+    type Output = Duration;
+
+    #[inline(always)]
+    fn add(self, rhs: Duration) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl AddAssign for Duration {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<StdDuration> for Duration {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: StdDuration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Neg for Duration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        -1 * self
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting durations")
+    }
+}
+
+impl Sub<StdDuration> for Duration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: StdDuration) -> Self::Output {
+        self - Self::try_from(rhs)
+            .expect("overflow converting `std::time::Duration` to `time::Duration`")
+    }
+}
+
+impl Sub<Duration> for StdDuration {
+    type Output = Duration;
+
+    #[inline(always)]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        Duration::try_from(self)
+            .expect("overflow converting `std::time::Duration` to `time::Duration`")
+            - rhs
+    }
+}
+
+impl SubAssign for Duration {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<StdDuration> for Duration {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: StdDuration) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<Duration> for StdDuration {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = (*self - rhs).try_into().expect(
+            "Cannot represent a resulting duration in std. Try `let x = x - rhs;`, which will \
+             change the type.",
+        );
+    }
+}
+
+macro_rules! duration_mul_div_int {
+    ($($type:ty),+) => {
+        $(
+            impl Mul<$type> for Duration {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul(self, rhs: $type) -> Self::Output {
+                    Self::nanoseconds_i128(
+                        self.checked_whole_nanoseconds()
+                            .and_then(|nanoseconds| nanoseconds.checked_mul(rhs as i128))
+                            .expect("overflow when multiplying duration")
+                    )
+                }
+            }
+
+            impl MulAssign<$type> for Duration {
+                #[inline(always)]
+                fn mul_assign(&mut self, rhs: $type) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl Mul<Duration> for $type {
+                type Output = Duration;
+
+                #[inline(always)]
+                fn mul(self, rhs: Duration) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl Div<$type> for Duration {
+                type Output = Self;
+
+                #[inline(always)]
+                fn div(self, rhs: $type) -> Self::Output {
+                    Self::nanoseconds_i128(
+                        self.checked_whole_nanoseconds()
+                            .expect("overflow when dividing duration")
+                            / rhs as i128
+                    )
+                }
+            }
+
+            impl DivAssign<$type> for Duration {
+                #[inline(always)]
+                fn div_assign(&mut self, rhs: $type) {
+                    *self = *self / rhs;
+                }
+            }
+        )+
+    };
+}
+duration_mul_div_int![i8, i16, i32, u8, u16, u32];
+
+impl Mul<f32> for Duration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::saturating_seconds_f32(self.as_seconds_f32() * rhs)
+    }
+}
+
+impl MulAssign<f32> for Duration {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Duration> for f32 {
+    type Output = Duration;
+
+    #[inline(always)]
+    fn mul(self, rhs: Duration) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<f64> for Duration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::saturating_seconds_f64(self.as_seconds_f64() * rhs)
+    }
+}
+
+impl MulAssign<f64> for Duration {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Duration> for f64 {
+    type Output = Duration;
+
+    #[inline(always)]
+    fn mul(self, rhs: Duration) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Div<f32> for Duration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: f32) -> Self::Output {
+        Self::saturating_seconds_f32(self.as_seconds_f32() / rhs)
+    }
+}
+
+impl DivAssign<f32> for Duration {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Div<f64> for Duration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::saturating_seconds_f64(self.as_seconds_f64() / rhs)
+    }
+}
+
+impl DivAssign<f64> for Duration {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Div<Duration> for Duration {
+    type Output = f64;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.as_seconds_f64() / rhs.as_seconds_f64()
+    }
+}
+
+impl Div<StdDuration> for Duration {
+    type Output = f64;
+
+    #[inline(always)]
+    fn div(self, rhs: StdDuration) -> Self::Output {
+        self.as_seconds_f64() / rhs.as_secs_f64()
+    }
+}
+
+impl Div<Duration> for StdDuration {
+    type Output = f64;
+
+    #[inline(always)]
+    fn div(self, rhs: Duration) -> Self::Output {
+        self.as_secs_f64() / rhs.as_seconds_f64()
+    }
+}
+
+impl PartialEq<StdDuration> for Duration {
+    #[inline(always)]
+    fn eq(&self, rhs: &StdDuration) -> bool {
+        Ok(*self) == Self::try_from(*rhs)
+    }
+}
+
+impl PartialEq<Duration> for StdDuration {
+    #[inline(always)]
+    fn eq(&self, rhs: &Duration) -> bool {
+        rhs == self
+    }
+}
+
+impl PartialEq<f64> for Duration {
+    #[inline(always)]
+    #[allow(clippy::float_cmp)]
+    fn eq(&self, rhs: &f64) -> bool {
+        self.as_seconds_f64() == *rhs
+    }
+}
+
+impl PartialEq<Duration> for f64 {
+    #[inline(always)]
+    fn eq(&self, rhs: &Duration) -> bool {
+        rhs == self
+    }
+}
+
+impl Display for Duration {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The output is assembled before being written so that padding is
+        // applied to the duration as a whole.
+        f.pad(&self.labeled_components(&UnitLabels::default(), false, ""))
+    }
+}
+
+impl FromStr for Duration {
+    type Err = ParseError;
+
+    #[inline(always)]
+    fn from_str(s: &str) -> ParseResult<Self> {
+        Self::parse_ascii(s)
+    }
+}
+
+impl PartialOrd for Duration {
+    #[inline(always)]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl PartialOrd<StdDuration> for Duration {
+    #[inline(always)]
+    fn partial_cmp(&self, rhs: &StdDuration) -> Option<Ordering> {
+        Some(self.cmp_std(rhs))
+    }
+}
+
+impl PartialOrd<Duration> for StdDuration {
+    #[inline(always)]
+    fn partial_cmp(&self, rhs: &Duration) -> Option<Ordering> {
+        Some(rhs.cmp_std(self).reverse())
+    }
+}
+
+impl PartialOrd<f64> for Duration {
+    #[inline(always)]
+    fn partial_cmp(&self, rhs: &f64) -> Option<Ordering> {
+        self.as_seconds_f64().partial_cmp(rhs)
+    }
+}
+
+impl PartialOrd<Duration> for f64 {
+    #[inline(always)]
+    fn partial_cmp(&self, rhs: &Duration) -> Option<Ordering> {
+        self.partial_cmp(&rhs.as_seconds_f64())
+    }
+}
+
+impl Ord for Duration {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        match self.seconds.cmp(&rhs.seconds) {
+            Less => Less,
+            Equal => self.nanoseconds.cmp(&rhs.nanoseconds),
+            Greater => Greater,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unit_values() {
+        assert_eq!(Duration::zero(), 0.seconds());
+        assert_eq!(Duration::nanosecond(), 1.nanoseconds());
+        assert_eq!(Duration::microsecond(), 1.microseconds());
+        assert_eq!(Duration::millisecond(), 1.milliseconds());
+        assert_eq!(Duration::second(), 1.seconds());
+        assert_eq!(Duration::minute(), 60.seconds());
+        assert_eq!(Duration::hour(), 3_600.seconds());
+        assert_eq!(Duration::day(), 86_400.seconds());
+        assert_eq!(Duration::week(), 604_800.seconds());
+    }
+
+    #[test]
+    fn is_unset() {
+        assert!(Duration::UNSET.is_unset());
+        assert!(Duration::min_value().is_unset());
+        assert!(!Duration::default().is_unset());
+        assert!(!0.seconds().is_unset());
+        assert!(!Duration::max_value().is_unset());
+        assert!(!(Duration::min_value() + 1.nanoseconds()).is_unset());
+        assert!(!Duration::seconds(i64::min_value()).is_unset());
+        assert!(!(-1).seconds().is_unset());
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn lt_const() {
+        const LESS: bool = Duration::seconds(1).lt_const(Duration::seconds(2));
+        const EQUAL: bool = Duration::seconds(1).lt_const(Duration::seconds(1));
+        const GREATER: bool = Duration::seconds(2).lt_const(Duration::seconds(1));
+        assert!(LESS);
+        assert!(!EQUAL);
+        assert!(!GREATER);
+
+        assert!(Duration::nanoseconds(-1).lt_const(Duration::zero()));
+        assert!(Duration::nanoseconds(-1_500_000_000).lt_const(Duration::seconds(-1)));
+        assert!(Duration::milliseconds(1_500).lt_const(Duration::seconds(2)));
+        assert!(!Duration::milliseconds(1_500).lt_const(Duration::seconds(1)));
+        assert!(Duration::min_value().lt_const(Duration::max_value()));
+        assert!(!Duration::max_value().lt_const(Duration::max_value()));
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn le_const() {
+        const LESS: bool = Duration::seconds(1).le_const(Duration::seconds(2));
+        const EQUAL: bool = Duration::seconds(1).le_const(Duration::seconds(1));
+        const GREATER: bool = Duration::seconds(2).le_const(Duration::seconds(1));
+        assert!(LESS);
+        assert!(EQUAL);
+        assert!(!GREATER);
+
+        assert!(Duration::nanoseconds(-1).le_const(Duration::zero()));
+        assert!(!Duration::zero().le_const(Duration::nanoseconds(-1)));
+        assert!(Duration::min_value().le_const(Duration::min_value()));
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn eq_const() {
+        const EQUAL: bool = Duration::minutes(1).eq_const(Duration::seconds(60));
+        const NOT_EQUAL: bool = Duration::minutes(1).eq_const(Duration::seconds(59));
+        assert!(EQUAL);
+        assert!(!NOT_EQUAL);
+
+        assert!(Duration::milliseconds(1_500).eq_const(Duration::nanoseconds(1_500_000_000)));
+        assert!(!Duration::nanoseconds(1).eq_const(Duration::nanoseconds(-1)));
+    }
+
+    #[test]
+    fn is_zero() {
+        assert!(!(-1).nanoseconds().is_zero());
+        assert!(0.seconds().is_zero());
+        assert!(!1.nanoseconds().is_zero());
+    }
+
+    #[test]
+    fn is_negative() {
+        assert!((-1).seconds().is_negative());
+        assert!(!0.seconds().is_negative());
+        assert!(!1.seconds().is_negative());
+    }
+
+    #[test]
+    fn is_positive() {
+        assert!(!(-1).seconds().is_positive());
+        assert!(!0.seconds().is_positive());
+        assert!(1.seconds().is_positive());
+    }
+
+    #[test]
+    fn is_zero_or_positive() {
+        assert!(1.seconds().is_zero_or_positive());
+        assert!(1.nanoseconds().is_zero_or_positive());
+        assert!(0.seconds().is_zero_or_positive());
+        assert!(!(-1).nanoseconds().is_zero_or_positive());
+        assert!((-1).nanoseconds().is_negative());
+        assert!(!(-1).seconds().is_zero_or_positive());
+        assert!(Duration::max_value().is_zero_or_positive());
+        assert!(!Duration::min_value().is_zero_or_positive());
+    }
+
+    #[test]
+    fn is_zero_or_negative() {
+        assert!((-1).seconds().is_zero_or_negative());
+        assert!((-1).nanoseconds().is_zero_or_negative());
+        assert!(0.seconds().is_zero_or_negative());
+        assert!(!1.nanoseconds().is_zero_or_negative());
+        assert!(1.nanoseconds().is_positive());
+        assert!(!1.seconds().is_zero_or_negative());
+        assert!(Duration::min_value().is_zero_or_negative());
+        assert!(!Duration::max_value().is_zero_or_negative());
+    }
+
+    #[test]
+    fn is_canonical() {
+        assert!(0.seconds().is_canonical());
+        assert!(1.5.seconds().is_canonical());
+        assert!((-1.5).seconds().is_canonical());
+        assert!(Duration::max_value().is_canonical());
+        assert!(Duration::min_value().is_canonical());
+        assert!(Duration::new(0, -1).is_canonical());
+        assert!(Duration::new(0, 999_999_999).is_canonical());
+        assert!(!Duration::new(1, -1).is_canonical());
+        assert!(!Duration::new(-1, 1).is_canonical());
+        assert!(!Duration {
+            seconds: 0,
+            nanoseconds: 1_000_000_000,
+        }
+        .is_canonical());
+        assert!(!Duration {
+            seconds: 0,
+            nanoseconds: i32::min_value(),
+        }
+        .is_canonical());
+        assert!(Duration::from_timespec(-2, 500_000_000).is_canonical());
+        assert!(Duration::from_timespec(1, -2_500_000_000).is_canonical());
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(1.5.seconds().normalize(), 1.5.seconds());
+        assert_eq!(Duration::new(1, -1).normalize(), 999_999_999.nanoseconds());
+        assert_eq!(
+            Duration::new(-1, 1).normalize(),
+            (-999_999_999).nanoseconds()
+        );
+        assert_eq!(
+            Duration {
+                seconds: -2,
+                nanoseconds: 2_100_000_000,
+            }
+            .normalize(),
+            100.milliseconds()
+        );
+        assert_eq!(
+            Duration {
+                seconds: 0,
+                nanoseconds: i32::min_value(),
+            }
+            .normalize(),
+            Duration::new(-2, -147_483_648)
+        );
+        assert_eq!(
+            Duration {
+                seconds: i64::max_value(),
+                nanoseconds: i32::max_value(),
+            }
+            .normalize(),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration {
+                seconds: i64::min_value(),
+                nanoseconds: i32::min_value(),
+            }
+            .normalize(),
+            Duration::min_value()
+        );
+
+        // Denormalized timespec-like fields, where the nanoseconds are
+        // positive regardless of the sign of the seconds.
+        for &(seconds, nanoseconds) in &[(-2, 500_000_000), (-1, 1), (0, 1), (5, 999_999_999)] {
+            let duration = Duration {
+                seconds,
+                nanoseconds,
+            }
+            .normalize();
+            assert!(duration.is_canonical());
+            assert_eq!(
+                duration,
+                Duration::from_timespec(seconds, nanoseconds as i64)
+            );
+        }
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn sign() {
+        use crate::Sign::*;
+        assert_eq!(1.seconds().sign(), Positive);
+        assert_eq!((-1).seconds().sign(), Negative);
+        assert_eq!(0.seconds().sign(), Zero);
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(1.seconds().abs(), 1.seconds());
+        assert_eq!(0.seconds().abs(), 0.seconds());
+        assert_eq!((-1).seconds().abs(), 1.seconds());
+    }
+
+    #[test]
+    fn saturating_abs() {
+        assert_eq!(1.seconds().saturating_abs(), 1.seconds());
+        assert_eq!(0.seconds().saturating_abs(), 0.seconds());
+        assert_eq!((-1).seconds().saturating_abs(), 1.seconds());
+        assert_eq!((-1).nanoseconds().saturating_abs(), 1.nanoseconds());
+        assert_eq!(
+            Duration::max_value().saturating_abs(),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().saturating_abs(),
+            Duration::max_value()
+        );
+        assert_eq!(
+            (Duration::min_value() + 1.seconds()).saturating_abs(),
+            Duration::max_value()
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(Duration::new(1, 0), 1.seconds());
+        assert_eq!(Duration::new(-1, 0), (-1).seconds());
+        assert_eq!(Duration::new(1, 2_000_000_000), 3.seconds());
+
+        assert!(Duration::new(0, 0).is_zero());
+        assert!(Duration::new(0, 1_000_000_000).is_positive());
+        assert!(Duration::new(-1, 1_000_000_000).is_zero());
+        assert!(Duration::new(-2, 1_000_000_000).is_negative());
+        assert!(Duration::new(1, 2_000_000_000).is_canonical());
+        assert!(Duration::new(-1, -2_000_000_000).is_canonical());
+        assert!(Duration::new(0, -1).is_canonical());
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(Duration::weeks(1), 604_800.seconds());
+        assert_eq!(Duration::weeks(2), (2 * 604_800).seconds());
+        assert_eq!(Duration::weeks(-1), (-604_800).seconds());
+        assert_eq!(Duration::weeks(-2), (2 * -604_800).seconds());
+        assert!(Duration::weeks(1).is_canonical());
+        assert!(Duration::weeks(-2).is_canonical());
+    }
+
+    #[test]
+    fn whole_weeks() {
+        assert_eq!(Duration::weeks(1).whole_weeks(), 1);
+        assert_eq!(Duration::weeks(-1).whole_weeks(), -1);
+        assert_eq!(Duration::days(6).whole_weeks(), 0);
+        assert_eq!(Duration::days(-6).whole_weeks(), 0);
+        assert_eq!(Duration::days(-8).whole_weeks(), -1);
+        assert_eq!(Duration::days(-13).whole_weeks(), -1);
+        assert_eq!((-1).nanoseconds().whole_weeks(), 0);
+        assert_eq!((Duration::weeks(-1) - 1.nanoseconds()).whole_weeks(), -1);
+        assert_eq!(Duration::min_value().whole_weeks(), -15_250_284_452_471);
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(Duration::days(1), 86_400.seconds());
+        assert_eq!(Duration::days(2), (2 * 86_400).seconds());
+        assert_eq!(Duration::days(-1), (-86_400).seconds());
+        assert_eq!(Duration::days(-2), (2 * -86_400).seconds());
+        assert!(Duration::days(1).is_canonical());
+        assert!(Duration::days(-2).is_canonical());
+    }
+
+    #[test]
+    fn whole_days() {
+        assert_eq!(Duration::days(1).whole_days(), 1);
+        assert_eq!(Duration::days(-1).whole_days(), -1);
+        assert_eq!(Duration::hours(23).whole_days(), 0);
+        assert_eq!(Duration::hours(-23).whole_days(), 0);
+        assert_eq!(Duration::hours(-25).whole_days(), -1);
+        assert_eq!(Duration::hours(-47).whole_days(), -1);
+        assert_eq!((-1).nanoseconds().whole_days(), 0);
+        assert_eq!((Duration::days(-1) - 1.nanoseconds()).whole_days(), -1);
+        assert_eq!(Duration::min_value().whole_days(), -106_751_991_167_300);
+    }
+
+    #[test]
+    fn hours() {
+        assert_eq!(Duration::hours(1), 3_600.seconds());
+        assert_eq!(Duration::hours(2), (2 * 3_600).seconds());
+        assert_eq!(Duration::hours(-1), (-3_600).seconds());
+        assert_eq!(Duration::hours(-2), (2 * -3_600).seconds());
+        assert!(Duration::hours(1).is_canonical());
+        assert!(Duration::hours(-2).is_canonical());
+    }
+
+    #[test]
+    fn whole_hours() {
+        assert_eq!(Duration::hours(1).whole_hours(), 1);
+        assert_eq!(Duration::hours(-1).whole_hours(), -1);
+        assert_eq!(Duration::minutes(59).whole_hours(), 0);
+        assert_eq!(Duration::minutes(-59).whole_hours(), 0);
+        assert_eq!(Duration::minutes(-61).whole_hours(), -1);
+        assert_eq!(Duration::minutes(-119).whole_hours(), -1);
+        assert_eq!((-1).nanoseconds().whole_hours(), 0);
+        assert_eq!((Duration::hours(-1) - 1.nanoseconds()).whole_hours(), -1);
+        assert_eq!(Duration::min_value().whole_hours(), -2_562_047_788_015_215);
+    }
+
+    #[test]
+    fn minutes() {
+        assert_eq!(Duration::minutes(1), 60.seconds());
+        assert_eq!(Duration::minutes(2), (2 * 60).seconds());
+        assert_eq!(Duration::minutes(-1), (-60).seconds());
+        assert_eq!(Duration::minutes(-2), (2 * -60).seconds());
+        assert!(Duration::minutes(1).is_canonical());
+        assert!(Duration::minutes(-2).is_canonical());
+    }
+
+    #[test]
+    fn from_minutes_saturating() {
+        assert_eq!(Duration::from_minutes_saturating(0), Duration::zero());
+        assert_eq!(Duration::from_minutes_saturating(1), 1.minutes());
+        assert_eq!(Duration::from_minutes_saturating(-90), (-90).minutes());
+        assert_eq!(
+            Duration::from_minutes_saturating(i64::max_value() / 60),
+            Duration::minutes(i64::max_value() / 60)
+        );
+        assert_eq!(
+            Duration::from_minutes_saturating(i64::max_value() / 60 + 1),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_minutes_saturating(i64::max_value()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_minutes_saturating(i64::min_value() / 60),
+            Duration::minutes(i64::min_value() / 60)
+        );
+        assert_eq!(
+            Duration::from_minutes_saturating(i64::min_value() / 60 - 1),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::from_minutes_saturating(i64::min_value()),
+            Duration::min_value()
+        );
+        assert!(Duration::from_minutes_saturating(-90).is_canonical());
+        assert!(Duration::from_minutes_saturating(i64::min_value()).is_canonical());
+    }
+
+    #[test]
+    fn whole_minutes() {
+        assert_eq!(1.minutes().whole_minutes(), 1);
+        assert_eq!((-1).minutes().whole_minutes(), -1);
+        assert_eq!(59.seconds().whole_minutes(), 0);
+        assert_eq!((-59).seconds().whole_minutes(), 0);
+        assert_eq!((-61).seconds().whole_minutes(), -1);
+        assert_eq!((-119).seconds().whole_minutes(), -1);
+        assert_eq!((-1).nanoseconds().whole_minutes(), 0);
+        assert_eq!(((-1).minutes() - 1.nanoseconds()).whole_minutes(), -1);
+        assert_eq!(
+            Duration::min_value().whole_minutes(),
+            -153_722_867_280_912_930
+        );
+    }
+
+    #[test]
+    fn seconds() {
+        assert_eq!(Duration::seconds(1), 1_000.milliseconds());
+        assert_eq!(Duration::seconds(2), (2 * 1_000).milliseconds());
+        assert_eq!(Duration::seconds(-1), (-1_000).milliseconds());
+        assert_eq!(Duration::seconds(-2), (2 * -1_000).milliseconds());
+        assert!(Duration::seconds(i64::max_value()).is_canonical());
+        assert!(Duration::seconds(i64::min_value()).is_canonical());
+    }
+
+    #[test]
+    fn whole_seconds() {
+        assert_eq!(1.seconds().whole_seconds(), 1);
+        assert_eq!((-1).seconds().whole_seconds(), -1);
+        assert_eq!(1.minutes().whole_seconds(), 60);
+        assert_eq!((-1).minutes().whole_seconds(), -60);
+        assert_eq!((-1).nanoseconds().whole_seconds(), 0);
+        assert_eq!((-999).milliseconds().whole_seconds(), 0);
+        assert_eq!((-1_999).milliseconds().whole_seconds(), -1);
+        assert_eq!((-1.5).seconds().whole_seconds(), -1);
+        assert_eq!(Duration::min_value().whole_seconds(), i64::min_value());
+    }
+
+    #[test]
+    fn seconds_f64() {
+        assert_eq!(Duration::seconds_f64(0.5), 0.5.seconds());
+        assert_eq!(Duration::seconds_f64(-0.5), (-0.5).seconds());
+    }
+
+    #[test]
+    fn negative_zero() {
+        for &duration in &[
+            Duration::seconds_f64(-0.),
+            Duration::seconds_f32(-0.),
+            Duration::seconds_f64(-1e-10),
+            1.seconds() * -0_f64,
+            (-1).seconds() * 0_f64,
+            0.seconds() / -1_f64,
+            Duration::from_days_f64(-0.),
+        ] {
+            assert_eq!(duration, Duration::zero());
+            assert!(!duration.is_negative());
+            assert_eq!(duration.to_string(), "0s");
+            assert_eq!(duration.format_seconds(None), "0");
+            assert_eq!(duration.format_seconds(Some(3)), "0.000");
+            assert_eq!(duration.to_iso8601(), "PT0S");
+        }
+    }
+
+    #[test]
+    fn seconds_f64_rounding() {
+        assert_eq!(Duration::seconds_f64(6e-10), 1.nanoseconds());
+        assert_eq!(Duration::seconds_f64(-6e-10), (-1).nanoseconds());
+        assert_eq!(Duration::seconds_f64(1e-12), Duration::zero());
+        assert_eq!(Duration::seconds_f64(4.9e-10), Duration::zero());
+        assert_eq!(Duration::seconds_f64(5e-10), 1.nanoseconds());
+        assert_eq!(Duration::seconds_f64(-5e-10), (-1).nanoseconds());
+        assert_eq!(Duration::seconds_f64(1.5e-9), 2.nanoseconds());
+        assert_eq!(Duration::seconds_f64(2.4e-9), 2.nanoseconds());
+        assert_eq!(
+            Duration::seconds_f64(core::f64::MIN_POSITIVE),
+            Duration::zero()
+        );
+        assert_eq!(Duration::seconds_f64(5e-324), Duration::zero());
+        assert!(!Duration::seconds_f64(-5e-324).is_negative());
+        assert_eq!(Duration::seconds_f64(0.999_999_999_9), 1.seconds());
+        assert_eq!(Duration::seconds_f64(-0.999_999_999_9), (-1).seconds());
+        assert_eq!(Duration::seconds_f64(1.999_999_999_9), 2.seconds());
+    }
+
+    #[test]
+    #[cfg(serde)]
+    fn negative_zero_serde() -> serde_json::Result<()> {
+        assert_eq!(
+            serde_json::to_string(&Duration::seconds_f64(-0.))?,
+            serde_json::to_string(&Duration::seconds_f64(0.))?
+        );
+        assert_eq!(serde_json::to_string(&Duration::seconds_f64(-0.))?, "[0,0]");
+        Ok(())
+    }
+
+    #[test]
+    fn seconds_f64_round_trip() {
+        let magnitudes = [
+            0.,
+            1e-10,
+            1e-9,
+            5e-9,
+            0.1,
+            0.3,
+            0.5,
+            0.999_999_999,
+            1.,
+            1.5,
+            2.25,
+            59.999,
+            3_600.000_001,
+            86_400.5,
+            1e9 + 0.25,
+            1e15 + 0.5,
+        ];
+
+        for &magnitude in &magnitudes {
+            for &x in &[magnitude, -magnitude] {
+                let duration = Duration::seconds_f64(x);
+                let tolerance = f64::max(1e-9, x.abs() * core::f64::EPSILON);
+                assert!(
+                    (duration.as_seconds_f64() - x).abs() <= tolerance,
+                    "{} did not round-trip (got {})",
+                    x,
+                    duration.as_seconds_f64()
+                );
+
+                // The sign of both fields must agree with the input.
+                assert!(duration.whole_seconds() * x.signum() as i64 >= 0);
+                assert!(duration.subsec_nanoseconds() * x.signum() as i32 >= 0);
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn as_seconds_f64() {
+        assert_eq!(1.seconds().as_seconds_f64(), 1.0);
+        assert_eq!((-1).seconds().as_seconds_f64(), -1.0);
+        assert_eq!(1.minutes().as_seconds_f64(), 60.0);
+        assert_eq!((-1).minutes().as_seconds_f64(), -60.0);
+        assert_eq!(1.5.seconds().as_seconds_f64(), 1.5);
+        assert_eq!((-1.5).seconds().as_seconds_f64(), -1.5);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn as_seconds_f64_high_precision() {
+        assert_eq!(1.seconds().as_seconds_f64_high_precision(), 1.0);
+        assert_eq!((-1).minutes().as_seconds_f64_high_precision(), -60.0);
+        assert_eq!(1.5.seconds().as_seconds_f64_high_precision(), 1.5);
+        assert_eq!((-1.5).seconds().as_seconds_f64_high_precision(), -1.5);
+        assert_eq!(
+            123_456.789.seconds().as_seconds_f64_high_precision(),
+            123_456.789.seconds().as_seconds_f64()
+        );
+
+        // 2^53 + 1.6 seconds is nearest to 2^53 + 2, but converting the whole
+        // seconds first rounds down to 2^53.
+        let duration = Duration::seconds((1 << 53) + 1) + 600.milliseconds();
+        assert_eq!(duration.as_seconds_f64(), 9_007_199_254_740_992.);
+        assert_eq!(
+            duration.as_seconds_f64_high_precision(),
+            9_007_199_254_740_994.
+        );
+        assert_eq!(
+            (-duration).as_seconds_f64_high_precision(),
+            -9_007_199_254_740_994.
+        );
+
+        // The whole seconds lie exactly halfway between two representable
+        // values and round down to even, but the fraction breaks the tie.
+        let duration = Duration::seconds((1 << 62) + 512) + 500.milliseconds();
+        assert_eq!(duration.as_seconds_f64(), 4_611_686_018_427_387_904.);
+        assert_eq!(
+            duration.as_seconds_f64_high_precision(),
+            4_611_686_018_427_388_928.
+        );
+
+        assert_eq!(
+            Duration::max_value().as_seconds_f64_high_precision(),
+            9_223_372_036_854_775_808.
+        );
+        assert_eq!(
+            Duration::min_value().as_seconds_f64_high_precision(),
+            -9_223_372_036_854_775_808.
+        );
+    }
+
+    #[test]
+    fn seconds_f32() {
+        assert_eq!(Duration::seconds_f32(0.5), 0.5.seconds());
+        assert_eq!(Duration::seconds_f32(-0.5), (-0.5).seconds());
+    }
+
+    #[test]
+    fn from_days_f64() {
+        assert_eq!(Duration::from_days_f64(1.5), 36.hours());
+        assert_eq!(Duration::from_days_f64(-0.5), (-12).hours());
+        assert_eq!(Duration::from_days_f64(0.), Duration::zero());
+        assert_eq!(
+            Duration::from_days_f64(core::f64::INFINITY),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_days_f64(core::f64::NEG_INFINITY),
+            Duration::min_value()
+        );
+        assert_eq!(Duration::from_days_f64(1e300), Duration::max_value());
+        assert_eq!(Duration::from_days_f64(core::f64::NAN), Duration::zero());
+    }
+
+    #[test]
+    fn from_hours_f64() {
+        assert_eq!(Duration::from_hours_f64(1.5), 90.minutes());
+        assert_eq!(Duration::from_hours_f64(-1.5), (-90).minutes());
+        assert_eq!(
+            Duration::from_hours_f64(core::f64::INFINITY),
+            Duration::max_value()
+        );
+        assert_eq!(Duration::from_hours_f64(core::f64::NAN), Duration::zero());
+    }
+
+    #[test]
+    fn from_minutes_f64() {
+        assert_eq!(Duration::from_minutes_f64(1.5), 90.seconds());
+        assert_eq!(Duration::from_minutes_f64(-1.5), (-90).seconds());
+        assert_eq!(
+            Duration::from_minutes_f64(core::f64::NEG_INFINITY),
+            Duration::min_value()
+        );
+        assert_eq!(Duration::from_minutes_f64(core::f64::NAN), Duration::zero());
+    }
+
+    #[test]
+    fn from_days_f32() {
+        assert_eq!(Duration::from_days_f32(1.5), 36.hours());
+        assert_eq!(Duration::from_days_f32(-0.5), (-12).hours());
+        assert_eq!(
+            Duration::from_days_f32(core::f32::INFINITY),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_days_f32(core::f32::NEG_INFINITY),
+            Duration::min_value()
+        );
+        assert_eq!(Duration::from_days_f32(core::f32::NAN), Duration::zero());
+    }
+
+    #[test]
+    fn from_hours_f32() {
+        assert_eq!(Duration::from_hours_f32(1.5), 90.minutes());
+        assert_eq!(Duration::from_hours_f32(-1.5), (-90).minutes());
+        assert_eq!(
+            Duration::from_hours_f32(core::f32::INFINITY),
+            Duration::max_value()
+        );
+    }
+
+    #[test]
+    fn from_minutes_f32() {
+        assert_eq!(Duration::from_minutes_f32(1.5), 90.seconds());
+        assert_eq!(Duration::from_minutes_f32(-1.5), (-90).seconds());
+        assert_eq!(
+            Duration::from_minutes_f32(core::f32::INFINITY),
+            Duration::max_value()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn as_seconds_f32() {
+        assert_eq!(1.seconds().as_seconds_f32(), 1.0);
+        assert_eq!((-1).seconds().as_seconds_f32(), -1.0);
+        assert_eq!(1.minutes().as_seconds_f32(), 60.0);
+        assert_eq!((-1).minutes().as_seconds_f32(), -60.0);
+        assert_eq!(1.5.seconds().as_seconds_f32(), 1.5);
+        assert_eq!((-1.5).seconds().as_seconds_f32(), -1.5);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn as_secs_float() {
+        for &duration in &[
+            0.seconds(),
+            1.5.seconds(),
+            (-1.5).seconds(),
+            1.nanoseconds(),
+            Duration::new(123_456, 789_012_345),
+            Duration::max_value(),
+            Duration::min_value(),
+        ] {
+            assert_eq!(duration.as_secs_f64(), duration.as_seconds_f64());
+            assert_eq!(duration.as_secs_f32(), duration.as_seconds_f32());
+        }
+    }
+
+    #[test]
+    fn milliseconds() {
+        assert_eq!(Duration::milliseconds(1), 1_000.microseconds());
+        assert_eq!(Duration::milliseconds(-1), (-1000).microseconds());
+        assert!(Duration::milliseconds(1_500).is_canonical());
+        assert!(Duration::milliseconds(-1_500).is_canonical());
+        assert!(Duration::milliseconds(i64::min_value()).is_canonical());
+    }
+
+    #[test]
+    fn whole_milliseconds() {
+        assert_eq!(1.seconds().whole_milliseconds(), 1_000);
+        assert_eq!((-1).seconds().whole_milliseconds(), -1_000);
+        assert_eq!(1.milliseconds().whole_milliseconds(), 1);
+        assert_eq!((-1).milliseconds().whole_milliseconds(), -1);
+        assert_eq!((-1).nanoseconds().whole_milliseconds(), 0);
+        assert_eq!((-999).microseconds().whole_milliseconds(), 0);
+        assert_eq!((-1_999).microseconds().whole_milliseconds(), -1);
+        assert_eq!((-1.0015).seconds().whole_milliseconds(), -1_001);
     }
 
-    #[inline(always)]
-    #[allow(deprecated)]
-    #[deprecated(
-        since = "0.2.0",
-        note = "Use `std::time::Duration::try_from(value)` or `value.try_into()`"
-    )]
-    pub fn to_std(&self) -> Result<StdDuration, ConversionRangeError> {
-        (*self).try_into()
+    #[test]
+    fn subsec_milliseconds() {
+        assert_eq!(1.4.seconds().subsec_milliseconds(), 400);
+        assert_eq!((-1.4).seconds().subsec_milliseconds(), -400);
     }
-}
 
-impl TryFrom<StdDuration> for Duration {
-    type Error = ConversionRangeError;
+    #[test]
+    fn subsec_milliseconds_abs() {
+        assert_eq!(1.4.seconds().subsec_milliseconds_abs(), 400);
+        assert_eq!((-1.4).seconds().subsec_milliseconds_abs(), 400);
+        assert_eq!(1.seconds().subsec_milliseconds_abs(), 0);
+        assert_eq!(Duration::min_value().subsec_milliseconds_abs(), 999);
+    }
 
-    #[inline(always)]
-    fn try_from(original: StdDuration) -> Result<Self, ConversionRangeError> {
-        Ok(Self::new(
-            original
-                .as_secs()
-                .try_into()
-                .map_err(|_| ConversionRangeError::new())?,
-            original
-                .subsec_nanos()
-                .try_into()
-                .map_err(|_| ConversionRangeError::new())?,
-        ))
+    #[test]
+    fn microseconds() {
+        assert_eq!(Duration::microseconds(1), 1_000.nanoseconds());
+        assert_eq!(Duration::microseconds(-1), (-1_000).nanoseconds());
+        assert!(Duration::microseconds(1_500_000).is_canonical());
+        assert!(Duration::microseconds(-1_500_000).is_canonical());
+        assert!(Duration::microseconds(i64::min_value()).is_canonical());
     }
-}
 
-impl TryFrom<Duration> for StdDuration {
-    type Error = ConversionRangeError;
+    #[test]
+    fn from_millis_i128() {
+        assert_eq!(Duration::from_millis_i128(0), 0.seconds());
+        assert_eq!(Duration::from_millis_i128(1_500), 1.5.seconds());
+        assert_eq!(Duration::from_millis_i128(-1_500), (-1.5).seconds());
+        assert_eq!(
+            Duration::from_millis_i128(i64::max_value() as i128),
+            Duration::milliseconds(i64::max_value())
+        );
+        assert_eq!(
+            Duration::from_millis_i128(i64::max_value() as i128 * 1_000),
+            Duration::seconds(i64::max_value())
+        );
+        assert_eq!(
+            Duration::from_millis_i128(i64::max_value() as i128 * 1_000 + 1_000),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_millis_i128(i64::min_value() as i128 * 1_000 - 1_000),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::from_millis_i128(i128::max_value()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_millis_i128(i128::min_value()),
+            Duration::min_value()
+        );
+    }
 
-    #[inline(always)]
-    fn try_from(duration: Duration) -> Result<Self, ConversionRangeError> {
-        Ok(Self::new(
-            duration
-                .seconds
-                .try_into()
-                .map_err(|_| ConversionRangeError::new())?,
-            duration
-                .nanoseconds
-                .try_into()
-                .map_err(|_| ConversionRangeError::new())?,
-        ))
+    #[test]
+    fn from_micros_i128() {
+        assert_eq!(Duration::from_micros_i128(0), 0.seconds());
+        assert_eq!(Duration::from_micros_i128(1), 1.microseconds());
+        assert_eq!(Duration::from_micros_i128(-1), (-1).microseconds());
+        assert_eq!(
+            Duration::from_micros_i128(1_234_567_890_123_456),
+            Duration::microseconds(1_234_567_890_123_456)
+        );
+        // Beyond the range of `Duration::microseconds`, but still representable.
+        assert_eq!(
+            Duration::from_micros_i128(i64::max_value() as i128 + 1),
+            Duration::nanoseconds_i128((i64::max_value() as i128 + 1) * 1_000)
+        );
+        assert_eq!(
+            Duration::from_micros_i128(i64::max_value() as i128 * 1_000_000),
+            Duration::seconds(i64::max_value())
+        );
+        assert_eq!(
+            Duration::from_micros_i128(i64::max_value() as i128 * 1_000_000 + 1_000_000),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_micros_i128(i64::min_value() as i128 * 1_000_000 - 1_000_000),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::from_micros_i128(i128::max_value()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_micros_i128(i128::min_value()),
+            Duration::min_value()
+        );
     }
-}
 
-impl Add for Duration {
-    type Output = Self;
+    #[test]
+    fn whole_milliseconds_i64() {
+        assert_eq!(1.seconds().whole_milliseconds_i64(), 1_000);
+        assert_eq!((-1).seconds().whole_milliseconds_i64(), -1_000);
+        assert_eq!(1_999.microseconds().whole_milliseconds_i64(), 1);
+        assert_eq!((-1_999).microseconds().whole_milliseconds_i64(), -1);
+        assert_eq!(
+            Duration::milliseconds(i64::max_value()).whole_milliseconds_i64(),
+            i64::max_value()
+        );
+        assert_eq!(
+            Duration::max_value().whole_milliseconds_i64(),
+            i64::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().whole_milliseconds_i64(),
+            i64::min_value()
+        );
+        assert_eq!((-999).microseconds().whole_milliseconds_i64(), 0);
+    }
 
-    #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.checked_add(rhs)
-            .expect("overflow when adding durations")
+    #[test]
+    fn as_millis_i64_saturating() {
+        assert_eq!(1_500.milliseconds().as_millis_i64_saturating(), 1_500);
+        assert_eq!((-1_500).milliseconds().as_millis_i64_saturating(), -1_500);
+        assert_eq!(1_999_999.nanoseconds().as_millis_i64_saturating(), 1);
+        assert_eq!(0.seconds().as_millis_i64_saturating(), 0);
+        assert_eq!(
+            Duration::milliseconds(i64::max_value()).as_millis_i64_saturating(),
+            i64::max_value()
+        );
+        assert_eq!(
+            Duration::max_value().as_millis_i64_saturating(),
+            i64::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().as_millis_i64_saturating(),
+            i64::min_value()
+        );
     }
-}
 
-impl Add<StdDuration> for Duration {
-    type Output = Self;
+    #[test]
+    fn whole_microseconds() {
+        assert_eq!(1.milliseconds().whole_microseconds(), 1_000);
+        assert_eq!((-1).milliseconds().whole_microseconds(), -1_000);
+        assert_eq!(1.microseconds().whole_microseconds(), 1);
+        assert_eq!((-1).microseconds().whole_microseconds(), -1);
+        assert_eq!((-999).nanoseconds().whole_microseconds(), 0);
+        assert_eq!((-1_999).nanoseconds().whole_microseconds(), -1);
+        assert_eq!(
+            ((-1).seconds() - 1_500.nanoseconds()).whole_microseconds(),
+            -1_000_001
+        );
+    }
 
-    #[inline(always)]
-    fn add(self, std_duration: StdDuration) -> Self::Output {
-        self + Self::try_from(std_duration)
-            .expect("overflow converting `std::time::Duration` to `time::Duration`")
+    #[test]
+    fn whole_microseconds_i64() {
+        assert_eq!(1.milliseconds().whole_microseconds_i64(), 1_000);
+        assert_eq!((-1).milliseconds().whole_microseconds_i64(), -1_000);
+        assert_eq!(1_999.nanoseconds().whole_microseconds_i64(), 1);
+        assert_eq!((-1_999).nanoseconds().whole_microseconds_i64(), -1);
+        assert_eq!(
+            Duration::microseconds(i64::min_value()).whole_microseconds_i64(),
+            i64::min_value()
+        );
+        assert_eq!(
+            Duration::max_value().whole_microseconds_i64(),
+            i64::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().whole_microseconds_i64(),
+            i64::min_value()
+        );
+        assert_eq!((-999).nanoseconds().whole_microseconds_i64(), 0);
     }
-}
 
-impl Add<Duration> for StdDuration {
-    type Output = Duration;
+    #[test]
+    fn subsec_microseconds() {
+        assert_eq!(1.0004.seconds().subsec_microseconds(), 400);
+        assert_eq!((-1.0004).seconds().subsec_microseconds(), -400);
+    }
 
-    #[inline(always)]
-    fn add(self, rhs: Duration) -> Self::Output {
-        rhs + self
+    #[test]
+    fn subsec_microseconds_abs() {
+        assert_eq!(1.0004.seconds().subsec_microseconds_abs(), 400);
+        assert_eq!((-1.0004).seconds().subsec_microseconds_abs(), 400);
+        assert_eq!(1.seconds().subsec_microseconds_abs(), 0);
+        assert_eq!(Duration::min_value().subsec_microseconds_abs(), 999_999);
     }
-}
 
-impl AddAssign for Duration {
-    #[inline(always)]
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
+    #[test]
+    fn nanoseconds() {
+        assert_eq!(Duration::nanoseconds(1), 1.microseconds() / 1_000);
+        assert_eq!(Duration::nanoseconds(-1), (-1).microseconds() / 1_000);
+        assert!(Duration::nanoseconds(1_500_000_000).is_canonical());
+        assert!(Duration::nanoseconds(-1_500_000_000).is_canonical());
+        assert!(Duration::nanoseconds(i64::max_value()).is_canonical());
+        assert!(Duration::nanoseconds(i64::min_value()).is_canonical());
+        assert!(Duration::nanoseconds_i128(-1_500_000_000).is_canonical());
+        assert!(
+            Duration::nanoseconds_i128(Duration::min_value().whole_nanoseconds()).is_canonical()
+        );
     }
-}
 
-impl AddAssign<StdDuration> for Duration {
-    #[inline(always)]
-    fn add_assign(&mut self, rhs: StdDuration) {
-        *self = *self + rhs;
+    #[test]
+    fn whole_nanoseconds() {
+        assert_eq!(1.microseconds().whole_nanoseconds(), 1_000);
+        assert_eq!((-1).microseconds().whole_nanoseconds(), -1_000);
+        assert_eq!(1.nanoseconds().whole_nanoseconds(), 1);
+        assert_eq!((-1).nanoseconds().whole_nanoseconds(), -1);
+        assert_eq!((-1.5).seconds().whole_nanoseconds(), -1_500_000_000);
     }
-}
 
-impl Neg for Duration {
-    type Output = Self;
+    #[test]
+    fn whole_nanoseconds_i64() {
+        assert_eq!(0.seconds().whole_nanoseconds_i64(), 0);
+        assert_eq!(1.5.seconds().whole_nanoseconds_i64(), 1_500_000_000);
+        assert_eq!((-1.5).seconds().whole_nanoseconds_i64(), -1_500_000_000);
+        assert_eq!(
+            Duration::nanoseconds(i64::min_value()).whole_nanoseconds_i64(),
+            i64::min_value()
+        );
+        assert_eq!(
+            Duration::max_value().whole_nanoseconds_i64(),
+            i64::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().whole_nanoseconds_i64(),
+            i64::min_value()
+        );
+    }
 
-    #[inline(always)]
-    fn neg(self) -> Self::Output {
-        -1 * self
+    #[test]
+    fn as_nanos_i64_saturating() {
+        assert_eq!(0.seconds().as_nanos_i64_saturating(), 0);
+        assert_eq!(1.5.seconds().as_nanos_i64_saturating(), 1_500_000_000);
+        assert_eq!((-1.5).seconds().as_nanos_i64_saturating(), -1_500_000_000);
+        assert_eq!(
+            (200 * 365).days().as_nanos_i64_saturating(),
+            200 * 365 * 86_400 * 1_000_000_000
+        );
+        assert_eq!(
+            Duration::nanoseconds(i64::max_value()).as_nanos_i64_saturating(),
+            i64::max_value()
+        );
+        assert_eq!(
+            Duration::nanoseconds(i64::min_value()).as_nanos_i64_saturating(),
+            i64::min_value()
+        );
+        assert_eq!(
+            (Duration::nanoseconds(i64::max_value()) + 1.nanoseconds()).as_nanos_i64_saturating(),
+            i64::max_value()
+        );
+        assert_eq!(
+            (300 * 365).days().as_nanos_i64_saturating(),
+            i64::max_value()
+        );
+        assert_eq!(
+            (-300 * 365).days().as_nanos_i64_saturating(),
+            i64::min_value()
+        );
+        assert_eq!(
+            Duration::max_value().as_nanos_i64_saturating(),
+            i64::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().as_nanos_i64_saturating(),
+            i64::min_value()
+        );
     }
-}
 
-impl Sub for Duration {
-    type Output = Self;
+    #[test]
+    fn checked_whole_nanoseconds() {
+        assert_eq!(1.microseconds().checked_whole_nanoseconds(), Some(1_000));
+        assert_eq!(
+            (-1).microseconds().checked_whole_nanoseconds(),
+            Some(-1_000)
+        );
 
-    #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.checked_sub(rhs)
-            .expect("overflow when subtracting durations")
+        for &duration in &[
+            0.seconds(),
+            1.nanoseconds(),
+            (-1).nanoseconds(),
+            1.5.seconds(),
+            (-1.5).seconds(),
+            Duration::new(123_456_789, 987_654_321),
+            Duration::seconds(i64::max_value()),
+            Duration::seconds(i64::min_value()),
+            Duration::max_value(),
+            Duration::min_value(),
+        ] {
+            assert_eq!(
+                duration.checked_whole_nanoseconds(),
+                Some(duration.whole_nanoseconds())
+            );
+        }
     }
-}
 
-impl Sub<StdDuration> for Duration {
-    type Output = Self;
+    #[test]
+    fn subsec_nanoseconds() {
+        assert_eq!(1.000_000_4.seconds().subsec_nanoseconds(), 400);
+        assert_eq!((-1.000_000_4).seconds().subsec_nanoseconds(), -400);
+    }
 
-    #[inline(always)]
-    fn sub(self, rhs: StdDuration) -> Self::Output {
-        self - Self::try_from(rhs)
-            .expect("overflow converting `std::time::Duration` to `time::Duration`")
+    #[test]
+    fn subsec_nanoseconds_abs() {
+        assert_eq!(1.000_000_4.seconds().subsec_nanoseconds_abs(), 400);
+        assert_eq!((-1.000_000_4).seconds().subsec_nanoseconds_abs(), 400);
+        assert_eq!(1.seconds().subsec_nanoseconds_abs(), 0);
+        assert_eq!(Duration::min_value().subsec_nanoseconds_abs(), 999_999_999);
     }
-}
 
-impl Sub<Duration> for StdDuration {
-    type Output = Duration;
+    #[test]
+    fn to_packed_i128() {
+        assert_eq!(0.seconds().to_packed_i128(), 0);
+        assert_eq!(1.5.seconds().to_packed_i128(), 1_500_000_000);
+        assert_eq!((-1.5).seconds().to_packed_i128(), -1_500_000_000);
+        assert!(1.nanoseconds().to_packed_i128() > 0.seconds().to_packed_i128());
+        assert!((-1).nanoseconds().to_packed_i128() < 0.seconds().to_packed_i128());
+    }
 
-    #[inline(always)]
-    fn sub(self, rhs: Duration) -> Self::Output {
-        Duration::try_from(self)
-            .expect("overflow converting `std::time::Duration` to `time::Duration`")
-            - rhs
+    #[test]
+    fn from_packed_i128() {
+        assert_eq!(Duration::from_packed_i128(0), Ok(0.seconds()));
+        assert_eq!(Duration::from_packed_i128(1_500_000_000), Ok(1.5.seconds()));
+        assert_eq!(
+            Duration::from_packed_i128(-1_500_000_000),
+            Ok((-1.5).seconds())
+        );
+        assert!(Duration::from_packed_i128(i128::max_value()).is_err());
+        assert!(Duration::from_packed_i128(i128::min_value()).is_err());
+        assert!(Duration::from_packed_i128(Duration::max_value().to_packed_i128() + 1).is_err());
+        assert!(Duration::from_packed_i128(Duration::min_value().to_packed_i128() - 1).is_err());
     }
-}
 
-impl SubAssign for Duration {
-    #[inline(always)]
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+    #[test]
+    fn packed_i128_round_trip() {
+        let mut durations = vec![
+            Duration::zero(),
+            Duration::nanosecond(),
+            -Duration::nanosecond(),
+            Duration::max_value(),
+            Duration::min_value(),
+        ];
+
+        // A simple linear congruential generator, providing deterministic
+        // pseudo-random values.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..1_000 {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let seconds = state as i64;
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let nanoseconds = ((state >> 33) % 1_000_000_000) as i32;
+            durations.push(Duration::new(
+                seconds,
+                if seconds < 0 {
+                    -nanoseconds
+                } else {
+                    nanoseconds
+                },
+            ));
+        }
+
+        for &duration in &durations {
+            assert_eq!(
+                Duration::from_packed_i128(duration.to_packed_i128()),
+                Ok(duration)
+            );
+        }
     }
-}
 
-impl SubAssign<StdDuration> for Duration {
-    #[inline(always)]
-    fn sub_assign(&mut self, rhs: StdDuration) {
-        *self = *self - rhs;
+    #[test]
+    fn from_timespec() {
+        assert_eq!(Duration::from_timespec(1, 500_000_000), 1.5.seconds());
+        assert_eq!(Duration::from_timespec(-2, 500_000_000), (-1.5).seconds());
+        assert_eq!(Duration::from_timespec(0, 2_500_000_000), 2.5.seconds());
+        assert_eq!(Duration::from_timespec(1, -1_500_000_000), (-0.5).seconds());
+        assert_eq!(Duration::from_timespec(0, 0), 0.seconds());
+
+        assert_eq!(
+            Duration::from_timespec(i64::max_value(), 999_999_999),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_timespec(i64::max_value(), 1_000_000_000),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_timespec(i64::max_value(), i64::max_value()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_timespec(i64::max_value(), -1_000_000_000),
+            Duration::seconds(i64::max_value() - 1)
+        );
+        assert_eq!(
+            Duration::from_timespec(i64::min_value(), -999_999_999),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::from_timespec(i64::min_value(), -1_000_000_000),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::from_timespec(i64::min_value(), i64::min_value()),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::from_timespec(i64::min_value(), 500_000_000),
+            Duration::seconds(i64::min_value()) + 500.milliseconds()
+        );
     }
-}
 
-impl SubAssign<Duration> for StdDuration {
-    #[inline(always)]
-    fn sub_assign(&mut self, rhs: Duration) {
-        *self = (*self - rhs).try_into().expect(
-            "Cannot represent a resulting duration in std. Try `let x = x - rhs;`, which will \
-             change the type.",
+    #[test]
+    fn to_le_bytes() {
+        assert_eq!(0.seconds().to_le_bytes(), [0; 12]);
+        assert_eq!(
+            1.5.seconds().to_le_bytes(),
+            [1, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x65, 0xcd, 0x1d]
+        );
+        assert_eq!(
+            (-1.5).seconds().to_le_bytes(),
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x9b, 0x32, 0xe2]
+        );
+        assert_eq!(
+            Duration::max_value().to_le_bytes(),
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f, 0xff, 0xc9, 0x9a, 0x3b]
+        );
+        assert_eq!(
+            Duration::min_value().to_le_bytes(),
+            [0, 0, 0, 0, 0, 0, 0, 0x80, 0x01, 0x36, 0x65, 0xc4]
         );
     }
-}
 
-macro_rules! duration_mul_div_int {
-    ($($type:ty),+) => {
-        $(
-            impl Mul<$type> for Duration {
-                type Output = Self;
+    #[test]
+    fn from_le_bytes() {
+        for &duration in &[
+            0.seconds(),
+            1.5.seconds(),
+            (-1.5).seconds(),
+            (-1).nanoseconds(),
+            Duration::max_value(),
+            Duration::min_value(),
+        ] {
+            assert_eq!(
+                Duration::from_le_bytes(duration.to_le_bytes()),
+                Ok(duration)
+            );
+        }
 
-                #[inline(always)]
-                fn mul(self, rhs: $type) -> Self::Output {
-                    Self::nanoseconds_i128(
-                        self.whole_nanoseconds()
-                            .checked_mul(rhs as i128)
-                            .expect("overflow when multiplying duration")
-                    )
-                }
-            }
+        // nanoseconds of -1 with positive seconds
+        assert!(Duration::from_le_bytes([1, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]).is_err());
+        // nanoseconds of 1_000_000_000
+        assert!(Duration::from_le_bytes([0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0xca, 0x9a, 0x3b]).is_err());
+    }
 
-            impl MulAssign<$type> for Duration {
-                #[inline(always)]
-                fn mul_assign(&mut self, rhs: $type) {
-                    *self = *self * rhs;
-                }
-            }
+    #[test]
+    fn to_timespec() {
+        assert_eq!(1.5.seconds().to_timespec(), Some((1, 500_000_000)));
+        assert_eq!((-1.5).seconds().to_timespec(), Some((-2, 500_000_000)));
+        assert_eq!((-1).seconds().to_timespec(), Some((-1, 0)));
+        assert_eq!((-1).nanoseconds().to_timespec(), Some((-1, 999_999_999)));
+        assert_eq!(0.seconds().to_timespec(), Some((0, 0)));
+        assert_eq!(
+            Duration::seconds(i64::min_value()).to_timespec(),
+            Some((i64::min_value(), 0))
+        );
+        assert_eq!(
+            Duration::max_value().to_timespec(),
+            Some((i64::max_value(), 999_999_999))
+        );
+        assert_eq!(Duration::min_value().to_timespec(), None);
+        assert_eq!(
+            (Duration::seconds(i64::min_value()) - 1.nanoseconds()).to_timespec(),
+            None
+        );
+    }
 
-            impl Mul<Duration> for $type {
-                type Output = Duration;
+    #[test]
+    fn to_parts() {
+        let duration =
+            1.weeks() + 2.days() + 3.hours() + 4.minutes() + 5.seconds() + 6.nanoseconds();
+        assert_eq!(duration.to_parts(), (1, 2, 3, 4, 5, 6));
+        assert_eq!((-duration).to_parts(), (-1, -2, -3, -4, -5, -6));
+        assert_eq!(Duration::zero().to_parts(), (0, 0, 0, 0, 0, 0));
+        assert_eq!(6.days().to_parts(), (0, 6, 0, 0, 0, 0));
+        assert_eq!(
+            Duration::max_value().to_parts(),
+            (15_250_284_452_471, 3, 15, 30, 7, 999_999_999)
+        );
+    }
 
-                #[inline(always)]
-                fn mul(self, rhs: Duration) -> Self::Output {
-                    rhs * self
-                }
-            }
+    #[test]
+    fn to_wdhms() {
+        assert_eq!((10.days() + 5.hours()).to_wdhms(), (false, 1, 3, 5, 0, 0));
+        assert_eq!((-(10.days() + 5.hours())).to_wdhms(), (true, 1, 3, 5, 0, 0));
+        assert_eq!(Duration::zero().to_wdhms(), (false, 0, 0, 0, 0, 0));
+        assert_eq!((-1).days().to_wdhms(), (true, 0, 1, 0, 0, 0));
+        assert_eq!((-1).seconds().to_wdhms(), (true, 0, 0, 0, 0, 1));
+        assert_eq!((-0.5).seconds().to_wdhms(), (false, 0, 0, 0, 0, 0));
+        assert_eq!((-1.5).seconds().to_wdhms(), (true, 0, 0, 0, 0, 1));
+        assert_eq!(
+            (6.days() + 23.hours() + 59.minutes() + 59.seconds()).to_wdhms(),
+            (false, 0, 6, 23, 59, 59)
+        );
+        assert_eq!(
+            Duration::max_value().to_wdhms(),
+            (false, 15_250_284_452_471, 3, 15, 30, 7)
+        );
+        assert_eq!(
+            Duration::min_value().to_wdhms(),
+            (true, 15_250_284_452_471, 3, 15, 30, 8)
+        );
+    }
 
-            impl Div<$type> for Duration {
-                type Output = Self;
+    #[test]
+    fn from_parts() {
+        assert_eq!(
+            Duration::from_parts(1, 2, 3, 4, 5, 6),
+            1.weeks() + 2.days() + 3.hours() + 4.minutes() + 5.seconds() + 6.nanoseconds()
+        );
+        assert_eq!(Duration::from_parts(0, 0, 1, -30, 0, 0), 30.minutes());
+        assert_eq!(Duration::from_parts(0, 0, 0, 0, 90, 0), 1.5.minutes());
+        assert_eq!(
+            Duration::from_parts(0, 0, 0, 0, 0, -1_500_000_000),
+            (-1.5).seconds()
+        );
+        assert_eq!(
+            Duration::from_parts(i64::max_value(), 0, 0, 0, 0, 0),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_parts(i64::min_value(), i64::min_value(), 0, 0, 0, 0),
+            Duration::min_value()
+        );
 
-                #[inline(always)]
-                fn div(self, rhs: $type) -> Self::Output {
-                    Self::nanoseconds_i128(self.whole_nanoseconds() / rhs as i128)
-                }
-            }
+        let mut durations = vec![Duration::max_value(), Duration::min_value()];
+        // A simple linear congruential generator, providing deterministic
+        // pseudo-random values.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..1_000 {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            durations.push(Duration::nanoseconds_i128(
+                (state as i64) as i128 * 1_000_000_000 + (state >> 34) as i128 % 1_000_000_000,
+            ));
+        }
 
-            impl DivAssign<$type> for Duration {
-                #[inline(always)]
-                fn div_assign(&mut self, rhs: $type) {
-                    *self = *self / rhs;
-                }
-            }
-        )+
-    };
-}
-duration_mul_div_int![i8, i16, i32, u8, u16, u32];
+        for &duration in &durations {
+            let (weeks, days, hours, minutes, seconds, nanoseconds) = duration.to_parts();
+            assert_eq!(
+                Duration::from_parts(weeks, days, hours, minutes, seconds, nanoseconds),
+                duration
+            );
+        }
+    }
 
-impl Mul<f32> for Duration {
-    type Output = Self;
+    #[test]
+    fn checked_from_hms() {
+        assert_eq!(Duration::checked_from_hms(0, 0, 0), Some(0.seconds()));
+        assert_eq!(Duration::checked_from_hms(1, 30, 15), Some(5_415.seconds()));
+        assert_eq!(
+            Duration::checked_from_hms(-1, -30, -15),
+            Some((-5_415).seconds())
+        );
+        assert_eq!(Duration::checked_from_hms(0, 90, 0), Some(90.minutes()));
+        assert_eq!(Duration::checked_from_hms(1, -30, 0), Some(30.minutes()));
+        assert_eq!(
+            Duration::checked_from_hms(0, 0, i64::max_value()),
+            Some(Duration::seconds(i64::max_value()))
+        );
+        assert_eq!(
+            Duration::checked_from_hms(0, 0, i64::min_value()),
+            Some(Duration::seconds(i64::min_value()))
+        );
+        assert_eq!(
+            Duration::checked_from_hms(1, 0, -3_600 + i64::max_value()),
+            Some(Duration::seconds(i64::max_value()))
+        );
 
-    #[inline(always)]
-    fn mul(self, rhs: f32) -> Self::Output {
-        Self::seconds_f32(self.as_seconds_f32() * rhs)
+        assert_eq!(Duration::checked_from_hms(i64::max_value(), 0, 0), None);
+        assert_eq!(Duration::checked_from_hms(i64::min_value(), 0, 0), None);
+        assert_eq!(Duration::checked_from_hms(0, i64::max_value(), 0), None);
+        assert_eq!(Duration::checked_from_hms(0, 1, i64::max_value()), None);
+        assert_eq!(Duration::checked_from_hms(0, -1, i64::min_value()), None);
     }
-}
 
-impl MulAssign<f32> for Duration {
-    #[inline(always)]
-    fn mul_assign(&mut self, rhs: f32) {
-        *self = *self * rhs;
+    #[test]
+    fn to_samples() {
+        assert_eq!(1.seconds().to_samples(44_100), Some(44_100));
+        assert_eq!((-1).seconds().to_samples(44_100), Some(-44_100));
+        assert_eq!(0.seconds().to_samples(44_100), Some(0));
+        assert_eq!(1.5.seconds().to_samples(48_000), Some(72_000));
+        // 1 / 44_100 s is 22_675.7 ns.
+        assert_eq!(22_675.nanoseconds().to_samples(44_100), Some(1));
+        assert_eq!(11_337.nanoseconds().to_samples(44_100), Some(0));
+        assert_eq!(11_338.nanoseconds().to_samples(44_100), Some(1));
+        assert_eq!((-11_338).nanoseconds().to_samples(44_100), Some(-1));
+        // Exactly half of a sample at 2 Hz.
+        assert_eq!(250.milliseconds().to_samples(2), Some(1));
+        assert_eq!((-250).milliseconds().to_samples(2), Some(-1));
+        assert_eq!(1.seconds().to_samples(0), None);
+        assert_eq!(0.seconds().to_samples(0), None);
+        assert_eq!(Duration::max_value().to_samples(48_000), None);
+        assert_eq!(Duration::min_value().to_samples(48_000), None);
+        assert_eq!(
+            Duration::seconds(i64::max_value()).to_samples(1),
+            Some(i64::max_value())
+        );
     }
-}
 
-impl Mul<Duration> for f32 {
-    type Output = Duration;
+    #[test]
+    fn to_frames() {
+        assert_eq!(1.seconds().to_frames(25, 1), Some(25));
+        assert_eq!((-1).seconds().to_frames(25, 1), Some(-25));
+        assert_eq!(0.seconds().to_frames(24_000, 1_001), Some(0));
+        // 1 second at 23.976 fps is 23.976 frames.
+        assert_eq!(1.seconds().to_frames(24_000, 1_001), Some(24));
+        assert_eq!(1_001.seconds().to_frames(24_000, 1_001), Some(24_000));
+        assert_eq!(1.hours().to_frames(30_000, 1_001), Some(107_892));
+        // Exactly half of a frame at 1 fps.
+        assert_eq!(500.milliseconds().to_frames(1, 1), Some(1));
+        assert_eq!((-500).milliseconds().to_frames(1, 1), Some(-1));
+        assert_eq!(499.milliseconds().to_frames(1, 1), Some(0));
+        assert_eq!(
+            Duration::seconds(i64::max_value()).to_frames(1, 1),
+            Some(i64::max_value())
+        );
 
-    #[inline(always)]
-    fn mul(self, rhs: Duration) -> Self::Output {
-        rhs * self
+        assert_eq!(1.seconds().to_frames(0, 1), None);
+        assert_eq!(1.seconds().to_frames(24, 0), None);
+        assert_eq!(0.seconds().to_frames(0, 0), None);
+        assert_eq!(Duration::max_value().to_frames(60, 1), None);
+        assert_eq!(Duration::min_value().to_frames(60, 1), None);
     }
-}
 
-impl Mul<f64> for Duration {
-    type Output = Self;
+    #[test]
+    fn from_frames() {
+        assert_eq!(Duration::from_frames(25, 25, 1), Some(1.seconds()));
+        assert_eq!(Duration::from_frames(-25, 25, 1), Some((-1).seconds()));
+        assert_eq!(Duration::from_frames(0, 24_000, 1_001), Some(0.seconds()));
+        assert_eq!(
+            Duration::from_frames(24, 24_000, 1_001),
+            Some(1_001.milliseconds())
+        );
+        assert_eq!(
+            Duration::from_frames(1, 24_000, 1_001),
+            Some(41_708_333.nanoseconds())
+        );
+        assert_eq!(
+            Duration::from_frames(i64::min_value(), 1, 1),
+            Some(Duration::seconds(i64::min_value()))
+        );
 
-    #[inline(always)]
-    fn mul(self, rhs: f64) -> Self::Output {
-        Self::seconds_f64(self.as_seconds_f64() * rhs)
+        assert_eq!(Duration::from_frames(1, 0, 1), None);
+        assert_eq!(Duration::from_frames(1, 24, 0), None);
+        assert_eq!(Duration::from_frames(0, 0, 0), None);
+        assert_eq!(Duration::from_frames(i64::max_value(), 1, 2), None);
+        assert_eq!(Duration::from_frames(i64::min_value(), 1, 2), None);
+
+        // Round-tripping through frames is exact.
+        for &(fps_num, fps_den) in &[(24_000, 1_001), (30_000, 1_001), (25, 1), (60, 1)] {
+            for &frames in &[0, 1, -1, 23, 24, 1_000_000, -1_000_000] {
+                let duration = Duration::from_frames(frames, fps_num, fps_den);
+                assert_eq!(
+                    duration.and_then(|duration| duration.to_frames(fps_num, fps_den)),
+                    Some(frames)
+                );
+            }
+        }
+        let frames = 1.seconds().to_frames(24_000, 1_001);
+        assert_eq!(
+            frames
+                .and_then(|frames| Duration::from_frames(frames, 24_000, 1_001))
+                .and_then(|duration| duration.to_frames(24_000, 1_001)),
+            frames
+        );
     }
-}
 
-impl MulAssign<f64> for Duration {
-    #[inline(always)]
-    fn mul_assign(&mut self, rhs: f64) {
-        *self = *self * rhs;
-    }
-}
+    #[test]
+    fn step_by() {
+        fn collect(start: Duration, end: Duration, step: Duration) -> Vec<Duration> {
+            Duration::step_by(start, end, step).collect()
+        }
 
-impl Mul<Duration> for f64 {
-    type Output = Duration;
+        assert_eq!(
+            collect(0.seconds(), 1.seconds(), 250.milliseconds()),
+            vec![
+                0.seconds(),
+                250.milliseconds(),
+                500.milliseconds(),
+                750.milliseconds()
+            ]
+        );
+        assert_eq!(
+            collect(0.seconds(), 1_001.milliseconds(), 500.milliseconds()),
+            vec![0.seconds(), 500.milliseconds(), 1.seconds()]
+        );
+        assert_eq!(
+            collect((-1).seconds(), 1.seconds(), 1.seconds()),
+            vec![(-1).seconds(), 0.seconds()]
+        );
 
-    #[inline(always)]
-    fn mul(self, rhs: Duration) -> Self::Output {
-        rhs * self
-    }
-}
+        assert_eq!(
+            collect(1.seconds(), 0.seconds(), (-500).milliseconds()),
+            vec![1.seconds(), 500.milliseconds()]
+        );
+        assert_eq!(
+            collect(0.seconds(), (-3).seconds(), (-1).seconds()),
+            vec![0.seconds(), (-1).seconds(), (-2).seconds()]
+        );
 
-impl Div<f32> for Duration {
-    type Output = Self;
+        assert!(collect(0.seconds(), 1.seconds(), 0.seconds()).is_empty());
+        assert!(collect(1.seconds(), 0.seconds(), 0.seconds()).is_empty());
+        assert!(collect(1.seconds(), 1.seconds(), 1.seconds()).is_empty());
+        assert!(collect(1.seconds(), 0.seconds(), 1.seconds()).is_empty());
+        assert!(collect(0.seconds(), 1.seconds(), (-1).seconds()).is_empty());
+
+        assert_eq!(
+            collect(
+                Duration::max_value() - 1.seconds(),
+                Duration::max_value(),
+                700.milliseconds()
+            ),
+            vec![
+                Duration::max_value() - 1.seconds(),
+                Duration::max_value() - 300.milliseconds()
+            ]
+        );
+        assert_eq!(
+            collect(
+                Duration::min_value() + 1.nanoseconds(),
+                Duration::min_value(),
+                (-1).nanoseconds()
+            ),
+            vec![Duration::min_value() + 1.nanoseconds()]
+        );
 
-    #[inline(always)]
-    fn div(self, rhs: f32) -> Self::Output {
-        Self::seconds_f32(self.as_seconds_f32() / rhs)
+        let mut iter = Duration::step_by(0.seconds(), 1.seconds(), 1.seconds());
+        assert_eq!(iter.next(), Some(0.seconds()));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
     }
-}
 
-impl DivAssign<f32> for Duration {
-    #[inline(always)]
-    fn div_assign(&mut self, rhs: f32) {
-        *self = *self / rhs;
+    #[test]
+    fn max_of() {
+        assert_eq!(
+            Duration::max_of(vec![1.seconds(), (-5).seconds(), 3.seconds(), 0.seconds()]),
+            Some(3.seconds())
+        );
+        assert_eq!(
+            Duration::max_of(vec![(-1).seconds(), (-5).seconds()]),
+            Some((-1).seconds())
+        );
+        assert_eq!(
+            Duration::max_of(vec![Duration::min_value(), Duration::max_value()]),
+            Some(Duration::max_value())
+        );
+        assert_eq!(
+            Duration::max_of(vec![1.nanoseconds()]),
+            Some(1.nanoseconds())
+        );
+        assert_eq!(Duration::max_of(Vec::new()), None);
     }
-}
 
-impl Div<f64> for Duration {
-    type Output = Self;
+    #[test]
+    fn min_of() {
+        assert_eq!(
+            Duration::min_of(vec![1.seconds(), (-5).seconds(), 3.seconds(), 0.seconds()]),
+            Some((-5).seconds())
+        );
+        assert_eq!(
+            Duration::min_of(vec![5.seconds(), 1.seconds()]),
+            Some(1.seconds())
+        );
+        assert_eq!(
+            Duration::min_of(vec![Duration::max_value(), Duration::min_value()]),
+            Some(Duration::min_value())
+        );
+        assert_eq!(
+            Duration::min_of(vec![1.nanoseconds()]),
+            Some(1.nanoseconds())
+        );
+        assert_eq!(Duration::min_of(Vec::new()), None);
+    }
 
-    #[inline(always)]
-    fn div(self, rhs: f64) -> Self::Output {
-        Self::seconds_f64(self.as_seconds_f64() / rhs)
+    #[test]
+    fn midpoint() {
+        assert_eq!(Duration::midpoint(1.seconds(), 3.seconds()), 2.seconds());
+        assert_eq!(Duration::midpoint(3.seconds(), 1.seconds()), 2.seconds());
+        assert_eq!(
+            Duration::midpoint((-1).seconds(), 2.seconds()),
+            500.milliseconds()
+        );
+        assert_eq!(Duration::midpoint(0.seconds(), 0.seconds()), 0.seconds());
+        assert_eq!(
+            Duration::midpoint(0.seconds(), 1.nanoseconds()),
+            0.seconds()
+        );
+        assert_eq!(
+            Duration::midpoint(0.seconds(), (-1).nanoseconds()),
+            0.seconds()
+        );
+        assert_eq!(
+            Duration::midpoint(1.seconds(), 2.nanoseconds()),
+            500_000_001.nanoseconds()
+        );
+        assert_eq!(
+            Duration::midpoint(Duration::max_value(), Duration::min_value()),
+            (-500).milliseconds()
+        );
+        assert_eq!(
+            Duration::midpoint(Duration::max_value(), Duration::max_value()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::midpoint(Duration::min_value(), Duration::min_value()),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::midpoint(Duration::max_value(), Duration::zero()),
+            Duration::new(i64::max_value() / 2, 999_999_999)
+        );
     }
-}
 
-impl DivAssign<f64> for Duration {
-    #[inline(always)]
-    fn div_assign(&mut self, rhs: f64) {
-        *self = *self / rhs;
+    #[test]
+    fn split() {
+        assert_eq!(1.seconds().split(3), (333_333_333.nanoseconds(), 1));
+        assert_eq!((-1).seconds().split(3), ((-333_333_334).nanoseconds(), 2));
+        assert_eq!(1.seconds().split(4), (250.milliseconds(), 0));
+        assert_eq!(0.seconds().split(7), (0.seconds(), 0));
+        assert_eq!(5.nanoseconds().split(10), (0.seconds(), 5));
+        assert_eq!(Duration::max_value().split(1), (Duration::max_value(), 0));
+        assert_eq!(Duration::min_value().split(1), (Duration::min_value(), 0));
+
+        for &duration in &[
+            1.seconds(),
+            (-1).seconds(),
+            7.nanoseconds(),
+            (-7).nanoseconds(),
+            1.5.seconds(),
+            Duration::new(12_345, 678_901_234),
+            Duration::new(-12_345, -678_901_234),
+            Duration::max_value(),
+            Duration::min_value(),
+        ] {
+            for &n in &[1, 2, 3, 7, 1_000, 999_999_937, u32::max_value()] {
+                let (base, remainder) = duration.split(n);
+                assert!(remainder < n);
+                let base = base.whole_nanoseconds();
+                let total = base * (n - remainder) as i128 + (base + 1) * remainder as i128;
+                assert_eq!(total, duration.whole_nanoseconds());
+            }
+        }
     }
-}
 
-impl Div<Duration> for Duration {
-    type Output = f64;
+    #[test]
+    fn convert_with_remainder() {
+        assert_eq!(
+            150.seconds().convert_with_remainder(Unit::Minute),
+            (2, 30.seconds())
+        );
+        assert_eq!(
+            (-150).seconds().convert_with_remainder(Unit::Minute),
+            (-2, (-30).seconds())
+        );
+        assert_eq!(
+            150_000.milliseconds().convert_with_remainder(Unit::Minute),
+            (2, 30.seconds())
+        );
+        assert_eq!(
+            59.seconds().convert_with_remainder(Unit::Minute),
+            (0, 59.seconds())
+        );
+        assert_eq!(
+            (1.weeks() + 1.nanoseconds()).convert_with_remainder(Unit::Week),
+            (1, 1.nanoseconds())
+        );
+        assert_eq!(
+            1_234_567
+                .nanoseconds()
+                .convert_with_remainder(Unit::Microsecond),
+            (1_234, 567.nanoseconds())
+        );
+        assert_eq!(
+            1.5.seconds().convert_with_remainder(Unit::Nanosecond),
+            (1_500_000_000, 0.seconds())
+        );
+        assert_eq!(
+            Duration::max_value().convert_with_remainder(Unit::Nanosecond),
+            (
+                i64::max_value(),
+                Duration::nanoseconds_i128(
+                    Duration::max_value().whole_nanoseconds() - i64::max_value() as i128
+                )
+            )
+        );
+        assert_eq!(
+            Duration::min_value().convert_with_remainder(Unit::Second),
+            (i64::min_value(), (-999_999_999).nanoseconds())
+        );
+    }
 
-    #[inline(always)]
-    fn div(self, rhs: Self) -> Self::Output {
-        self.as_seconds_f64() / rhs.as_seconds_f64()
+    #[test]
+    fn div_rem_i64() {
+        assert_eq!(100.seconds().div_rem_i64(7), (14.seconds(), 2.seconds()));
+        assert_eq!(
+            (-100).seconds().div_rem_i64(7),
+            ((-14).seconds(), (-2).seconds())
+        );
+        assert_eq!(
+            100.seconds().div_rem_i64(-7),
+            ((-14).seconds(), 2.seconds())
+        );
+        assert_eq!(1.5.seconds().div_rem_i64(2), (0.seconds(), 1.5.seconds()));
+        assert_eq!(
+            90.5.seconds().div_rem_i64(3),
+            (30.seconds(), 500.milliseconds())
+        );
+        assert_eq!(0.seconds().div_rem_i64(5), (0.seconds(), 0.seconds()));
+        assert_eq!(
+            Duration::max_value().div_rem_i64(1),
+            (i64::max_value().seconds(), 999_999_999.nanoseconds())
+        );
+        assert_eq!(
+            Duration::min_value().div_rem_i64(-1),
+            (i64::max_value().seconds(), (-1_999_999_999).nanoseconds())
+        );
+
+        for &duration in &[
+            Duration::max_value(),
+            Duration::min_value(),
+            1.5.seconds(),
+            (-7).nanoseconds(),
+        ] {
+            for &rhs in &[1, -1, 2, 7, -7, i64::max_value(), i64::min_value()] {
+                let (quotient, remainder) = duration.div_rem_i64(rhs);
+                assert_eq!(
+                    quotient.whole_nanoseconds() * rhs as i128 + remainder.whole_nanoseconds(),
+                    duration.whole_nanoseconds()
+                );
+            }
+        }
     }
-}
 
-impl Div<StdDuration> for Duration {
-    type Output = f64;
+    #[test]
+    fn from_samples() {
+        assert_eq!(Duration::from_samples(44_100, 44_100), Some(1.seconds()));
+        assert_eq!(
+            Duration::from_samples(-44_100, 44_100),
+            Some((-1).seconds())
+        );
+        assert_eq!(Duration::from_samples(0, 44_100), Some(0.seconds()));
+        assert_eq!(
+            Duration::from_samples(1, 44_100),
+            Some(22_676.nanoseconds())
+        );
+        assert_eq!(
+            Duration::from_samples(-1, 44_100),
+            Some((-22_676).nanoseconds())
+        );
+        assert_eq!(
+            Duration::from_samples(1, 2_000_000_000),
+            Some(1.nanoseconds())
+        );
+        assert_eq!(
+            Duration::from_samples(i64::max_value(), 1),
+            Some(Duration::seconds(i64::max_value()))
+        );
+        assert_eq!(
+            Duration::from_samples(i64::min_value(), 1),
+            Some(Duration::seconds(i64::min_value()))
+        );
+        assert_eq!(Duration::from_samples(1, 0), None);
+        assert_eq!(Duration::from_samples(0, 0), None);
+    }
 
-    #[inline(always)]
-    fn div(self, rhs: StdDuration) -> Self::Output {
-        self.as_seconds_f64() / rhs.as_secs_f64()
+    #[test]
+    fn samples_round_trip() {
+        for &rate in &[8_000, 44_100, 48_000, 96_000, 192_000] {
+            for &samples in &[0, 1, -1, 7, 44_099, -44_101, 1 << 40] {
+                assert_eq!(
+                    Duration::from_samples(samples, rate)
+                        .and_then(|duration| duration.to_samples(rate)),
+                    Some(samples)
+                );
+            }
+        }
     }
-}
 
-impl Div<Duration> for StdDuration {
-    type Output = f64;
+    #[test]
+    fn to_std_rounded() {
+        assert_eq!(
+            0.seconds().to_std_rounded(RoundMode::Truncate),
+            Ok(0.std_seconds())
+        );
+        assert_eq!(
+            1.5.seconds().to_std_rounded(RoundMode::Truncate),
+            Ok(1.5.std_seconds())
+        );
+        assert_eq!(
+            Duration::max_value().to_std_rounded(RoundMode::Truncate),
+            Ok(StdDuration::new(i64::max_value() as u64, 999_999_999))
+        );
+        assert_eq!(
+            (-1).nanoseconds().to_std_rounded(RoundMode::Truncate),
+            Err(Error::NegativeDuration(NegativeDurationError::new()))
+        );
+        assert_eq!(
+            Duration::min_value().to_std_rounded(RoundMode::Truncate),
+            Err(Error::NegativeDuration(NegativeDurationError::new()))
+        );
+        // The `TryFrom` implementation does not distinguish the cause.
+        assert_eq!(
+            StdDuration::try_from((-1).nanoseconds()),
+            Err(ConversionRangeError::new())
+        );
+    }
 
-    #[inline(always)]
-    fn div(self, rhs: Duration) -> Self::Output {
-        self.as_secs_f64() / rhs.as_seconds_f64()
+    #[test]
+    fn to_std_clamped() {
+        let max = 1.std_hours();
+        assert_eq!((-1).seconds().to_std_clamped(max), 0.std_seconds());
+        assert_eq!((-1).nanoseconds().to_std_clamped(max), 0.std_seconds());
+        assert_eq!(Duration::min_value().to_std_clamped(max), 0.std_seconds());
+        assert_eq!(0.seconds().to_std_clamped(max), 0.std_seconds());
+        assert_eq!(1.5.seconds().to_std_clamped(max), 1.5.std_seconds());
+        assert_eq!(1.hours().to_std_clamped(max), max);
+        assert_eq!((1.hours() + 1.nanoseconds()).to_std_clamped(max), max);
+        assert_eq!(Duration::max_value().to_std_clamped(max), max);
+        assert_eq!(1.seconds().to_std_clamped(0.std_seconds()), 0.std_seconds());
+        assert_eq!(
+            Duration::max_value().to_std_clamped(StdDuration::new(u64::max_value(), 999_999_999)),
+            StdDuration::new(i64::max_value() as u64, 999_999_999)
+        );
     }
-}
 
-impl PartialEq<StdDuration> for Duration {
-    #[inline(always)]
-    fn eq(&self, rhs: &StdDuration) -> bool {
-        Ok(*self) == Self::try_from(*rhs)
+    #[test]
+    fn into_std_or_zero() {
+        assert_eq!(0.seconds().into_std_or_zero(), 0.std_seconds());
+        assert_eq!(1.5.seconds().into_std_or_zero(), 1.5.std_seconds());
+        assert_eq!(1.nanoseconds().into_std_or_zero(), 1.std_nanoseconds());
+        assert_eq!((-1).seconds().into_std_or_zero(), 0.std_seconds());
+        assert_eq!((-1).nanoseconds().into_std_or_zero(), 0.std_seconds());
+        assert_eq!(Duration::min_value().into_std_or_zero(), 0.std_seconds());
+        assert_eq!(
+            Duration::max_value().into_std_or_zero(),
+            StdDuration::new(i64::max_value() as u64, 999_999_999)
+        );
     }
-}
 
-impl PartialEq<Duration> for StdDuration {
-    #[inline(always)]
-    fn eq(&self, rhs: &Duration) -> bool {
-        rhs == self
+    #[test]
+    fn min_nonzero() {
+        let floor = 10.milliseconds();
+        assert_eq!(0.seconds().min_nonzero(floor), floor);
+        assert_eq!((-1).nanoseconds().min_nonzero(floor), floor);
+        assert_eq!((-1).seconds().min_nonzero(floor), floor);
+        assert_eq!(Duration::min_value().min_nonzero(floor), floor);
+        assert_eq!(1.seconds().min_nonzero(floor), 1.seconds());
+        assert_eq!(10.milliseconds().min_nonzero(floor), floor);
+        assert_eq!(1.nanoseconds().min_nonzero(floor), 1.nanoseconds());
+        assert_eq!(
+            Duration::max_value().min_nonzero(floor),
+            Duration::max_value()
+        );
     }
-}
 
-impl PartialOrd for Duration {
-    #[inline(always)]
-    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
-        Some(self.cmp(rhs))
+    #[test]
+    fn clamp_magnitude() {
+        let max = 2.seconds();
+        assert_eq!(5.seconds().clamp_magnitude(max), 2.seconds());
+        assert_eq!((-5).seconds().clamp_magnitude(max), (-2).seconds());
+        assert_eq!(1.seconds().clamp_magnitude(max), 1.seconds());
+        assert_eq!((-1).seconds().clamp_magnitude(max), (-1).seconds());
+        assert_eq!(2.seconds().clamp_magnitude(max), 2.seconds());
+        assert_eq!((-2).seconds().clamp_magnitude(max), (-2).seconds());
+        assert_eq!(0.seconds().clamp_magnitude(max), 0.seconds());
+        assert_eq!(5.seconds().clamp_magnitude(-max), 2.seconds());
+        assert_eq!((-5).seconds().clamp_magnitude(-max), (-2).seconds());
+        assert_eq!(1.seconds().clamp_magnitude(0.seconds()), 0.seconds());
+        assert_eq!(Duration::max_value().clamp_magnitude(max), 2.seconds());
+        assert_eq!(Duration::min_value().clamp_magnitude(max), (-2).seconds());
+        assert_eq!(
+            Duration::min_value().clamp_magnitude(Duration::min_value()),
+            -Duration::max_value()
+        );
     }
-}
 
-impl PartialOrd<StdDuration> for Duration {
-    #[inline(always)]
-    fn partial_cmp(&self, rhs: &StdDuration) -> Option<Ordering> {
-        if rhs.as_secs() > i64::max_value() as u64 {
-            return Some(Greater);
-        }
+    #[test]
+    fn try_from_std() {
+        assert_eq!(Duration::try_from_std(0.std_seconds()), Ok(0.seconds()));
+        assert_eq!(Duration::try_from_std(1.5.std_seconds()), Ok(1.5.seconds()));
+        assert_eq!(
+            Duration::try_from_std(StdDuration::new(i64::max_value() as u64, 999_999_999)),
+            Ok(Duration::max_value())
+        );
+        assert!(
+            Duration::try_from_std(StdDuration::from_secs(i64::max_value() as u64 + 1)).is_err()
+        );
+        assert!(Duration::try_from_std(StdDuration::from_secs(u64::max_value())).is_err());
+    }
 
-        match self.seconds.partial_cmp(&(rhs.as_secs() as i64)) {
-            Some(Less) => Some(Less),
-            Some(Equal) => self.nanoseconds.partial_cmp(&(rhs.subsec_nanos() as i32)),
-            Some(Greater) => Some(Greater),
-            None => None,
-        }
+    #[test]
+    fn from_std_saturating() {
+        assert_eq!(Duration::from_std_saturating(0.std_seconds()), 0.seconds());
+        assert_eq!(
+            Duration::from_std_saturating(1.5.std_seconds()),
+            1.5.seconds()
+        );
+        assert_eq!(
+            Duration::from_std_saturating(StdDuration::new(i64::max_value() as u64, 999_999_999)),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_std_saturating(StdDuration::from_secs(i64::max_value() as u64 + 1)),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::from_std_saturating(StdDuration::new(u64::max_value(), 999_999_999)),
+            Duration::max_value()
+        );
     }
-}
 
-impl PartialOrd<Duration> for StdDuration {
-    #[inline(always)]
-    fn partial_cmp(&self, rhs: &Duration) -> Option<Ordering> {
-        match rhs.partial_cmp(self) {
-            Some(Less) => Some(Greater),
-            Some(Equal) => Some(Equal),
-            Some(Greater) => Some(Less),
-            None => None,
-        }
+    #[test]
+    #[allow(deprecated)]
+    fn checked_add() {
+        assert_eq!(5.seconds().checked_add(5.seconds()), Some(10.seconds()));
+        assert_eq!(Duration::max_value().checked_add(1.nanoseconds()), None);
+        assert_eq!((-5).seconds().checked_add(5.seconds()), Some(0.seconds()));
     }
-}
 
-impl Ord for Duration {
-    #[inline]
-    fn cmp(&self, rhs: &Self) -> Ordering {
-        match self.seconds.cmp(&rhs.seconds) {
-            Less => Less,
-            Equal => self.nanoseconds.cmp(&rhs.nanoseconds),
-            Greater => Greater,
-        }
+    #[test]
+    #[allow(deprecated)]
+    fn checked_sub() {
+        assert_eq!(5.seconds().checked_sub(5.seconds()), Some(0.seconds()));
+        assert_eq!(Duration::min_value().checked_sub(1.nanoseconds()), None);
+        assert_eq!(5.seconds().checked_sub(10.seconds()), Some((-5).seconds()));
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    #[test]
+    fn checked_add_std() {
+        assert_eq!(
+            5.seconds().checked_add_std(5.std_seconds()),
+            Some(10.seconds())
+        );
+        assert_eq!(
+            (-5).seconds().checked_add_std(1.5.std_seconds()),
+            Some((-3.5).seconds())
+        );
+        assert_eq!(
+            (Duration::max_value() - 1.nanoseconds()).checked_add_std(1.std_nanoseconds()),
+            Some(Duration::max_value())
+        );
+        assert_eq!(
+            Duration::max_value().checked_add_std(1.std_nanoseconds()),
+            None
+        );
+        assert_eq!(
+            Duration::max_value().checked_add_std(0.std_seconds()),
+            Some(Duration::max_value())
+        );
+        assert_eq!(
+            Duration::min_value().checked_add_std(StdDuration::new(u64::max_value(), 999_999_999)),
+            Some(Duration::seconds(i64::max_value()))
+        );
+        assert_eq!(
+            0.seconds()
+                .checked_add_std(StdDuration::from_secs(u64::max_value())),
+            None
+        );
+    }
 
     #[test]
-    fn unit_values() {
-        assert_eq!(Duration::zero(), 0.seconds());
-        assert_eq!(Duration::nanosecond(), 1.nanoseconds());
-        assert_eq!(Duration::microsecond(), 1.microseconds());
-        assert_eq!(Duration::millisecond(), 1.milliseconds());
-        assert_eq!(Duration::second(), 1.seconds());
-        assert_eq!(Duration::minute(), 60.seconds());
-        assert_eq!(Duration::hour(), 3_600.seconds());
-        assert_eq!(Duration::day(), 86_400.seconds());
-        assert_eq!(Duration::week(), 604_800.seconds());
+    fn checked_sub_std() {
+        assert_eq!(
+            5.seconds().checked_sub_std(5.std_seconds()),
+            Some(0.seconds())
+        );
+        assert_eq!(
+            5.seconds().checked_sub_std(10.std_seconds()),
+            Some((-5).seconds())
+        );
+        assert_eq!(
+            (Duration::min_value() + 1.nanoseconds()).checked_sub_std(1.std_nanoseconds()),
+            Some(Duration::min_value())
+        );
+        assert_eq!(
+            Duration::min_value().checked_sub_std(1.std_nanoseconds()),
+            None
+        );
+        assert_eq!(
+            Duration::max_value().checked_sub_std(StdDuration::new(u64::max_value(), 999_999_999)),
+            Some(Duration::seconds(i64::min_value()))
+        );
+        assert_eq!(
+            0.seconds()
+                .checked_sub_std(StdDuration::from_secs(u64::max_value())),
+            None
+        );
     }
 
     #[test]
-    fn is_zero() {
-        assert!(!(-1).nanoseconds().is_zero());
-        assert!(0.seconds().is_zero());
-        assert!(!1.nanoseconds().is_zero());
+    fn saturating_add_std() {
+        assert_eq!(
+            5.seconds().saturating_add_std(5.std_seconds()),
+            5.seconds() + 5.std_seconds()
+        );
+        assert_eq!(
+            (-5).seconds().saturating_add_std(1.5.std_seconds()),
+            (-5).seconds() + 1.5.std_seconds()
+        );
+        assert_eq!(
+            (Duration::max_value() - 1.nanoseconds()).saturating_add_std(1.std_nanoseconds()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::max_value().saturating_add_std(1.std_nanoseconds()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().saturating_add_std(StdDuration::from_secs(u64::max_value())),
+            Duration::seconds(i64::max_value()) - 999_999_999.nanoseconds()
+        );
+        assert_eq!(
+            0.seconds()
+                .saturating_add_std(StdDuration::new(u64::max_value(), 999_999_999)),
+            Duration::max_value()
+        );
     }
 
     #[test]
-    fn is_negative() {
-        assert!((-1).seconds().is_negative());
-        assert!(!0.seconds().is_negative());
-        assert!(!1.seconds().is_negative());
+    fn saturating_sub_std() {
+        assert_eq!(
+            5.seconds().saturating_sub_std(10.std_seconds()),
+            5.seconds() - 10.std_seconds()
+        );
+        assert_eq!(
+            (Duration::min_value() + 1.nanoseconds()).saturating_sub_std(1.std_nanoseconds()),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::min_value().saturating_sub_std(1.std_nanoseconds()),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::max_value()
+                .saturating_sub_std(StdDuration::new(u64::max_value(), 999_999_999)),
+            Duration::seconds(i64::min_value())
+        );
+        assert_eq!(
+            0.seconds()
+                .saturating_sub_std(StdDuration::from_secs(u64::max_value())),
+            Duration::min_value()
+        );
     }
 
     #[test]
-    fn is_positive() {
-        assert!(!(-1).seconds().is_positive());
-        assert!(!0.seconds().is_positive());
-        assert!(1.seconds().is_positive());
+    fn wrapping_add() {
+        assert_eq!(5.seconds().wrapping_add(5.seconds()), 10.seconds());
+        assert_eq!(5.seconds().wrapping_add((-10).seconds()), (-5).seconds());
+        assert_eq!(
+            Duration::max_value().wrapping_add(1.nanoseconds()),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::max_value().wrapping_add(2.nanoseconds()),
+            Duration::min_value() + 1.nanoseconds()
+        );
+        assert_eq!(
+            Duration::min_value().wrapping_add((-1).nanoseconds()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::max_value().wrapping_add(Duration::max_value()),
+            (-1_000_000_001).nanoseconds()
+        );
+        assert_eq!(
+            Duration::min_value().wrapping_add(Duration::min_value()),
+            (-999_999_999).nanoseconds()
+        );
     }
 
-    #[allow(deprecated)]
     #[test]
-    fn sign() {
-        use crate::Sign::*;
-        assert_eq!(1.seconds().sign(), Positive);
-        assert_eq!((-1).seconds().sign(), Negative);
-        assert_eq!(0.seconds().sign(), Zero);
+    fn wrapping_sub() {
+        assert_eq!(5.seconds().wrapping_sub(10.seconds()), (-5).seconds());
+        assert_eq!(
+            Duration::min_value().wrapping_sub(1.nanoseconds()),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::max_value().wrapping_sub((-1).nanoseconds()),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::max_value().wrapping_sub(Duration::min_value()),
+            (-1).nanoseconds()
+        );
     }
 
     #[test]
-    fn abs() {
-        assert_eq!(1.seconds().abs(), 1.seconds());
-        assert_eq!(0.seconds().abs(), 0.seconds());
-        assert_eq!((-1).seconds().abs(), 1.seconds());
+    fn checked_sum() {
+        assert_eq!(
+            Duration::checked_sum(vec![1.seconds(), 2.seconds(), (-500).milliseconds()]),
+            Some(2_500.milliseconds())
+        );
+        assert_eq!(Duration::checked_sum(vec![]), Some(Duration::zero()));
+        assert_eq!(
+            Duration::checked_sum(vec![Duration::max_value(), 1.nanoseconds()]),
+            None
+        );
+        assert_eq!(
+            Duration::checked_sum(vec![Duration::min_value(), (-1).nanoseconds()]),
+            None
+        );
+        // The running total overflows, even though the final total would not.
+        assert_eq!(
+            Duration::checked_sum(vec![Duration::max_value(), 1.seconds(), (-1).seconds()]),
+            None
+        );
+        assert_eq!(
+            Duration::checked_sum((1..=4).map(Duration::seconds)),
+            Some(10.seconds())
+        );
     }
 
     #[test]
-    fn new() {
-        assert_eq!(Duration::new(1, 0), 1.seconds());
-        assert_eq!(Duration::new(-1, 0), (-1).seconds());
-        assert_eq!(Duration::new(1, 2_000_000_000), 3.seconds());
+    #[allow(deprecated)]
+    fn checked_mul() {
+        assert_eq!(5.seconds().checked_mul(2), Some(10.seconds()));
+        assert_eq!(5.seconds().checked_mul(-2), Some((-10).seconds()));
+        assert_eq!(5.seconds().checked_mul(0), Some(Duration::zero()));
+        assert_eq!(Duration::max_value().checked_mul(2), None);
+        assert_eq!(Duration::min_value().checked_mul(2), None);
+    }
 
-        assert!(Duration::new(0, 0).is_zero());
-        assert!(Duration::new(0, 1_000_000_000).is_positive());
-        assert!(Duration::new(-1, 1_000_000_000).is_zero());
-        assert!(Duration::new(-2, 1_000_000_000).is_negative());
+    #[test]
+    fn saturating_shl() {
+        assert_eq!(0.seconds().saturating_shl(0), 0.seconds());
+        assert_eq!(0.seconds().saturating_shl(u32::max_value()), 0.seconds());
+        assert_eq!(1.seconds().saturating_shl(0), 1.seconds());
+        assert_eq!(1.seconds().saturating_shl(1), 2.seconds());
+        assert_eq!(
+            100.milliseconds().saturating_shl(10),
+            102_400.milliseconds()
+        );
+        assert_eq!((-1).seconds().saturating_shl(2), (-4).seconds());
+        assert_eq!(
+            1.nanoseconds().saturating_shl(62),
+            Duration::nanoseconds(1 << 62)
+        );
+        assert_eq!(
+            1.nanoseconds().saturating_shl(92),
+            Duration::nanoseconds_i128(1 << 92)
+        );
+        assert_eq!(1.nanoseconds().saturating_shl(93), Duration::max_value());
+        assert_eq!((-1).nanoseconds().saturating_shl(93), Duration::min_value());
+
+        for &attempt in &[100, 126, 127, 128, 1_000, u32::max_value()] {
+            assert_eq!(
+                1.nanoseconds().saturating_shl(attempt),
+                Duration::max_value()
+            );
+            assert_eq!(1.seconds().saturating_shl(attempt), Duration::max_value());
+            assert_eq!(
+                (-1).nanoseconds().saturating_shl(attempt),
+                Duration::min_value()
+            );
+        }
+        assert_eq!(
+            Duration::max_value().saturating_shl(1),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().saturating_shl(1),
+            Duration::min_value()
+        );
     }
 
     #[test]
-    fn weeks() {
-        assert_eq!(Duration::weeks(1), 604_800.seconds());
-        assert_eq!(Duration::weeks(2), (2 * 604_800).seconds());
-        assert_eq!(Duration::weeks(-1), (-604_800).seconds());
-        assert_eq!(Duration::weeks(-2), (2 * -604_800).seconds());
+    fn checked_div() {
+        assert_eq!(10.seconds().checked_div(2), Some(5.seconds()));
+        assert_eq!(10.seconds().checked_div(-2), Some((-5).seconds()));
+        assert_eq!(1.seconds().checked_div(0), None);
     }
 
     #[test]
-    fn whole_weeks() {
-        assert_eq!(Duration::weeks(1).whole_weeks(), 1);
-        assert_eq!(Duration::weeks(-1).whole_weeks(), -1);
-        assert_eq!(Duration::days(6).whole_weeks(), 0);
-        assert_eq!(Duration::days(-6).whole_weeks(), 0);
+    fn checked_div_duration() {
+        assert_eq!(
+            1.seconds().checked_div_duration(100.milliseconds()),
+            Some(10)
+        );
+        assert_eq!(1.seconds().checked_div_duration(1.seconds()), Some(1));
+        assert_eq!(1.seconds().checked_div_duration((-1).seconds()), Some(-1));
+        assert_eq!(
+            1.seconds().checked_div_duration(300.milliseconds()),
+            Some(3)
+        );
+        assert_eq!(
+            (-1).seconds().checked_div_duration(300.milliseconds()),
+            Some(-3)
+        );
+        assert_eq!(1.seconds().checked_div_duration(2.seconds()), Some(0));
+        assert_eq!(0.seconds().checked_div_duration(1.seconds()), Some(0));
+        assert_eq!(
+            Duration::max_value().checked_div_duration(1.nanoseconds()),
+            Some(Duration::max_value().whole_nanoseconds())
+        );
+        assert_eq!(
+            Duration::min_value().checked_div_duration((-1).nanoseconds()),
+            Some(-Duration::min_value().whole_nanoseconds())
+        );
+        assert_eq!(1.seconds().checked_div_duration(0.seconds()), None);
+        assert_eq!(0.seconds().checked_div_duration(0.seconds()), None);
     }
 
     #[test]
-    fn days() {
-        assert_eq!(Duration::days(1), 86_400.seconds());
-        assert_eq!(Duration::days(2), (2 * 86_400).seconds());
-        assert_eq!(Duration::days(-1), (-86_400).seconds());
-        assert_eq!(Duration::days(-2), (2 * -86_400).seconds());
+    #[allow(clippy::float_cmp)]
+    fn percent_of() {
+        assert_eq!(15.seconds().percent_of(1.minutes()), 25.);
+        assert_eq!(1.minutes().percent_of(1.minutes()), 100.);
+        assert_eq!(0.seconds().percent_of(1.minutes()), 0.);
+        assert_eq!(90.seconds().percent_of(1.minutes()), 150.);
+        assert_eq!((-15).seconds().percent_of(1.minutes()), -25.);
+        assert_eq!(15.seconds().percent_of((-1).minutes()), -25.);
+        assert_eq!(1.seconds().percent_of(0.seconds()), 0.);
+        assert_eq!(0.seconds().percent_of(0.seconds()), 0.);
+        assert_eq!(Duration::max_value().percent_of(0.seconds()), 0.);
     }
 
     #[test]
-    fn whole_days() {
-        assert_eq!(Duration::days(1).whole_days(), 1);
-        assert_eq!(Duration::days(-1).whole_days(), -1);
-        assert_eq!(Duration::hours(23).whole_days(), 0);
-        assert_eq!(Duration::hours(-23).whole_days(), 0);
+    fn quantize() {
+        use Rounding::*;
+
+        let grid = 10.milliseconds();
+        assert_eq!(15.milliseconds().quantize(grid, Nearest), 20.milliseconds());
+        assert_eq!(
+            15.milliseconds().quantize(grid, NearestEven),
+            20.milliseconds()
+        );
+        assert_eq!(25.milliseconds().quantize(grid, Nearest), 30.milliseconds());
+        assert_eq!(
+            25.milliseconds().quantize(grid, NearestEven),
+            20.milliseconds()
+        );
+        assert_eq!(
+            (-25).milliseconds().quantize(grid, Nearest),
+            (-30).milliseconds()
+        );
+        assert_eq!(
+            (-25).milliseconds().quantize(grid, NearestEven),
+            (-20).milliseconds()
+        );
+        assert_eq!(
+            26.milliseconds().quantize(grid, NearestEven),
+            30.milliseconds()
+        );
+        assert_eq!(24.milliseconds().quantize(grid, Nearest), 20.milliseconds());
+
+        assert_eq!(21.milliseconds().quantize(grid, Down), 20.milliseconds());
+        assert_eq!(21.milliseconds().quantize(grid, Up), 30.milliseconds());
+        assert_eq!(
+            21.milliseconds().quantize(grid, TowardZero),
+            20.milliseconds()
+        );
+        assert_eq!(
+            (-21).milliseconds().quantize(grid, Down),
+            (-30).milliseconds()
+        );
+        assert_eq!(
+            (-21).milliseconds().quantize(grid, Up),
+            (-20).milliseconds()
+        );
+        assert_eq!(
+            (-21).milliseconds().quantize(grid, TowardZero),
+            (-20).milliseconds()
+        );
+
+        for &rounding in &[Nearest, NearestEven, Down, Up, TowardZero] {
+            assert_eq!(
+                30.milliseconds().quantize(grid, rounding),
+                30.milliseconds()
+            );
+            assert_eq!(
+                30.milliseconds().quantize(-grid, rounding),
+                30.milliseconds()
+            );
+            assert_eq!(0.seconds().quantize(grid, rounding), 0.seconds());
+        }
+
+        assert_eq!(
+            Duration::max_value().quantize(1.seconds(), Up),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().quantize(1.seconds(), Down),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::max_value().quantize(1.seconds(), Down),
+            Duration::seconds(i64::max_value())
+        );
     }
 
     #[test]
-    fn hours() {
-        assert_eq!(Duration::hours(1), 3_600.seconds());
-        assert_eq!(Duration::hours(2), (2 * 3_600).seconds());
-        assert_eq!(Duration::hours(-1), (-3_600).seconds());
-        assert_eq!(Duration::hours(-2), (2 * -3_600).seconds());
+    fn parse_ascii() {
+        assert_eq!(Duration::parse_ascii("1h"), Ok(1.hours()));
+        assert_eq!(Duration::parse_ascii("+1h"), Ok(1.hours()));
+        assert_eq!(Duration::parse_ascii("-1h"), Ok((-1).hours()));
+        assert_eq!(Duration::parse_ascii("0s"), Ok(0.seconds()));
+        assert_eq!(Duration::parse_ascii("-0s"), Ok(0.seconds()));
+        assert_eq!(Duration::parse_ascii("2w"), Ok(14.days()));
+        assert_eq!(Duration::parse_ascii("1d2h3m4s"), Ok(93_784.seconds()));
+        assert_eq!(Duration::parse_ascii("4s3m"), Ok(184.seconds()));
+        assert_eq!(Duration::parse_ascii("1s1s"), Ok(2.seconds()));
+        assert_eq!(Duration::parse_ascii("5ms"), Ok(5.milliseconds()));
+        assert_eq!(Duration::parse_ascii("5us"), Ok(5.microseconds()));
+        assert_eq!(Duration::parse_ascii("5µs"), Ok(5.microseconds()));
+        assert_eq!(Duration::parse_ascii("5ns"), Ok(5.nanoseconds()));
+        assert_eq!(
+            Duration::parse_ascii("-1s500ms"),
+            Ok((-1_500).milliseconds())
+        );
+        assert_eq!(
+            Duration::parse_ascii("9223372036854775807s999999999ns"),
+            Ok(Duration::max_value())
+        );
+        assert_eq!(
+            Duration::parse_ascii("-9223372036854775808s999999999ns"),
+            Ok(Duration::min_value())
+        );
+
+        let invalid = [
+            "",
+            "+",
+            "-",
+            "+-1s",
+            "--1s",
+            "1",
+            "s",
+            "1x",
+            "1s2",
+            "1.5s",
+            "- 1s",
+            " + ",
+            "1 s",
+            "1 h",
+            "1h 3 0m",
+            "9223372036854775808s",
+            "1000000000000000000000000000000000000000ns",
+        ];
+        for &s in &invalid {
+            assert_eq!(Duration::parse_ascii(s), Err(ParseError::InvalidDuration));
+        }
     }
 
     #[test]
-    fn whole_hours() {
-        assert_eq!(Duration::hours(1).whole_hours(), 1);
-        assert_eq!(Duration::hours(-1).whole_hours(), -1);
-        assert_eq!(Duration::minutes(59).whole_hours(), 0);
-        assert_eq!(Duration::minutes(-59).whole_hours(), 0);
+    fn parse_seconds_str() {
+        assert_eq!(Duration::parse_seconds_str("3600"), Ok(1.hours()));
+        assert_eq!(Duration::parse_seconds_str("+3600"), Ok(1.hours()));
+        assert_eq!(Duration::parse_seconds_str("-1.5"), Ok((-1.5).seconds()));
+        assert_eq!(Duration::parse_seconds_str("0"), Ok(0.seconds()));
+        assert_eq!(Duration::parse_seconds_str("-0"), Ok(0.seconds()));
+        assert_eq!(Duration::parse_seconds_str("007"), Ok(7.seconds()));
+        assert_eq!(
+            Duration::parse_seconds_str("0.000000001"),
+            Ok(1.nanoseconds())
+        );
+        assert_eq!(
+            Duration::parse_seconds_str("-0.25"),
+            Ok((-250).milliseconds())
+        );
+        assert_eq!(
+            Duration::parse_seconds_str("9223372036854775807.999999999"),
+            Ok(Duration::max_value())
+        );
+        assert_eq!(
+            Duration::parse_seconds_str("-9223372036854775808.999999999"),
+            Ok(Duration::min_value())
+        );
+
+        for &input in &[
+            "",
+            "abc",
+            "+",
+            "-",
+            "5s",
+            "1.",
+            ".5",
+            "1.5.5",
+            "1,5",
+            " 1",
+            "1 ",
+            "--1",
+            "1e3",
+            "0.0000000001",
+            "9223372036854775808",
+            "-9223372036854775809",
+            "99999999999999999999999999999999999999999",
+        ] {
+            assert_eq!(
+                Duration::parse_seconds_str(input),
+                Err(ParseError::InvalidDuration),
+                "{}",
+                input
+            );
+        }
     }
 
     #[test]
-    fn minutes() {
-        assert_eq!(Duration::minutes(1), 60.seconds());
-        assert_eq!(Duration::minutes(2), (2 * 60).seconds());
-        assert_eq!(Duration::minutes(-1), (-60).seconds());
-        assert_eq!(Duration::minutes(-2), (2 * -60).seconds());
+    fn parse_clock() {
+        assert_eq!(Duration::parse_clock("01:02:03"), Ok(3_723.seconds()));
+        assert_eq!(Duration::parse_clock("1:30"), Ok(90.seconds()));
+        assert_eq!(Duration::parse_clock("-0:30"), Ok((-30).seconds()));
+        assert_eq!(Duration::parse_clock("+0:30"), Ok(30.seconds()));
+        assert_eq!(Duration::parse_clock("100:00:00"), Ok(100.hours()));
+        assert_eq!(Duration::parse_clock("90:00"), Ok(90.minutes()));
+        assert_eq!(Duration::parse_clock("0:00:01.5"), Ok(1.5.seconds()));
+        assert_eq!(
+            Duration::parse_clock("-1:00.000000001"),
+            Ok(-(1.minutes() + 1.nanoseconds()))
+        );
+        assert_eq!(
+            Duration::parse_clock("2562047788015215:30:07.999999999"),
+            Ok(Duration::max_value())
+        );
+        assert_eq!(
+            Duration::parse_clock("-2562047788015215:30:08.999999999"),
+            Ok(Duration::min_value())
+        );
+
+        for &input in &[
+            "",
+            "30",
+            "1:2:3:4",
+            "1:60",
+            "1:60:00",
+            "1::30",
+            ":30",
+            "1:",
+            "1.5:30",
+            "1:30.",
+            "1:30.0000000001",
+            " 1:30",
+            "1:30 ",
+            "--1:30",
+            "1h30m",
+            "2562047788015215:30:08",
+            "153722867280912931:00",
+            "99999999999999999999999999999999999999:00",
+        ] {
+            assert_eq!(
+                Duration::parse_clock(input),
+                Err(ParseError::InvalidDuration),
+                "{}",
+                input
+            );
+        }
     }
 
     #[test]
-    fn whole_minutes() {
-        assert_eq!(1.minutes().whole_minutes(), 1);
-        assert_eq!((-1).minutes().whole_minutes(), -1);
-        assert_eq!(59.seconds().whole_minutes(), 0);
-        assert_eq!((-59).seconds().whole_minutes(), 0);
+    fn format() {
+        use DurationFormatItem::*;
+
+        let timecode = [
+            Sign,
+            Hours,
+            Literal(":"),
+            Minutes,
+            Literal(":"),
+            Seconds,
+            Literal("."),
+            Subsecond(3),
+        ];
+        assert_eq!(0.seconds().format(&timecode), "00:00:00.000");
+        assert_eq!(
+            (1.hours() + 2.minutes() + 3.seconds() + 456_789.microseconds()).format(&timecode),
+            "01:02:03.456"
+        );
+        assert_eq!((-1.5).hours().format(&timecode), "-01:30:00.000");
+        assert_eq!(100.hours().format(&timecode), "100:00:00.000");
+        assert_eq!(
+            Duration::max_value().format(&timecode),
+            "2562047788015215:30:07.999"
+        );
+        assert_eq!(
+            Duration::min_value().format(&timecode),
+            "-2562047788015215:30:08.999"
+        );
+
+        assert_eq!(1.5.seconds().format(&[Subsecond(0)]), "");
+        assert_eq!(1.nanoseconds().format(&[Subsecond(9)]), "000000001");
+        assert_eq!(1.nanoseconds().format(&[Subsecond(20)]), "000000001");
+        assert_eq!(
+            (-1).nanoseconds()
+                .format(&[Sign, Seconds, Literal("."), Subsecond(1)]),
+            "-00.0"
+        );
+        assert_eq!(1.seconds().format(&[]), "");
     }
 
     #[test]
-    fn seconds() {
-        assert_eq!(Duration::seconds(1), 1_000.milliseconds());
-        assert_eq!(Duration::seconds(2), (2 * 1_000).milliseconds());
-        assert_eq!(Duration::seconds(-1), (-1_000).milliseconds());
-        assert_eq!(Duration::seconds(-2), (2 * -1_000).milliseconds());
+    fn format_seconds() {
+        assert_eq!(1.5.seconds().format_seconds(None), "1.5");
+        assert_eq!(1.5.seconds().format_seconds(Some(3)), "1.500");
+        assert_eq!(1.5.seconds().format_seconds(Some(0)), "1");
+        assert_eq!(1.5.seconds().format_seconds(Some(9)), "1.500000000");
+        assert_eq!(1.5.seconds().format_seconds(Some(255)), "1.500000000");
+        assert_eq!(1.seconds().format_seconds(None), "1");
+        assert_eq!(0.seconds().format_seconds(None), "0");
+        assert_eq!(0.seconds().format_seconds(Some(2)), "0.00");
+        assert_eq!(1.nanoseconds().format_seconds(None), "0.000000001");
+        assert_eq!(1_999.milliseconds().format_seconds(Some(1)), "1.9");
+        assert_eq!((-1.5).seconds().format_seconds(None), "-1.5");
+        assert_eq!((-500).milliseconds().format_seconds(Some(3)), "-0.500");
+        assert_eq!((-500).milliseconds().format_seconds(Some(0)), "0");
+        assert_eq!((-1).nanoseconds().format_seconds(Some(8)), "0.00000000");
+        assert_eq!(
+            Duration::min_value().format_seconds(None),
+            "-9223372036854775808.999999999"
+        );
+        assert_eq!(
+            Duration::max_value().format_seconds(Some(2)),
+            "9223372036854775807.99"
+        );
     }
 
     #[test]
-    fn whole_seconds() {
-        assert_eq!(1.seconds().whole_seconds(), 1);
-        assert_eq!((-1).seconds().whole_seconds(), -1);
-        assert_eq!(1.minutes().whole_seconds(), 60);
-        assert_eq!((-1).minutes().whole_seconds(), -60);
+    fn parse_iso8601() {
+        assert_eq!(Duration::parse_iso8601("P1D"), Ok(1.days()));
+        assert_eq!(Duration::parse_iso8601("PT1H"), Ok(1.hours()));
+        assert_eq!(Duration::parse_iso8601("PT1M"), Ok(1.minutes()));
+        assert_eq!(Duration::parse_iso8601("PT1S"), Ok(1.seconds()));
+        assert_eq!(Duration::parse_iso8601("PT0S"), Ok(0.seconds()));
+        assert_eq!(Duration::parse_iso8601("P1DT2H3M4S"), Ok(93_784.seconds()));
+        assert_eq!(Duration::parse_iso8601("PT2H4S"), Ok(7_204.seconds()));
+        assert_eq!(Duration::parse_iso8601("PT36H"), Ok(36.hours()));
+        assert_eq!(Duration::parse_iso8601("PT1.5S"), Ok(1.5.seconds()));
+        assert_eq!(Duration::parse_iso8601("PT1,5S"), Ok(1.5.seconds()));
+        assert_eq!(
+            Duration::parse_iso8601("PT0.000000001S"),
+            Ok(1.nanoseconds())
+        );
+        assert_eq!(Duration::parse_iso8601("+P1D"), Ok(1.days()));
+        assert_eq!(
+            Duration::parse_iso8601("-P1DT1S"),
+            Ok(-(1.days() + 1.seconds()))
+        );
+        assert_eq!(Duration::parse_iso8601("-PT0.5S"), Ok((-0.5).seconds()));
+
+        let invalid = [
+            "",
+            "P",
+            "PT",
+            "-P",
+            "1D",
+            "P1",
+            "PT1",
+            "P1Y",
+            "P1M",
+            "P1DT",
+            "PT1D",
+            "P1H",
+            "PT1S1M",
+            "PT1M1M",
+            "PT1.5M",
+            "PT1.S",
+            "PT.5S",
+            "PT1.0000000001S",
+            "P1D1D",
+            "p1d",
+            "P1DX",
+            "P-",
+            "PT-H",
+            "PT--1H",
+            "--PT1H",
+            "PT1.-5S",
+            "PT-",
+            "PT1H ",
+        ];
+        for &s in &invalid {
+            assert_eq!(Duration::parse_iso8601(s), Err(ParseError::InvalidDuration));
+        }
     }
 
     #[test]
-    fn seconds_f64() {
-        assert_eq!(Duration::seconds_f64(0.5), 0.5.seconds());
-        assert_eq!(Duration::seconds_f64(-0.5), (-0.5).seconds());
+    fn parse_iso8601_weeks() {
+        assert_eq!(Duration::parse_iso8601("P2W"), Ok(14.days()));
+        assert_eq!(Duration::parse_iso8601("P0W"), Ok(0.seconds()));
+        assert_eq!(Duration::parse_iso8601("-P1W"), Ok((-7).days()));
+
+        for &s in &["P2W1D", "P1D2W", "P2WT1H", "P2W2W", "PT2W"] {
+            assert_eq!(Duration::parse_iso8601(s), Err(ParseError::InvalidDuration));
+        }
+        assert_eq!(
+            Duration::parse_iso8601("P2W1D").map_err(Error::from),
+            Err(Error::Parse(ParseError::InvalidDuration))
+        );
     }
 
     #[test]
-    #[allow(clippy::float_cmp)]
-    fn as_seconds_f64() {
-        assert_eq!(1.seconds().as_seconds_f64(), 1.0);
-        assert_eq!((-1).seconds().as_seconds_f64(), -1.0);
-        assert_eq!(1.minutes().as_seconds_f64(), 60.0);
-        assert_eq!((-1).minutes().as_seconds_f64(), -60.0);
-        assert_eq!(1.5.seconds().as_seconds_f64(), 1.5);
-        assert_eq!((-1.5).seconds().as_seconds_f64(), -1.5);
+    fn parse_iso8601_component_signs() {
+        let expected = Ok((-90).minutes());
+        assert_eq!(Duration::parse_iso8601("-PT1H30M"), expected);
+        assert_eq!(Duration::parse_iso8601("PT-1H30M"), expected);
+        assert_eq!(Duration::parse_iso8601("PT-1H-30M"), expected);
+        assert_eq!(Duration::parse_iso8601("PT-90M"), expected);
+        assert_eq!(
+            Duration::parse_iso8601("-PT1H30M"),
+            Duration::parse_iso8601("PT-1H30M")
+        );
+        assert_eq!(
+            Duration::parse_iso8601("PT-1H30M"),
+            Duration::parse_iso8601("PT-1H-30M")
+        );
+        assert_eq!(
+            Duration::parse_iso8601("PT-1H30M15S"),
+            Ok((-5_415).seconds())
+        );
+        assert_eq!(Duration::parse_iso8601("PT-1H+30M"), Ok((-30).minutes()));
+        assert_eq!(Duration::parse_iso8601("PT+1H-30M"), Ok(30.minutes()));
+        assert_eq!(Duration::parse_iso8601("PT1H-30M15S"), Ok(1_785.seconds()));
+        assert_eq!(Duration::parse_iso8601("-PT-1H30M"), Ok(90.minutes()));
+        assert_eq!(Duration::parse_iso8601("-PT-1H"), Ok(1.hours()));
+        assert_eq!(Duration::parse_iso8601("P-1D"), Ok((-1).days()));
+        assert_eq!(Duration::parse_iso8601("P-1W"), Ok((-1).weeks()));
+        assert_eq!(Duration::parse_iso8601("P-1DT1H"), Ok((-23).hours()));
+        assert_eq!(Duration::parse_iso8601("PT-1.5S"), Ok((-1.5).seconds()));
+        assert_eq!(Duration::parse_iso8601("PT1M-0.5S"), Ok(59.5.seconds()));
+        assert_eq!(Duration::parse_iso8601("PT-0S"), Ok(0.seconds()));
+
+        for &duration in &[(-90).minutes(), (-1.5).seconds(), (-1).days()] {
+            assert_eq!(Duration::parse_iso8601(duration.to_iso8601()), Ok(duration));
+        }
     }
 
     #[test]
-    fn seconds_f32() {
-        assert_eq!(Duration::seconds_f32(0.5), 0.5.seconds());
-        assert_eq!(Duration::seconds_f32(-0.5), (-0.5).seconds());
+    fn display_as() {
+        use DisplayStyle::*;
+
+        let duration = 1.days() + 2.hours() + 3.minutes() + 4.seconds() + 5.milliseconds();
+        assert_eq!(duration.display_as(Compact).to_string(), "1d2h3m4s5ms");
+        assert_eq!(duration.display_as(Iso8601).to_string(), "P1DT2H3M4.005S");
+        assert_eq!(duration.display_as(Timecode).to_string(), "26:03:04.005");
+        assert_eq!(duration.display_as(Humanized).to_string(), "in 1 day");
+
+        let duration = (-90).seconds();
+        assert_eq!(duration.display_as(Compact).to_string(), "-1m30s");
+        assert_eq!(duration.display_as(Iso8601).to_string(), "-PT1M30S");
+        assert_eq!(duration.display_as(Timecode).to_string(), "-00:01:30.000");
+        assert_eq!(duration.display_as(Humanized).to_string(), "1 minute ago");
+
+        let duration = Duration::zero();
+        assert_eq!(duration.display_as(Compact).to_string(), "0s");
+        assert_eq!(duration.display_as(Iso8601).to_string(), "PT0S");
+        assert_eq!(duration.display_as(Timecode).to_string(), "00:00:00.000");
+        assert_eq!(duration.display_as(Humanized).to_string(), "just now");
+
+        assert_eq!(
+            format!("[{:>8}]", 90.seconds().display_as(Iso8601)),
+            "[ PT1M30S]"
+        );
     }
 
     #[test]
-    #[allow(clippy::float_cmp)]
-    fn as_seconds_f32() {
-        assert_eq!(1.seconds().as_seconds_f32(), 1.0);
-        assert_eq!((-1).seconds().as_seconds_f32(), -1.0);
-        assert_eq!(1.minutes().as_seconds_f32(), 60.0);
-        assert_eq!((-1).minutes().as_seconds_f32(), -60.0);
-        assert_eq!(1.5.seconds().as_seconds_f32(), 1.5);
-        assert_eq!((-1.5).seconds().as_seconds_f32(), -1.5);
+    fn display_with_labels() {
+        let french = UnitLabels {
+            days: UnitLabel::new("j", "jour", "jours"),
+            hours: UnitLabel::new("h", "heure", "heures"),
+            minutes: UnitLabel::new("min", "minute", "minutes"),
+            seconds: UnitLabel::new("s", "seconde", "secondes"),
+            ..Default::default()
+        };
+
+        let duration = 1.days() + 2.hours();
+        assert_eq!(duration.display_with_labels(french).to_string(), "1 j 2 h");
+        assert_eq!(
+            format!("{:#}", duration.display_with_labels(french)),
+            "1 jour 2 heures"
+        );
+        assert_eq!(
+            (-90).seconds().display_with_labels(french).to_string(),
+            "-1 min 30 s"
+        );
+        assert_eq!(
+            format!("{:#}", 0.seconds().display_with_labels(french)),
+            "0 secondes"
+        );
+        assert_eq!(0.seconds().display_with_labels(french).to_string(), "0 s");
+        assert_eq!(
+            format!("{:>8}", 2.hours().display_with_labels(french)),
+            "     2 h"
+        );
+
+        let english = UnitLabels::default();
+        assert_eq!(
+            (1.5).seconds().display_with_labels(english).to_string(),
+            "1 s 500 ms"
+        );
+        assert_eq!(
+            format!("{:#}", (-1).nanoseconds().display_with_labels(english)),
+            "-1 nanosecond"
+        );
     }
 
     #[test]
-    fn milliseconds() {
-        assert_eq!(Duration::milliseconds(1), 1_000.microseconds());
-        assert_eq!(Duration::milliseconds(-1), (-1000).microseconds());
+    fn to_iso8601() {
+        assert_eq!(0.seconds().to_iso8601(), "PT0S");
+        assert_eq!(1.days().to_iso8601(), "P1D");
+        assert_eq!(2.weeks().to_iso8601(), "P14D");
+        assert_eq!(1.hours().to_iso8601(), "PT1H");
+        assert_eq!(93_784.seconds().to_iso8601(), "P1DT2H3M4S");
+        assert_eq!(7_204.seconds().to_iso8601(), "PT2H4S");
+        assert_eq!(1.5.seconds().to_iso8601(), "PT1.5S");
+        assert_eq!(1.nanoseconds().to_iso8601(), "PT0.000000001S");
+        assert_eq!(
+            (1.days() + 1.nanoseconds()).to_iso8601(),
+            "P1DT0.000000001S"
+        );
+        assert_eq!((-1.5).seconds().to_iso8601(), "-PT1.5S");
+        assert_eq!((-1).days().to_iso8601(), "-P1D");
     }
 
     #[test]
-    fn whole_milliseconds() {
-        assert_eq!(1.seconds().whole_milliseconds(), 1_000);
-        assert_eq!((-1).seconds().whole_milliseconds(), -1_000);
-        assert_eq!(1.milliseconds().whole_milliseconds(), 1);
-        assert_eq!((-1).milliseconds().whole_milliseconds(), -1);
+    fn iso8601_round_trip() {
+        let durations = [
+            0.seconds(),
+            1.nanoseconds(),
+            (-1).nanoseconds(),
+            2.weeks(),
+            93_784.seconds(),
+            (-1.5).seconds(),
+            Duration::max_value(),
+            Duration::min_value(),
+        ];
+        for &duration in &durations {
+            assert!(!duration.to_iso8601().contains('W'));
+            assert_eq!(Duration::parse_iso8601(duration.to_iso8601()), Ok(duration));
+        }
     }
 
     #[test]
-    fn subsec_milliseconds() {
-        assert_eq!(1.4.seconds().subsec_milliseconds(), 400);
-        assert_eq!((-1.4).seconds().subsec_milliseconds(), -400);
+    fn parse_ascii_whitespace_and_case() {
+        assert_eq!(Duration::parse_ascii(" 1H 30M "), Ok(90.minutes()));
+        assert_eq!(Duration::parse_ascii("1h30m"), Ok(90.minutes()));
+        assert_eq!(Duration::parse_ascii(" 5S "), Ok(5.seconds()));
+        assert_eq!(Duration::parse_ascii("\t-1h\n"), Ok((-1).hours()));
+        assert_eq!(Duration::parse_ascii("1Ms"), Ok(1.milliseconds()));
+        assert_eq!(
+            Duration::parse_ascii("1mS 1NS"),
+            Ok(1_000_001.nanoseconds())
+        );
+        assert_eq!(Duration::parse_ascii("1W 1D"), Ok(8.days()));
+        assert_eq!(Duration::parse_ascii("5µs"), Ok(5.microseconds()));
+        assert_eq!(Duration::parse_ascii("5µS"), Ok(5.microseconds()));
+        assert_eq!(Duration::parse_ascii("5US"), Ok(5.microseconds()));
+        assert_eq!(" 1H 30M ".parse::<Duration>(), Ok(90.minutes()));
     }
 
     #[test]
-    fn microseconds() {
-        assert_eq!(Duration::microseconds(1), 1_000.nanoseconds());
-        assert_eq!(Duration::microseconds(-1), (-1_000).nanoseconds());
+    fn parse_ascii_separators() {
+        assert_eq!(Duration::parse_ascii("1_000ms"), Ok(1.seconds()));
+        assert_eq!(Duration::parse_ascii("1_0_0_0ms"), Ok(1.seconds()));
+        assert_eq!(
+            Duration::parse_ascii("-1_000s 1_000ms"),
+            Ok((-1_001).seconds())
+        );
+        assert_eq!(
+            Duration::parse_ascii("9_223_372_036_854_775_807s"),
+            Ok(Duration::seconds(i64::max_value()))
+        );
+        assert_eq!("1_000ms".parse::<Duration>(), Ok(1.seconds()));
+
+        for &s in &[
+            "1__000ms", "_1000ms", "1000_ms", "1_ms", "1,000s", "1_,000s",
+        ] {
+            assert_eq!(Duration::parse_ascii(s), Err(ParseError::InvalidDuration));
+        }
+        assert_eq!(
+            Duration::parse_ascii("1_000_000_000_000_000_000_000_000_000_000_000_000_000ns"),
+            Err(ParseError::InvalidDuration)
+        );
     }
 
     #[test]
-    fn whole_microseconds() {
-        assert_eq!(1.milliseconds().whole_microseconds(), 1_000);
-        assert_eq!((-1).milliseconds().whole_microseconds(), -1_000);
-        assert_eq!(1.microseconds().whole_microseconds(), 1);
-        assert_eq!((-1).microseconds().whole_microseconds(), -1);
+    fn parse_ascii_with_commas() {
+        assert_eq!(
+            Duration::parse_ascii_with_commas("1,000s"),
+            Ok(1_000.seconds())
+        );
+        assert_eq!(
+            Duration::parse_ascii_with_commas("1_000ms"),
+            Ok(1.seconds())
+        );
+        assert_eq!(
+            Duration::parse_ascii_with_commas("1,000_000us"),
+            Ok(1.seconds())
+        );
+        assert_eq!(
+            Duration::parse_ascii_with_commas("1h 30m"),
+            Ok(90.minutes())
+        );
+
+        for &s in &["1,,000s", ",1000s", "1000,s", "1,s", "1_,000s", "1s,1s"] {
+            assert_eq!(
+                Duration::parse_ascii_with_commas(s),
+                Err(ParseError::InvalidDuration)
+            );
+        }
     }
 
     #[test]
-    fn subsec_microseconds() {
-        assert_eq!(1.0004.seconds().subsec_microseconds(), 400);
-        assert_eq!((-1.0004).seconds().subsec_microseconds(), -400);
+    fn from_ascii_strict() {
+        assert_eq!(Duration::from_ascii_strict(b"5s"), Ok(5.seconds()));
+        assert_eq!(Duration::from_ascii_strict(b"1h30m"), Ok(90.minutes()));
+        assert_eq!(Duration::from_ascii_strict(b"-1h30m"), Ok((-90).minutes()));
+        assert_eq!(Duration::from_ascii_strict(b"0s"), Ok(0.seconds()));
+        assert_eq!(Duration::from_ascii_strict(b"-0s"), Ok(0.seconds()));
+        assert_eq!(
+            Duration::from_ascii_strict(b"1w2d3h4m5s6ms7us8ns"),
+            Ok(1.weeks()
+                + 2.days()
+                + 3.hours()
+                + 4.minutes()
+                + 5.seconds()
+                + 6.milliseconds()
+                + 7.microseconds()
+                + 8.nanoseconds())
+        );
+        assert_eq!(Duration::from_ascii_strict(b"90m"), Ok(90.minutes()));
+        assert_eq!(
+            Duration::from_ascii_strict(b"1m1ms"),
+            Ok(60_001.milliseconds())
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"9223372036854775807s999999999ns"),
+            Ok(Duration::max_value())
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"-9223372036854775808s999999999ns"),
+            Ok(Duration::min_value())
+        );
     }
 
     #[test]
-    fn nanoseconds() {
-        assert_eq!(Duration::nanoseconds(1), 1.microseconds() / 1_000);
-        assert_eq!(Duration::nanoseconds(-1), (-1).microseconds() / 1_000);
+    fn from_ascii_strict_invalid_digit() {
+        use DurationParseError::InvalidDigit;
+
+        assert_eq!(
+            Duration::from_ascii_strict(b""),
+            Err(InvalidDigit { position: 0 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"-"),
+            Err(InvalidDigit { position: 1 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"+1s"),
+            Err(InvalidDigit { position: 0 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b" 1s"),
+            Err(InvalidDigit { position: 0 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1h 30m"),
+            Err(InvalidDigit { position: 2 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1s "),
+            Err(InvalidDigit { position: 2 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"s"),
+            Err(InvalidDigit { position: 0 })
+        );
     }
 
     #[test]
-    fn whole_nanoseconds() {
-        assert_eq!(1.microseconds().whole_nanoseconds(), 1_000);
-        assert_eq!((-1).microseconds().whole_nanoseconds(), -1_000);
-        assert_eq!(1.nanoseconds().whole_nanoseconds(), 1);
-        assert_eq!((-1).nanoseconds().whole_nanoseconds(), -1);
+    fn from_ascii_strict_unknown_unit() {
+        use DurationParseError::UnknownUnit;
+
+        assert_eq!(
+            Duration::from_ascii_strict(b"1"),
+            Err(UnknownUnit { position: 1 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1h30x"),
+            Err(UnknownUnit { position: 4 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1H"),
+            Err(UnknownUnit { position: 1 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict("1µs".as_bytes()),
+            Err(UnknownUnit { position: 1 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1_000ms"),
+            Err(UnknownUnit { position: 1 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1.5s"),
+            Err(UnknownUnit { position: 1 })
+        );
     }
 
     #[test]
-    fn subsec_nanoseconds() {
-        assert_eq!(1.000_000_4.seconds().subsec_nanoseconds(), 400);
-        assert_eq!((-1.000_000_4).seconds().subsec_nanoseconds(), -400);
+    fn from_ascii_strict_unit_out_of_order() {
+        use DurationParseError::UnitOutOfOrder;
+
+        assert_eq!(
+            Duration::from_ascii_strict(b"30m1h"),
+            Err(UnitOutOfOrder { position: 4 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1s1s"),
+            Err(UnitOutOfOrder { position: 3 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1ms1s"),
+            Err(UnitOutOfOrder { position: 4 })
+        );
     }
 
     #[test]
-    #[allow(deprecated)]
-    fn checked_add() {
-        assert_eq!(5.seconds().checked_add(5.seconds()), Some(10.seconds()));
-        assert_eq!(Duration::max_value().checked_add(1.nanoseconds()), None);
-        assert_eq!((-5).seconds().checked_add(5.seconds()), Some(0.seconds()));
+    fn from_ascii_strict_overflow() {
+        use DurationParseError::Overflow;
+
+        assert_eq!(
+            Duration::from_ascii_strict(b"9223372036854775808s"),
+            Err(Overflow { position: 0 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"-9223372036854775808s1000000000ns"),
+            Err(Overflow { position: 21 })
+        );
+        assert_eq!(
+            Duration::from_ascii_strict(b"1h1000000000000000000000000000000000000000ns"),
+            Err(Overflow { position: 2 })
+        );
+        assert_eq!(Overflow { position: 2 }.position(), 2);
+        assert_eq!(
+            Error::from(Overflow { position: 2 }),
+            Error::DurationParse(Overflow { position: 2 })
+        );
     }
 
     #[test]
-    #[allow(deprecated)]
-    fn checked_sub() {
-        assert_eq!(5.seconds().checked_sub(5.seconds()), Some(0.seconds()));
-        assert_eq!(Duration::min_value().checked_sub(1.nanoseconds()), None);
-        assert_eq!(5.seconds().checked_sub(10.seconds()), Some((-5).seconds()));
+    fn from_str() {
+        assert_eq!("+1h".parse::<Duration>(), Ok(1.hours()));
+        assert_eq!("-1h".parse::<Duration>(), Ok((-1).hours()));
+        assert_eq!("1h".parse::<Duration>(), Ok(1.hours()));
+        assert_eq!("+".parse::<Duration>(), Err(ParseError::InvalidDuration));
     }
 
     #[test]
-    #[allow(deprecated)]
-    fn checked_mul() {
-        assert_eq!(5.seconds().checked_mul(2), Some(10.seconds()));
-        assert_eq!(5.seconds().checked_mul(-2), Some((-10).seconds()));
-        assert_eq!(5.seconds().checked_mul(0), Some(Duration::zero()));
-        assert_eq!(Duration::max_value().checked_mul(2), None);
-        assert_eq!(Duration::min_value().checked_mul(2), None);
+    fn humanize() {
+        assert_eq!(0.seconds().humanize(), "just now");
+        assert_eq!(999.milliseconds().humanize(), "just now");
+        assert_eq!((-999).milliseconds().humanize(), "just now");
+        assert_eq!(1.seconds().humanize(), "in 1 second");
+        assert_eq!((-1).seconds().humanize(), "1 second ago");
+        assert_eq!(59.seconds().humanize(), "in 59 seconds");
+        assert_eq!(3.minutes().humanize(), "in 3 minutes");
+        assert_eq!((-3).minutes().humanize(), "3 minutes ago");
+        assert_eq!(90.minutes().humanize(), "in 1 hour");
+        assert_eq!((-47).hours().humanize(), "1 day ago");
+        assert_eq!(13.days().humanize(), "in 1 week");
+        assert_eq!((-3).weeks().humanize(), "3 weeks ago");
+        assert_eq!(Duration::max_value().humanize(), "in 15250284452471 weeks");
+        assert_eq!(Duration::min_value().humanize(), "15250284452471 weeks ago");
     }
 
     #[test]
-    fn checked_div() {
-        assert_eq!(10.seconds().checked_div(2), Some(5.seconds()));
-        assert_eq!(10.seconds().checked_div(-2), Some((-5).seconds()));
-        assert_eq!(1.seconds().checked_div(0), None);
+    fn humanize_with() {
+        let options = HumanizeOptions {
+            just_now_threshold: 1.minutes(),
+            just_now: "now",
+            future_prefix: "",
+            future_suffix: " from now",
+            ..Default::default()
+        };
+        assert_eq!(59.seconds().humanize_with(options), "now");
+        assert_eq!((-59).seconds().humanize_with(options), "now");
+        assert_eq!(1.minutes().humanize_with(options), "1 minute from now");
+        assert_eq!((-1).minutes().humanize_with(options), "1 minute ago");
+
+        let options = HumanizeOptions {
+            just_now_threshold: 0.seconds(),
+            future_prefix: "dans ",
+            past_prefix: "il y a ",
+            past_suffix: "",
+            labels: UnitLabels {
+                weeks: UnitLabel::new("sem.", "semaine", "semaines"),
+                days: UnitLabel::new("j", "jour", "jours"),
+                hours: UnitLabel::new("h", "heure", "heures"),
+                seconds: UnitLabel::new("s", "seconde", "secondes"),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(0.seconds().humanize_with(options), "dans 0 secondes");
+        assert_eq!(2.days().humanize_with(options), "dans 2 jours");
+        assert_eq!((-1).hours().humanize_with(options), "il y a 1 heure");
+        assert_eq!((-2).weeks().humanize_with(options), "il y a 2 semaines");
+
+        // The same labels can be used for both formatters.
+        assert_eq!(
+            format!("{:#}", 2.days().display_with_labels(options.labels)),
+            "2 jours"
+        );
     }
 
     #[test]
@@ -1498,6 +7604,59 @@ mod test {
         assert_eq!(value, 0);
     }
 
+    #[test]
+    #[cfg(std)]
+    fn time_fn_with() {
+        let clock = crate::MockClock::default();
+        let (time, value) = Duration::time_fn_with(&clock, || {
+            clock.advance(1.5.seconds());
+            0
+        });
+        assert_eq!(time, 1.5.seconds());
+        assert_eq!(value, 0);
+
+        let (time, ()) = Duration::time_fn_with(&clock, || ());
+        assert_eq!(time, Duration::zero());
+    }
+
+    #[test]
+    #[cfg(std)]
+    fn since_system_time() -> Result<(), ConversionRangeError> {
+        let earlier = SystemTime::now() - 1.std_hours();
+        let duration = Duration::since_system_time(earlier)?;
+        assert!(duration >= 1.hours());
+        assert!(duration < 2.hours());
+
+        let later = SystemTime::now() + 1.std_hours();
+        let duration = Duration::since_system_time(later)?;
+        assert!(duration <= (-59).minutes());
+        assert!(duration > (-1).hours());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(std)]
+    fn between() {
+        let a = SystemTime::UNIX_EPOCH + 1_000.std_seconds();
+        let b = a + 1.5.std_seconds();
+
+        assert_eq!(Duration::between(a, b), Ok(1.5.seconds()));
+        assert_eq!(Duration::between(b, a), Ok((-1.5).seconds()));
+        assert_eq!(Duration::between(a, a), Ok(0.seconds()));
+        assert_eq!(
+            Duration::between(a, a + 1.std_nanoseconds()),
+            Ok(1.nanoseconds())
+        );
+        assert_eq!(
+            Duration::between(a + 1.std_nanoseconds(), a),
+            Ok((-1).nanoseconds())
+        );
+        assert_eq!(
+            Duration::between(SystemTime::UNIX_EPOCH, a),
+            Ok(1_000.seconds())
+        );
+    }
+
     #[test]
     fn try_from_std_duration() {
         assert_eq!(Duration::try_from(0.std_seconds()), Ok(0.seconds()));
@@ -1511,6 +7670,88 @@ mod test {
         assert!(StdDuration::try_from((-1).seconds()).is_err());
     }
 
+    #[test]
+    fn try_from_components() {
+        assert_eq!(
+            Duration::try_from(DurationComponents {
+                weeks: Some(1),
+                days: Some(1),
+                hours: Some(1),
+                minutes: Some(1),
+                seconds: Some(1),
+                milliseconds: Some(1),
+                microseconds: Some(1),
+                nanoseconds: Some(1),
+            }),
+            Ok(8.days() + 1.hours() + 1.minutes() + 1.seconds() + 1_001_001.nanoseconds())
+        );
+        assert_eq!(
+            Duration::try_from(DurationComponents {
+                days: Some(1),
+                hours: Some(2),
+                minutes: Some(30),
+                ..Default::default()
+            }),
+            Ok(1.days() + 150.minutes())
+        );
+        assert_eq!(
+            Duration::try_from(DurationComponents {
+                hours: Some(1),
+                minutes: Some(-30),
+                ..Default::default()
+            }),
+            Ok(30.minutes())
+        );
+        assert_eq!(
+            Duration::try_from(DurationComponents {
+                milliseconds: Some(1_500),
+                ..Default::default()
+            }),
+            Ok(1.5.seconds())
+        );
+        assert_eq!(
+            Duration::try_from(DurationComponents::default()),
+            Ok(0.seconds())
+        );
+        assert_eq!(
+            Duration::try_from(DurationComponents {
+                seconds: Some(i64::max_value()),
+                nanoseconds: Some(999_999_999),
+                ..Default::default()
+            }),
+            Ok(Duration::max_value())
+        );
+
+        assert!(Duration::try_from(DurationComponents {
+            seconds: Some(i64::max_value()),
+            nanoseconds: Some(1_000_000_000),
+            ..Default::default()
+        })
+        .is_err());
+        assert!(Duration::try_from(DurationComponents {
+            weeks: Some(i64::max_value()),
+            ..Default::default()
+        })
+        .is_err());
+        assert!(Duration::try_from(DurationComponents {
+            days: Some(i64::min_value()),
+            nanoseconds: Some(i64::min_value()),
+            ..Default::default()
+        })
+        .is_err());
+        assert!(Duration::try_from(DurationComponents {
+            weeks: Some(i64::max_value()),
+            days: Some(i64::max_value()),
+            hours: Some(i64::max_value()),
+            minutes: Some(i64::max_value()),
+            seconds: Some(i64::max_value()),
+            milliseconds: Some(i64::max_value()),
+            microseconds: Some(i64::max_value()),
+            nanoseconds: Some(i64::max_value()),
+        })
+        .is_err());
+    }
+
     #[test]
     fn add() {
         assert_eq!(1.seconds() + 1.seconds(), 2.seconds());
@@ -1749,6 +7990,353 @@ mod test {
         assert_eq!(1.seconds() / -1_f64, (-1).seconds());
     }
 
+    #[test]
+    fn scale() {
+        assert_eq!(2.seconds().scale(1.5), 3.seconds());
+        assert_eq!(2.seconds().scale(-1.5), (-3).seconds());
+        assert_eq!((-2).seconds().scale(0.5), (-1).seconds());
+
+        for &factor in &[0., -0.] {
+            for &duration in &[(-1).seconds(), 1.seconds(), Duration::min_value()] {
+                let scaled = duration.scale(factor);
+                assert!(scaled.is_zero());
+                assert!(!scaled.is_negative());
+                assert!(!scaled.is_positive());
+            }
+        }
+        assert!(!((-1).seconds() * 0_f64).is_negative());
+        assert!(!((-1).seconds() * -0_f64).is_negative());
+
+        assert_eq!(
+            1.seconds().scale(core::f64::INFINITY),
+            Duration::max_value()
+        );
+        assert_eq!(
+            1.seconds().scale(core::f64::NEG_INFINITY),
+            Duration::min_value()
+        );
+        assert_eq!(
+            (-1).seconds().scale(core::f64::INFINITY),
+            Duration::min_value()
+        );
+        assert_eq!(Duration::max_value().scale(2.), Duration::max_value());
+        assert_eq!(1.seconds().scale(core::f64::NAN), Duration::zero());
+        assert_eq!(0.seconds().scale(core::f64::INFINITY), Duration::zero());
+    }
+
+    #[test]
+    fn try_mul_f64() {
+        assert_eq!(2.seconds().try_mul_f64(1.5), Ok(3.seconds()));
+        assert_eq!(2.seconds().try_mul_f64(-1.5), Ok((-3).seconds()));
+        assert_eq!((-1).seconds().try_mul_f64(0.), Ok(0.seconds()));
+        assert_eq!(0.seconds().try_mul_f64(1e300), Ok(0.seconds()));
+
+        for &factor in &[core::f64::INFINITY, core::f64::NEG_INFINITY, core::f64::NAN] {
+            assert_eq!(
+                1.seconds().try_mul_f64(factor),
+                Err(ConversionRangeError::new())
+            );
+            assert_eq!(
+                0.seconds().try_mul_f64(factor),
+                Err(ConversionRangeError::new())
+            );
+        }
+
+        assert_eq!(
+            Duration::max_value().try_mul_f64(2.),
+            Err(ConversionRangeError::new())
+        );
+        assert_eq!(
+            Duration::min_value().try_mul_f64(2.),
+            Err(ConversionRangeError::new())
+        );
+        assert_eq!(
+            1.seconds().try_mul_f64(core::f64::MAX),
+            Err(ConversionRangeError::new())
+        );
+        assert_eq!(
+            1.seconds().try_mul_f64(1e18),
+            Ok(Duration::seconds(1_000_000_000_000_000_000))
+        );
+    }
+
+    #[test]
+    fn geometric() {
+        assert_eq!(Duration::geometric(1.seconds(), 2., 0), 1.seconds());
+        assert_eq!(
+            Duration::geometric(Duration::max_value(), 2., 0),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::geometric(1.seconds(), core::f64::NAN, 0),
+            1.seconds()
+        );
+        assert_eq!(
+            Duration::geometric(Duration::max_value(), 1., 5),
+            Duration::max_value()
+        );
+
+        assert_eq!(Duration::geometric(1.seconds(), 2., 1), 2.seconds());
+        assert_eq!(Duration::geometric(1.seconds(), 2., 10), 1_024.seconds());
+        assert_eq!(Duration::geometric(250.milliseconds(), 2., 4), 4.seconds());
+        assert_eq!(Duration::geometric((-1).seconds(), 3., 2), (-9).seconds());
+        assert_eq!(Duration::geometric(1.seconds(), -2., 3), (-8).seconds());
+
+        assert_eq!(Duration::geometric(1.seconds(), 0.5, 1), 500.milliseconds());
+        assert_eq!(Duration::geometric(8.seconds(), 0.5, 3), 1.seconds());
+        assert_eq!(
+            Duration::geometric(1.seconds(), 0.5, 2_000),
+            Duration::zero()
+        );
+        assert_eq!(Duration::geometric(1.seconds(), 0., 1), Duration::zero());
+
+        assert_eq!(
+            Duration::geometric(1.seconds(), 10., 100),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::geometric((-1).seconds(), 10., 100),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::geometric(1.seconds(), core::f64::INFINITY, 1),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::geometric(1.seconds(), core::f64::NAN, 1),
+            Duration::zero()
+        );
+        assert_eq!(
+            Duration::geometric(Duration::zero(), core::f64::INFINITY, 1),
+            Duration::zero()
+        );
+    }
+
+    #[test]
+    fn backoff() {
+        let delays: Vec<_> =
+            Duration::backoff(100.milliseconds(), 2., 1.seconds(), 6, None).collect();
+        assert_eq!(
+            delays,
+            [
+                100.milliseconds(),
+                200.milliseconds(),
+                400.milliseconds(),
+                800.milliseconds(),
+                1.seconds(),
+                1.seconds(),
+            ]
+        );
+
+        assert_eq!(
+            Duration::backoff(1.seconds(), 2., 1.minutes(), 0, None).count(),
+            0
+        );
+        assert_eq!(
+            Duration::backoff(1.seconds(), 2., 1.minutes(), 100, None).count(),
+            100
+        );
+        assert!(Duration::backoff(1.seconds(), 2., 1.minutes(), 100, None)
+            .all(|delay| delay <= 1.minutes()));
+        assert_eq!(
+            Duration::backoff(1.seconds(), 2., 1.minutes(), 100, None).last(),
+            Some(1.minutes())
+        );
+        assert_eq!(
+            Duration::backoff(1.seconds(), 10., Duration::max_value(), 100, None).last(),
+            Some(Duration::max_value())
+        );
+
+        // A base exceeding the cap is capped from the start.
+        assert_eq!(
+            Duration::backoff(10.seconds(), 2., 1.seconds(), 3, None).collect::<Vec<_>>(),
+            [1.seconds(), 1.seconds(), 1.seconds()]
+        );
+
+        let jittered: Vec<_> = Duration::backoff(
+            1.seconds(),
+            2.,
+            4.seconds(),
+            4,
+            Some(|delay| delay + 100.milliseconds()),
+        )
+        .collect();
+        assert_eq!(
+            jittered,
+            [
+                1_100.milliseconds(),
+                2_100.milliseconds(),
+                4_100.milliseconds(),
+                4_100.milliseconds(),
+            ]
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        assert_eq!(Duration::lerp(1.seconds(), 3.seconds(), 0.), 1.seconds());
+        assert_eq!(Duration::lerp(1.seconds(), 3.seconds(), 1.), 3.seconds());
+        assert_eq!(Duration::lerp(1.seconds(), 3.seconds(), 0.5), 2.seconds());
+        assert_eq!(
+            Duration::lerp(1.seconds(), 3.seconds(), 0.25),
+            1.5.seconds()
+        );
+        assert_eq!(Duration::lerp(3.seconds(), 1.seconds(), 0.5), 2.seconds());
+        assert_eq!(
+            Duration::lerp((-1).seconds(), 1.seconds(), 0.5),
+            0.seconds()
+        );
+        assert_eq!(
+            Duration::lerp(0.seconds(), 3.nanoseconds(), 0.5),
+            2.nanoseconds()
+        );
+        assert_eq!(
+            Duration::lerp(0.seconds(), (-3).nanoseconds(), 0.5),
+            (-2).nanoseconds()
+        );
+
+        assert_eq!(Duration::lerp(1.seconds(), 3.seconds(), -1.), 1.seconds());
+        assert_eq!(Duration::lerp(1.seconds(), 3.seconds(), 2.), 3.seconds());
+        assert_eq!(
+            Duration::lerp(1.seconds(), 3.seconds(), core::f64::INFINITY),
+            3.seconds()
+        );
+        assert_eq!(
+            Duration::lerp(1.seconds(), 3.seconds(), core::f64::NAN),
+            1.seconds()
+        );
+
+        // The endpoints are exact even where the difference cannot be
+        // represented exactly as a float.
+        let a = Duration::new(-1_234_567_890_123, -456_789_123);
+        let b = Duration::new(9_876_543_210_987, 654_321_987);
+        assert_eq!(Duration::lerp(a, b, 0.), a);
+        assert_eq!(Duration::lerp(a, b, 1.), b);
+        assert_eq!(
+            Duration::lerp(Duration::min_value(), Duration::max_value(), 0.),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::lerp(Duration::min_value(), Duration::max_value(), 1.),
+            Duration::max_value()
+        );
+        assert_eq!(Duration::lerp(0.seconds(), 2.days(), 0.5), 1.days());
+        assert_eq!(Duration::lerp((-1).weeks(), 3.weeks(), 0.5), 1.weeks());
+    }
+
+    #[test]
+    fn lerp_unclamped() {
+        assert_eq!(
+            Duration::lerp_unclamped(1.seconds(), 3.seconds(), 0.5),
+            2.seconds()
+        );
+        assert_eq!(
+            Duration::lerp_unclamped(1.seconds(), 3.seconds(), 2.),
+            5.seconds()
+        );
+        assert_eq!(
+            Duration::lerp_unclamped(1.seconds(), 3.seconds(), -1.),
+            (-1).seconds()
+        );
+        assert_eq!(
+            Duration::lerp_unclamped(1.seconds(), 3.seconds(), core::f64::NAN),
+            1.seconds()
+        );
+        assert_eq!(
+            Duration::lerp_unclamped(1.seconds(), 3.seconds(), core::f64::INFINITY),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::lerp_unclamped(1.seconds(), 3.seconds(), core::f64::NEG_INFINITY),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::lerp_unclamped(Duration::min_value(), Duration::max_value(), 2.),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::lerp_unclamped(Duration::max_value(), Duration::min_value(), 2.),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::lerp_unclamped(0.seconds(), 1.seconds(), 1e20),
+            Duration::max_value()
+        );
+    }
+
+    #[test]
+    fn with_tempo_ratio() {
+        assert_eq!(
+            500.milliseconds().with_tempo_ratio(120, 240),
+            250.milliseconds()
+        );
+        assert_eq!(
+            (-500).milliseconds().with_tempo_ratio(120, 240),
+            (-250).milliseconds()
+        );
+        assert_eq!(1.nanoseconds().with_tempo_ratio(1, 2), 1.nanoseconds());
+        assert_eq!(
+            1.seconds().with_tempo_ratio(100, 90),
+            1_111_111_111.nanoseconds()
+        );
+        assert_eq!(1.seconds().with_tempo_ratio(120, 120), 1.seconds());
+        assert_eq!(1.seconds().with_tempo_ratio(0, 120), 0.seconds());
+        assert_eq!(1.seconds().with_tempo_ratio(120, 0), Duration::max_value());
+        assert_eq!(
+            (-1).seconds().with_tempo_ratio(120, 0),
+            Duration::min_value()
+        );
+        assert_eq!(0.seconds().with_tempo_ratio(120, 0), 0.seconds());
+        assert_eq!(
+            Duration::max_value().with_tempo_ratio(2, 1),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::min_value().with_tempo_ratio(u32::max_value(), 1),
+            Duration::min_value()
+        );
+
+        // Doubling the tempo exactly halves the duration.
+        for &duration in &[
+            1.seconds(),
+            (-1).seconds(),
+            Duration::new(12_345, 678_901_234),
+            Duration::max_value() - 1.nanoseconds(),
+        ] {
+            assert_eq!(duration.with_tempo_ratio(60, 120) * 2, duration);
+        }
+    }
+
+    #[test]
+    fn mul_float_saturating() {
+        assert_eq!(Duration::max_value() * 2_f64, Duration::max_value());
+        assert_eq!(Duration::max_value() * -2_f64, Duration::min_value());
+        assert_eq!(Duration::min_value() * 2_f64, Duration::min_value());
+        assert_eq!(1.seconds() * core::f64::INFINITY, Duration::max_value());
+        assert_eq!(1.seconds() * core::f64::NEG_INFINITY, Duration::min_value());
+        assert_eq!((-1).seconds() * core::f64::INFINITY, Duration::min_value());
+        assert_eq!(1.seconds() * core::f64::NAN, Duration::zero());
+        assert_eq!(1.seconds() * 1e300_f64, Duration::max_value());
+
+        assert_eq!(Duration::max_value() * 2_f32, Duration::max_value());
+        assert_eq!(Duration::min_value() * 2_f32, Duration::min_value());
+        assert_eq!(1.seconds() * core::f32::INFINITY, Duration::max_value());
+        assert_eq!(1.seconds() * core::f32::NEG_INFINITY, Duration::min_value());
+        assert_eq!(1.seconds() * core::f32::NAN, Duration::zero());
+    }
+
+    #[test]
+    fn div_float_saturating() {
+        assert_eq!(1.seconds() / 0_f64, Duration::max_value());
+        assert_eq!((-1).seconds() / 0_f64, Duration::min_value());
+        assert_eq!(Duration::max_value() / 0.5_f64, Duration::max_value());
+        assert_eq!(0.seconds() / 0_f64, Duration::zero());
+
+        assert_eq!(1.seconds() / 0_f32, Duration::max_value());
+        assert_eq!((-1).seconds() / 0_f32, Duration::min_value());
+        assert_eq!(0.seconds() / 0_f32, Duration::zero());
+    }
+
     #[test]
     fn div_float_assign() {
         let mut duration = 1.seconds();
@@ -1803,6 +8391,23 @@ mod test {
         assert_ne!(40.std_seconds(), 1.minutes());
     }
 
+    #[test]
+    fn partial_eq_f64() {
+        assert_eq!(1.5.seconds(), 1.5);
+        assert_eq!((-1.5).seconds(), -1.5);
+        assert_eq!(0.seconds(), 0.);
+        assert_ne!(1.5.seconds(), 1.);
+        assert_ne!(0.seconds(), core::f64::NAN);
+    }
+
+    #[test]
+    fn f64_partial_eq() {
+        assert_eq!(1.5, 1.5.seconds());
+        assert_eq!(-1.5, (-1.5).seconds());
+        assert_ne!(1., 1.5.seconds());
+        assert_ne!(core::f64::NAN, 0.seconds());
+    }
+
     #[test]
     fn partial_ord() {
         assert_eq!(0.seconds().partial_cmp(&0.seconds()), Some(Equal));
@@ -1824,6 +8429,10 @@ mod test {
         assert_eq!(0.seconds().partial_cmp(&1.std_seconds()), Some(Less));
         assert_eq!((-1).seconds().partial_cmp(&0.std_seconds()), Some(Less));
         assert_eq!(1.minutes().partial_cmp(&1.std_seconds()), Some(Greater));
+        assert_eq!(
+            Duration::max_value().partial_cmp(&StdDuration::new(u64::max_value(), 0)),
+            Some(Less)
+        );
     }
 
     #[test]
@@ -1834,6 +8443,202 @@ mod test {
         assert_eq!(0.std_seconds().partial_cmp(&(-1).seconds()), Some(Greater));
         assert_eq!(0.std_seconds().partial_cmp(&1.seconds()), Some(Less));
         assert_eq!(1.std_minutes().partial_cmp(&1.seconds()), Some(Greater));
+        assert_eq!(
+            StdDuration::new(u64::max_value(), 0).partial_cmp(&Duration::max_value()),
+            Some(Greater)
+        );
+    }
+
+    #[test]
+    fn partial_ord_f64() {
+        assert!(600.milliseconds() > 0.5);
+        assert!(400.milliseconds() < 0.5);
+        assert!((-600).milliseconds() < -0.5);
+        assert_eq!(500.milliseconds().partial_cmp(&0.5), Some(Equal));
+        assert_eq!(1.seconds().partial_cmp(&core::f64::INFINITY), Some(Less));
+        assert_eq!(0.seconds().partial_cmp(&core::f64::NAN), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(0.seconds().to_string(), "0s");
+        assert_eq!(1.seconds().to_string(), "1s");
+        assert_eq!((-1).seconds().to_string(), "-1s");
+        assert_eq!(90.minutes().to_string(), "1h30m");
+        assert_eq!(1.5.seconds().to_string(), "1s500ms");
+        assert_eq!((-1).nanoseconds().to_string(), "-1ns");
+        assert_eq!(
+            (1.days()
+                + 2.hours()
+                + 3.minutes()
+                + 4.seconds()
+                + 5.milliseconds()
+                + 6.microseconds()
+                + 7.nanoseconds())
+            .to_string(),
+            "1d2h3m4s5ms6µs7ns"
+        );
+        assert_eq!(
+            Duration::min_value().to_string(),
+            "-106751991167300d15h30m8s999ms999µs999ns"
+        );
+        assert_eq!(
+            Duration::max_value().to_string(),
+            "106751991167300d15h30m7s999ms999µs999ns"
+        );
+
+        for &duration in &[
+            0.seconds(),
+            (-90).minutes(),
+            1.5.seconds(),
+            Duration::new(123_456_789, 987_654_321),
+            Duration::min_value(),
+            Duration::max_value(),
+        ] {
+            assert_eq!(duration.to_string().parse(), Ok(duration));
+        }
+    }
+
+    #[test]
+    fn display_padding() {
+        assert_eq!(format!("{:10}", 90.minutes()), "1h30m     ");
+        assert_eq!(format!("{:<10}", 90.minutes()), "1h30m     ");
+        assert_eq!(format!("{:>10}", 90.minutes()), "     1h30m");
+        assert_eq!(format!("{:^10}", 90.minutes()), "  1h30m   ");
+        assert_eq!(format!("{:*^10}", 90.minutes()), "**1h30m***");
+        assert_eq!(format!("{:->6}", (-1).seconds()), "----1s");
+        assert_eq!(format!("{:>3}", 0.seconds()), " 0s");
+        assert_eq!(format!("{:>2}", 90.minutes()), "1h30m");
+        assert_eq!(format!("{:>8}", 1.microseconds()), "     1µs");
+    }
+
+    #[test]
+    fn cmp_std() {
+        assert_eq!(0.seconds().cmp_std(&0.std_seconds()), Equal);
+        assert_eq!(1.5.seconds().cmp_std(&1.5.std_seconds()), Equal);
+        assert_eq!(1.seconds().cmp_std(&0.std_seconds()), Greater);
+        assert_eq!(0.seconds().cmp_std(&1.std_nanoseconds()), Less);
+        assert_eq!((-1).nanoseconds().cmp_std(&0.std_seconds()), Less);
+        assert_eq!((-1).seconds().cmp_std(&1.std_seconds()), Less);
+        assert_eq!(1.minutes().cmp_std(&1.std_seconds()), Greater);
+        assert_eq!(1.seconds().cmp_std(&999.std_milliseconds()), Greater);
+        assert_eq!(Duration::min_value().cmp_std(&0.std_seconds()), Less);
+
+        let max = StdDuration::new(i64::max_value() as u64, 999_999_999);
+        assert_eq!(Duration::max_value().cmp_std(&max), Equal);
+        assert_eq!(
+            Duration::max_value().cmp_std(&StdDuration::new(i64::max_value() as u64 + 1, 0)),
+            Less
+        );
+        assert_eq!(
+            Duration::max_value().cmp_std(&StdDuration::new(u64::max_value(), 999_999_999)),
+            Less
+        );
+        assert_eq!(
+            (Duration::max_value() - 1.nanoseconds()).cmp_std(&max),
+            Less
+        );
+
+        let mut durations = vec![
+            (Some(1.seconds()), None),
+            (None, Some(StdDuration::new(u64::max_value(), 0))),
+            (Some((-1).seconds()), None),
+            (None, Some(500.std_milliseconds())),
+        ];
+        durations.sort_by(|a, b| match (*a, *b) {
+            ((Some(a), _), (Some(b), _)) => a.cmp(&b),
+            ((Some(a), _), (_, Some(b))) => a.cmp_std(&b),
+            ((_, Some(a)), (Some(b), _)) => b.cmp_std(&a).reverse(),
+            ((_, Some(a)), (_, Some(b))) => a.cmp(&b),
+            _ => unreachable!(),
+        });
+        assert_eq!(
+            durations,
+            vec![
+                (Some((-1).seconds()), None),
+                (None, Some(500.std_milliseconds())),
+                (Some(1.seconds()), None),
+                (None, Some(StdDuration::new(u64::max_value(), 0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn cmp_seconds_f64() {
+        assert_eq!(600.milliseconds().cmp_seconds_f64(0.5), Greater);
+        assert_eq!(500.milliseconds().cmp_seconds_f64(0.5), Equal);
+        assert_eq!(400.milliseconds().cmp_seconds_f64(0.5), Less);
+        assert_eq!((-600).milliseconds().cmp_seconds_f64(-0.5), Less);
+        assert_eq!((-400).milliseconds().cmp_seconds_f64(-0.5), Greater);
+        assert_eq!((-500).milliseconds().cmp_seconds_f64(0.3), Less);
+        assert_eq!((-1.5).seconds().cmp_seconds_f64(-0.2), Less);
+        assert_eq!((-0.5).seconds().cmp_seconds_f64(-1.2), Greater);
+        assert_eq!(0.seconds().cmp_seconds_f64(0.), Equal);
+        assert_eq!(0.seconds().cmp_seconds_f64(-0.), Equal);
+        assert_eq!(1.nanoseconds().cmp_seconds_f64(0.), Greater);
+
+        assert_eq!(0.seconds().cmp_seconds_f64(core::f64::NAN), Less);
+        assert_eq!(Duration::max_value().cmp_seconds_f64(core::f64::NAN), Less);
+        assert_eq!(
+            Duration::max_value().cmp_seconds_f64(core::f64::INFINITY),
+            Less
+        );
+        assert_eq!(
+            Duration::min_value().cmp_seconds_f64(core::f64::NEG_INFINITY),
+            Greater
+        );
+        assert_eq!(
+            Duration::max_value().cmp_seconds_f64(9_223_372_036_854_775_808.),
+            Less
+        );
+        assert_eq!(
+            Duration::min_value().cmp_seconds_f64(-9_223_372_036_854_775_808.),
+            Less
+        );
+        assert_eq!(
+            Duration::seconds(i64::min_value()).cmp_seconds_f64(-9_223_372_036_854_775_808.),
+            Equal
+        );
+        // Whole seconds are compared exactly, even where `as_seconds_f64` would
+        // lose precision.
+        assert_eq!(
+            Duration::seconds(1 << 60).cmp_seconds_f64((1_u64 << 60) as f64),
+            Equal
+        );
+        assert_eq!(
+            Duration::new(1 << 60, 1).cmp_seconds_f64((1_u64 << 60) as f64),
+            Greater
+        );
+        // Fractional seconds are compared exactly as well. The `f64` nearest
+        // to 0.1 is slightly greater than 0.1, and the one nearest to 0.3 is
+        // slightly less than 0.3.
+        assert_eq!(100.milliseconds().cmp_seconds_f64(0.1), Less);
+        assert_eq!((-100).milliseconds().cmp_seconds_f64(-0.1), Greater);
+        assert_eq!(300.milliseconds().cmp_seconds_f64(0.3), Greater);
+        assert_eq!((-300).milliseconds().cmp_seconds_f64(-0.3), Less);
+        assert_eq!(1.nanoseconds().cmp_seconds_f64(1e-9), Less);
+        assert_eq!(0.seconds().cmp_seconds_f64(core::f64::MIN_POSITIVE), Less);
+        assert_eq!(
+            0.seconds().cmp_seconds_f64(-core::f64::MIN_POSITIVE),
+            Greater
+        );
+        assert_eq!(0.seconds().cmp_seconds_f64(4.9e-324), Less);
+        assert_eq!(0.seconds().cmp_seconds_f64(-4.9e-324), Greater);
+        assert_eq!(1.5.seconds().cmp_seconds_f64(1.5), Equal);
+        assert_eq!((-1.5).seconds().cmp_seconds_f64(-1.5), Equal);
+    }
+
+    #[test]
+    fn f64_partial_ord() {
+        assert!(0.5 < 600.milliseconds());
+        assert!(0.5 > 400.milliseconds());
+        assert!(-0.5 > (-600).milliseconds());
+        assert_eq!(0.5.partial_cmp(&500.milliseconds()), Some(Equal));
+        assert_eq!(
+            core::f64::NEG_INFINITY.partial_cmp(&1.seconds()),
+            Some(Less)
+        );
+        assert_eq!(core::f64::NAN.partial_cmp(&0.seconds()), None);
     }
 
     #[test]
@@ -0,0 +1,159 @@
+use crate::internal_prelude::*;
+use core::cmp::{max, min};
+
+/// A half-open interval of [`Duration`]s, including `start` and excluding
+/// `end`.
+///
+/// This behaves in the same manner as [`core::ops::Range`], but provides
+/// additional methods for comparing intervals. A range whose `end` is not
+/// greater than its `start` is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationRange {
+    /// The lower bound of the range (inclusive).
+    pub start: Duration,
+    /// The upper bound of the range (exclusive).
+    pub end: Duration,
+}
+
+impl DurationRange {
+    /// Create a new `DurationRange` from the provided bounds.
+    ///
+    /// ```rust
+    /// # use time::{DurationRange, prelude::*};
+    /// let range = DurationRange::new(1.seconds(), 5.seconds());
+    /// assert_eq!(range.start, 1.seconds());
+    /// assert_eq!(range.end, 5.seconds());
+    /// ```
+    #[inline(always)]
+    pub const fn new(start: Duration, end: Duration) -> Self {
+        Self { start, end }
+    }
+
+    /// Check if the range contains no values.
+    ///
+    /// ```rust
+    /// # use time::{DurationRange, prelude::*};
+    /// assert!(!DurationRange::new(1.seconds(), 5.seconds()).is_empty());
+    /// assert!(DurationRange::new(1.seconds(), 1.seconds()).is_empty());
+    /// assert!(DurationRange::new(5.seconds(), 1.seconds()).is_empty());
+    /// ```
+    #[inline(always)]
+    pub fn is_empty(self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Check if the provided `Duration` is contained within the range.
+    ///
+    /// ```rust
+    /// # use time::{DurationRange, prelude::*};
+    /// let range = DurationRange::new(1.seconds(), 5.seconds());
+    /// assert!(range.contains(1.seconds()));
+    /// assert!(range.contains(3.seconds()));
+    /// assert!(!range.contains(5.seconds()));
+    /// ```
+    #[inline(always)]
+    pub fn contains(self, duration: Duration) -> bool {
+        self.start <= duration && duration < self.end
+    }
+
+    /// Check if any `Duration` is contained within both ranges. Ranges that
+    /// only touch at their bounds do not overlap.
+    ///
+    /// ```rust
+    /// # use time::{DurationRange, prelude::*};
+    /// let range = DurationRange::new(1.seconds(), 5.seconds());
+    /// assert!(range.overlaps(DurationRange::new(4.seconds(), 6.seconds())));
+    /// assert!(!range.overlaps(DurationRange::new(5.seconds(), 6.seconds())));
+    /// ```
+    #[inline(always)]
+    pub fn overlaps(self, other: Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Obtain the range of `Duration`s contained within both ranges, returning
+    /// `None` if there are none.
+    ///
+    /// ```rust
+    /// # use time::{DurationRange, prelude::*};
+    /// let range = DurationRange::new(1.seconds(), 5.seconds());
+    /// assert_eq!(
+    ///     range.intersection(DurationRange::new(4.seconds(), 6.seconds())),
+    ///     Some(DurationRange::new(4.seconds(), 5.seconds()))
+    /// );
+    /// assert_eq!(range.intersection(DurationRange::new(5.seconds(), 6.seconds())), None);
+    /// ```
+    #[inline]
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let intersection = Self::new(max(self.start, other.start), min(self.end, other.end));
+
+        if intersection.is_empty() {
+            None
+        } else {
+            Some(intersection)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn range(start: i64, end: i64) -> DurationRange {
+        DurationRange::new(start.seconds(), end.seconds())
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!range(1, 5).is_empty());
+        assert!(!range(-5, -1).is_empty());
+        assert!(range(1, 1).is_empty());
+        assert!(range(5, 1).is_empty());
+    }
+
+    #[test]
+    fn contains() {
+        assert!(range(1, 5).contains(1.seconds()));
+        assert!(range(1, 5).contains(4_999.milliseconds()));
+        assert!(!range(1, 5).contains(5.seconds()));
+        assert!(!range(1, 5).contains(999.milliseconds()));
+        assert!(range(-5, 0).contains((-1).nanoseconds()));
+        assert!(!range(1, 1).contains(1.seconds()));
+    }
+
+    #[test]
+    fn overlaps() {
+        // disjoint
+        assert!(!range(1, 2).overlaps(range(3, 4)));
+        assert!(!range(3, 4).overlaps(range(1, 2)));
+        // touching
+        assert!(!range(1, 2).overlaps(range(2, 3)));
+        assert!(!range(2, 3).overlaps(range(1, 2)));
+        // partially overlapping
+        assert!(range(1, 3).overlaps(range(2, 4)));
+        assert!(range(2, 4).overlaps(range(1, 3)));
+        // nested
+        assert!(range(1, 10).overlaps(range(2, 3)));
+        assert!(range(2, 3).overlaps(range(1, 10)));
+        // empty
+        assert!(!range(1, 10).overlaps(range(2, 2)));
+    }
+
+    #[test]
+    fn intersection() {
+        // disjoint
+        assert_eq!(range(1, 2).intersection(range(3, 4)), None);
+        // touching
+        assert_eq!(range(1, 2).intersection(range(2, 3)), None);
+        // partially overlapping
+        assert_eq!(range(1, 3).intersection(range(2, 4)), Some(range(2, 3)));
+        assert_eq!(
+            range(-4, -2).intersection(range(-3, 0)),
+            Some(range(-3, -2))
+        );
+        // nested
+        assert_eq!(range(1, 10).intersection(range(2, 3)), Some(range(2, 3)));
+        assert_eq!(range(2, 3).intersection(range(1, 10)), Some(range(2, 3)));
+        // identical
+        assert_eq!(range(1, 2).intersection(range(1, 2)), Some(range(1, 2)));
+    }
+}
@@ -15,6 +15,8 @@ pub enum Error {
     ComponentRange(Box<ComponentRangeError>),
     Parse(ParseError),
     IndeterminateOffset(IndeterminateOffsetError),
+    NegativeDuration(NegativeDurationError),
+    DurationParse(DurationParseError),
     #[cfg(not(supports_non_exhaustive))]
     #[doc(hidden)]
     __NonExhaustive,
@@ -28,6 +30,8 @@ impl fmt::Display for Error {
             Error::ComponentRange(e) => e.fmt(f),
             Error::Parse(e) => e.fmt(f),
             Error::IndeterminateOffset(e) => e.fmt(f),
+            Error::NegativeDuration(e) => e.fmt(f),
+            Error::DurationParse(e) => e.fmt(f),
             #[cfg(not(supports_non_exhaustive))]
             Error::__NonExhaustive => unreachable!(),
         }
@@ -43,6 +47,8 @@ impl std::error::Error for Error {
             Error::ComponentRange(box_err) => Some(box_err.as_ref()),
             Error::Parse(err) => Some(err),
             Error::IndeterminateOffset(err) => Some(err),
+            Error::NegativeDuration(err) => Some(err),
+            Error::DurationParse(err) => Some(err),
             #[cfg(not(supports_non_exhaustive))]
             Error::__NonExhaustive => unreachable!(),
         }
@@ -171,3 +177,130 @@ impl From<IndeterminateOffsetError> for Error {
         Error::IndeterminateOffset(original)
     }
 }
+
+/// An error type indicating that a conversion failed because the source
+/// [`Duration`] was negative, and the target type cannot represent negative
+/// values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeDurationError {
+    #[allow(clippy::missing_docs_in_private_items)]
+    __non_exhaustive: (),
+}
+
+impl NegativeDurationError {
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const fn new() -> Self {
+        Self {
+            __non_exhaustive: (),
+        }
+    }
+}
+
+impl fmt::Display for NegativeDurationError {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Source duration is negative")
+    }
+}
+
+#[cfg(std)]
+impl std::error::Error for NegativeDurationError {}
+
+impl From<NegativeDurationError> for Error {
+    #[inline(always)]
+    fn from(original: NegativeDurationError) -> Self {
+        Error::NegativeDuration(original)
+    }
+}
+
+/// An error type indicating that a [`Duration`] could not be parsed by
+/// [`Duration::from_ascii_strict`].
+///
+/// Each variant carries the index of the byte in the input at which the error
+/// was detected. No allocation is performed when creating or displaying this
+/// error.
+#[cfg_attr(supports_non_exhaustive, non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationParseError {
+    /// A digit was expected, but either a different byte was present or the
+    /// input ended.
+    InvalidDigit {
+        /// The index of the byte that was expected to be a digit.
+        position: usize,
+    },
+    /// A number was not followed by a recognized unit.
+    UnknownUnit {
+        /// The index of the byte at which a unit was expected.
+        position: usize,
+    },
+    /// A unit was not smaller than the unit preceding it.
+    UnitOutOfOrder {
+        /// The index of the first byte of the unit.
+        position: usize,
+    },
+    /// A component caused the duration to exceed the range of [`Duration`].
+    Overflow {
+        /// The index of the first digit of the component.
+        position: usize,
+    },
+    #[cfg(not(supports_non_exhaustive))]
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+impl DurationParseError {
+    /// Get the index of the byte in the input at which the error was detected.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// let error = Duration::from_ascii_strict(b"1h30x").unwrap_err();
+    /// assert_eq!(error.position(), 4);
+    /// ```
+    #[inline]
+    pub fn position(self) -> usize {
+        match self {
+            DurationParseError::InvalidDigit { position }
+            | DurationParseError::UnknownUnit { position }
+            | DurationParseError::UnitOutOfOrder { position }
+            | DurationParseError::Overflow { position } => position,
+            #[cfg(not(supports_non_exhaustive))]
+            DurationParseError::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for DurationParseError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationParseError::InvalidDigit { position } => {
+                write!(f, "expected a digit at byte {}", position)
+            }
+            DurationParseError::UnknownUnit { position } => {
+                write!(f, "expected a unit at byte {}", position)
+            }
+            DurationParseError::UnitOutOfOrder { position } => write!(
+                f,
+                "unit at byte {} is not smaller than the preceding unit",
+                position
+            ),
+            DurationParseError::Overflow { position } => write!(
+                f,
+                "component at byte {} is out of range for a duration",
+                position
+            ),
+            #[cfg(not(supports_non_exhaustive))]
+            DurationParseError::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+#[cfg(std)]
+impl std::error::Error for DurationParseError {}
+
+impl From<DurationParseError> for Error {
+    #[inline(always)]
+    fn from(original: DurationParseError) -> Self {
+        Error::DurationParse(original)
+    }
+}
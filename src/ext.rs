@@ -0,0 +1,155 @@
+use crate::Duration;
+use core::time::Duration as StdDuration;
+
+/// Additional methods for `std::time::Duration` that operate on signed
+/// [`Duration`]s.
+///
+/// This trait can be imported with `use time::prelude::*`.
+pub trait StdDurationExt {
+    /// Computes `self - rhs`, where `rhs` may be negative. The result is
+    /// clamped to zero rather than becoming negative, and to the maximum
+    /// representable value on overflow.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(5.std_seconds().saturating_sub_signed(2.seconds()), 3.std_seconds());
+    /// assert_eq!(5.std_seconds().saturating_sub_signed((-2).seconds()), 7.std_seconds());
+    /// assert_eq!(5.std_seconds().saturating_sub_signed(10.seconds()), 0.std_seconds());
+    /// ```
+    fn saturating_sub_signed(self, rhs: Duration) -> StdDuration;
+}
+
+impl StdDurationExt for StdDuration {
+    #[inline]
+    fn saturating_sub_signed(self, rhs: Duration) -> StdDuration {
+        // Widen before taking the absolute value, as `i64::min_value()` has no
+        // positive counterpart.
+        let magnitude = Self::new(
+            (rhs.whole_seconds() as i128).abs() as u64,
+            rhs.subsec_nanoseconds().abs() as u32,
+        );
+
+        if rhs.is_negative() {
+            self.checked_add(magnitude)
+                .unwrap_or_else(|| Self::new(u64::max_value(), 999_999_999))
+        } else {
+            self.checked_sub(magnitude)
+                .unwrap_or_else(|| Self::from_secs(0))
+        }
+    }
+}
+
+/// Access to the number of fractional seconds in a duration, using the method
+/// names of `std::time::Duration`. This allows code to be written generically
+/// over both [`Duration`] and `std::time::Duration`.
+///
+/// ```rust
+/// # use time::{FloatSeconds, prelude::*};
+/// fn total_seconds<T: FloatSeconds>(durations: &[T]) -> f64 {
+///     durations.iter().map(FloatSeconds::as_secs_f64).sum()
+/// }
+///
+/// assert_eq!(total_seconds(&[1.seconds(), (-0.5).seconds()]), 0.5);
+/// assert_eq!(total_seconds(&[1.std_seconds(), 0.5.std_seconds()]), 1.5);
+/// ```
+pub trait FloatSeconds {
+    /// Get the number of fractional seconds in the duration as an `f64`.
+    fn as_secs_f64(&self) -> f64;
+
+    /// Get the number of fractional seconds in the duration as an `f32`.
+    fn as_secs_f32(&self) -> f32;
+}
+
+impl FloatSeconds for Duration {
+    #[inline(always)]
+    fn as_secs_f64(&self) -> f64 {
+        self.as_seconds_f64()
+    }
+
+    #[inline(always)]
+    fn as_secs_f32(&self) -> f32 {
+        self.as_seconds_f32()
+    }
+}
+
+impl FloatSeconds for StdDuration {
+    // The inherent methods of the same name were not stabilized until Rust
+    // 1.38, so they are implemented manually.
+    #[inline(always)]
+    fn as_secs_f64(&self) -> f64 {
+        self.as_secs() as f64 + self.subsec_nanos() as f64 / 1_000_000_000.
+    }
+
+    #[inline(always)]
+    fn as_secs_f32(&self) -> f32 {
+        self.as_secs() as f32 + self.subsec_nanos() as f32 / 1_000_000_000.
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn saturating_sub_signed() {
+        assert_eq!(
+            5.std_seconds().saturating_sub_signed(2.seconds()),
+            3.std_seconds()
+        );
+        assert_eq!(
+            5.std_seconds().saturating_sub_signed((-2).seconds()),
+            7.std_seconds()
+        );
+        assert_eq!(
+            1.std_seconds().saturating_sub_signed(1_500.milliseconds()),
+            0.std_seconds()
+        );
+        assert_eq!(
+            5.std_seconds().saturating_sub_signed(10.seconds()),
+            0.std_seconds()
+        );
+        assert_eq!(
+            0.std_seconds().saturating_sub_signed(Duration::max_value()),
+            0.std_seconds()
+        );
+        assert_eq!(
+            StdDuration::new(u64::max_value(), 0).saturating_sub_signed((-1).seconds()),
+            StdDuration::new(u64::max_value(), 999_999_999)
+        );
+        assert_eq!(
+            0.std_seconds().saturating_sub_signed(Duration::min_value()),
+            StdDuration::new(1 << 63, 999_999_999)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_seconds() {
+        for &duration in &[
+            0.seconds(),
+            1.5.seconds(),
+            (-1.5).seconds(),
+            Duration::max_value(),
+        ] {
+            assert_eq!(
+                FloatSeconds::as_secs_f64(&duration),
+                duration.as_seconds_f64()
+            );
+            assert_eq!(
+                FloatSeconds::as_secs_f32(&duration),
+                duration.as_seconds_f32()
+            );
+        }
+
+        for &duration in &[
+            0.std_seconds(),
+            1.5.std_seconds(),
+            1.std_nanoseconds(),
+            StdDuration::new(u64::max_value(), 999_999_999),
+        ] {
+            assert_eq!(FloatSeconds::as_secs_f64(&duration), duration.as_secs_f64());
+            assert_eq!(FloatSeconds::as_secs_f32(&duration), duration.as_secs_f32());
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Formatting a `Duration` from a structured description.
+
+use crate::internal_prelude::*;
+use core::fmt::{self, Write};
+
+/// A single component of a structured [`Duration`] format, as accepted by
+/// [`Duration::format`].
+///
+/// Each numeric component is the magnitude of the duration, with the sign
+/// only written by [`DurationFormatItem::Sign`].
+///
+/// ```rust
+/// # use time::{DurationFormatItem::*, prelude::*};
+/// let timecode = [
+///     Sign,
+///     Hours,
+///     Literal(":"),
+///     Minutes,
+///     Literal(":"),
+///     Seconds,
+///     Literal("."),
+///     Subsecond(3),
+/// ];
+/// assert_eq!((-1.5).hours().format(&timecode), "-01:30:00.000");
+/// ```
+#[allow(variant_size_differences)]
+#[cfg_attr(supports_non_exhaustive, non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationFormatItem<'a> {
+    /// A value that should be printed as-is.
+    Literal(&'a str),
+    /// `-` if the duration is negative, nothing otherwise.
+    Sign,
+    /// The number of whole hours, padded with zeros to at least two digits.
+    /// Hours are not wrapped into days.
+    Hours,
+    /// The number of whole minutes past the hour (`00`-`59`).
+    Minutes,
+    /// The number of whole seconds past the minute (`00`-`59`).
+    Seconds,
+    /// The fractional second, truncated to the provided number of digits. A
+    /// value of zero writes nothing, and values above nine are treated as
+    /// nine.
+    Subsecond(u8),
+    #[cfg(not(supports_non_exhaustive))]
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+/// Write the duration using the provided items.
+#[inline]
+pub(crate) fn format_duration(
+    f: &mut impl Write,
+    duration: Duration,
+    items: &[DurationFormatItem<'_>],
+) -> fmt::Result {
+    use DurationFormatItem::*;
+
+    for item in items {
+        match *item {
+            Literal(literal) => f.write_str(literal)?,
+            Sign => {
+                if duration.is_negative() {
+                    f.write_char('-')?;
+                }
+            }
+            Hours => write!(f, "{:02}", duration.whole_hours().abs())?,
+            Minutes => write!(f, "{:02}", (duration.whole_minutes() % 60).abs())?,
+            Seconds => write!(f, "{:02}", (duration.whole_seconds() % 60).abs())?,
+            Subsecond(0) => {}
+            Subsecond(digits) => {
+                let digits = digits.min(9) as u32;
+                write!(
+                    f,
+                    "{:0width$}",
+                    duration.subsec_nanoseconds_abs() / 10_u32.pow(9 - digits),
+                    width = digits as usize
+                )?;
+            }
+            #[cfg(not(supports_non_exhaustive))]
+            __NonExhaustive => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
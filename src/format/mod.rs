@@ -13,6 +13,7 @@ macro_rules! pad {
 
 pub(crate) mod date;
 pub(crate) mod deferred_format;
+pub(crate) mod duration;
 #[allow(clippy::module_inception)]
 pub(crate) mod format;
 pub(crate) mod offset;
@@ -25,8 +26,12 @@ use crate::internal_prelude::*;
 use core::fmt::{self, Formatter};
 pub(crate) use deferred_format::DeferredFormat;
 #[allow(unreachable_pub)] // rust-lang/rust#64762
+pub use duration::DurationFormatItem;
+#[allow(unreachable_pub)] // rust-lang/rust#64762
 pub use format::Format;
 #[allow(unreachable_pub)] // rust-lang/rust#64762
+pub use offset::{write_offset, OffsetFormatOptions};
+#[allow(unreachable_pub)] // rust-lang/rust#64762
 pub use parse::ParseError;
 pub(crate) use parse::{parse, ParseResult, ParsedItems};
 pub(crate) use parse_items::{parse_fmt_string, try_parse_fmt_string};
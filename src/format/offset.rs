@@ -2,39 +2,347 @@
 
 #![allow(non_snake_case)]
 
-use super::{
-    parse::{try_consume_exact_digits_in_range, try_consume_first_match},
-    Padding, ParsedItems,
-};
+use super::ParsedItems;
 use crate::internal_prelude::*;
-use core::fmt::{self, Formatter};
+use core::fmt::{self, Formatter, Write};
+
+/// Options controlling how [`write_offset`] renders a [`UtcOffset`].
+///
+/// The default options render an offset as `±HHMM`, identical to the `%z`
+/// specifier.
+///
+/// ```rust
+/// # use time::OffsetFormatOptions;
+/// let options = OffsetFormatOptions {
+///     separator: Some(':'),
+///     ..Default::default()
+/// };
+/// # let _ = options;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OffsetFormatOptions {
+    /// The character placed between the hours, minutes, and seconds. `None`
+    /// places the components immediately adjacent to one another.
+    pub separator: Option<char>,
+    /// Whether the seconds component is written.
+    pub include_seconds: bool,
+    /// Whether an offset of exactly zero is written as `Z` rather than with a
+    /// sign and zero-valued components.
+    pub zero_as_z: bool,
+}
+
+/// Write the provided [`UtcOffset`] to `f`, rendered according to `options`.
+///
+/// The sign is always present, and each component is zero-padded to two
+/// digits. An offset is negative if it is west of UTC, even if only by
+/// seconds that are not written. Each component is the remainder of the total
+/// number of seconds in the offset, so writing the seconds never changes the
+/// hours or minutes.
+///
+/// ```rust
+/// # use time::{write_offset, OffsetFormatOptions, UtcOffset};
+/// # fn main() -> core::fmt::Result {
+/// let mut s = String::new();
+/// let options = OffsetFormatOptions {
+///     separator: Some(':'),
+///     ..Default::default()
+/// };
+/// write_offset(&mut s, UtcOffset::minutes(330), options)?;
+/// assert_eq!(s, "+05:30");
+///
+/// let mut s = String::new();
+/// let options = OffsetFormatOptions {
+///     separator: Some(':'),
+///     include_seconds: true,
+///     ..Default::default()
+/// };
+/// write_offset(&mut s, UtcOffset::seconds(1_172), options)?;
+/// assert_eq!(s, "+00:19:32");
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn write_offset(
+    f: &mut impl Write,
+    offset: UtcOffset,
+    options: OffsetFormatOptions,
+) -> fmt::Result {
+    if options.zero_as_z && offset == UtcOffset::UTC {
+        return f.write_char('Z');
+    }
+
+    let total_seconds = offset.as_seconds().abs();
+
+    f.write_char(if offset.as_seconds() < 0 { '-' } else { '+' })?;
+    write!(f, "{:02}", total_seconds / 3_600)?;
+    if let Some(separator) = options.separator {
+        f.write_char(separator)?;
+    }
+    write!(f, "{:02}", total_seconds / 60 % 60)?;
+
+    if options.include_seconds {
+        if let Some(separator) = options.separator {
+            f.write_char(separator)?;
+        }
+        write!(f, "{:02}", total_seconds % 60)?;
+    }
+
+    Ok(())
+}
 
 /// UTC offset
+///
+/// Any seconds are not written, matching `strftime`. Use [`write_offset`] with
+/// `include_seconds` to write them.
 #[inline(always)]
 pub(crate) fn fmt_z(f: &mut Formatter<'_>, offset: UtcOffset) -> fmt::Result {
-    let offset = offset.as_duration();
-
-    write!(
-        f,
-        "{}{:02}{:02}",
-        if offset.is_negative() { '-' } else { '+' },
-        offset.whole_hours().abs(),
-        (offset.whole_minutes() - 60 * offset.whole_hours()).abs()
-    )
+    write_offset(f, offset, OffsetFormatOptions::default())
 }
 
 /// UTC offset
+///
+/// Only `±HHMM` is accepted, though the sign may also be `−` (U+2212 MINUS
+/// SIGN) or `＋` (U+FF0B FULLWIDTH PLUS SIGN). Only the offset itself is
+/// consumed, with the remainder left in `s` for the caller. On error, `s` is
+/// left untouched.
 #[inline(always)]
 pub(crate) fn parse_z(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
-    let sign = try_consume_first_match(s, [("+", 1), ("-", -1)].iter().cloned())
-        .ok_or(ParseError::InvalidOffset)?;
+    let (offset, consumed) = parse_signed_offset(s.as_bytes(), false)?;
+    // Non-ASCII signs are consumed whole, so this is always a valid char
+    // boundary.
+    *s = &s[consumed..];
+    items.offset = Some(offset);
+    Ok(())
+}
+
+/// Optional UTC offset
+///
+/// Accepts the same forms as [`parse_offset_prefix`]. Succeeds without
+/// consuming anything if `s` does not begin with an offset, leaving
+/// `items.offset` unchanged. If `s` begins with a sign or `Z` that is not
+/// followed by a valid offset, an error is returned and `s` is left
+/// untouched.
+#[inline]
+pub(crate) fn parse_optional_offset(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    match s.chars().next() {
+        Some('+') | Some('-') | Some('Z') | Some('z') | Some('\u{2212}') | Some('\u{ff0b}') => {
+            let (offset, consumed) = parse_offset_prefix(s.as_bytes())?;
+            *s = &s[consumed..];
+            items.offset = Some(offset);
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Parse a UTC offset from the start of `bytes`, returning the offset and the
+/// number of bytes consumed. Anything after the offset is not examined.
+///
+/// `Z` (either case), `±HHMM`, and `±HH:MM` are accepted. In addition to the
+/// ASCII signs, `−` (U+2212 MINUS SIGN) and `＋` (U+FF0B FULLWIDTH PLUS SIGN)
+/// are recognized, as they are common in human-entered offsets.
+#[inline]
+pub(crate) fn parse_offset_prefix(bytes: &[u8]) -> ParseResult<(UtcOffset, usize)> {
+    match bytes.first() {
+        Some(c) if c.eq_ignore_ascii_case(&b'Z') => Ok((UtcOffset::UTC, 1)),
+        _ => parse_signed_offset(bytes, true),
+    }
+}
+
+/// Parse a signed offset of the form `±HHMM` from the start of `bytes`,
+/// returning the offset and the number of bytes consumed. If `allow_colon` is
+/// set, the hours and minutes may also be separated by a colon.
+#[inline]
+fn parse_signed_offset(bytes: &[u8], allow_colon: bool) -> ParseResult<(UtcOffset, usize)> {
+    /// U+2212 MINUS SIGN, encoded as UTF-8.
+    const MINUS_SIGN: &[u8] = b"\xe2\x88\x92";
+    /// U+FF0B FULLWIDTH PLUS SIGN, encoded as UTF-8.
+    const FULLWIDTH_PLUS_SIGN: &[u8] = b"\xef\xbc\x8b";
+
+    let (sign, mut consumed) = match bytes.first() {
+        Some(b'+') => (1, 1),
+        Some(b'-') => (-1, 1),
+        _ if bytes.starts_with(MINUS_SIGN) => (-1, MINUS_SIGN.len()),
+        _ if bytes.starts_with(FULLWIDTH_PLUS_SIGN) => (1, FULLWIDTH_PLUS_SIGN.len()),
+        _ => return Err(ParseError::InvalidOffset),
+    };
 
-    let hours: i16 = try_consume_exact_digits_in_range(s, 2, 0..24, Padding::Zero)
+    let hours = consume_two_digits(bytes, &mut consumed)
+        .filter(|&hours| hours < 24)
         .ok_or(ParseError::InvalidOffset)?;
 
-    let minutes = try_consume_exact_digits_in_range(s, 2, 0..60, Padding::Zero)
+    if allow_colon && bytes.get(consumed) == Some(&b':') {
+        consumed += 1;
+    }
+
+    let minutes = consume_two_digits(bytes, &mut consumed)
+        .filter(|&minutes| minutes < 60)
         .ok_or(ParseError::InvalidOffset)?;
 
-    items.offset = UtcOffset::minutes(sign * (hours * 60 + minutes)).into();
-    Ok(())
+    Ok((
+        UtcOffset::minutes(sign * (hours as i16 * 60 + minutes as i16)),
+        consumed,
+    ))
+}
+
+/// Consume exactly two ASCII digits starting at `*index`, advancing the index
+/// past them.
+#[inline(always)]
+fn consume_two_digits(bytes: &[u8], index: &mut usize) -> Option<u8> {
+    match bytes.get(*index..*index + 2) {
+        Some(&[tens, ones]) if tens.is_ascii_digit() && ones.is_ascii_digit() => {
+            *index += 2;
+            Some((tens - b'0') * 10 + (ones - b'0'))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_z_remainder() {
+        let mut items = ParsedItems::new();
+        let mut s = "+0000[Europe/London]";
+        assert_eq!(parse_z(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::UTC));
+        assert_eq!(s, "[Europe/London]");
+
+        let mut items = ParsedItems::new();
+        let mut s = "-0530:00";
+        assert_eq!(parse_z(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::minutes(-330)));
+        assert_eq!(s, ":00");
+
+        let mut items = ParsedItems::new();
+        let mut s = "+053012";
+        assert_eq!(parse_z(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::minutes(330)));
+        assert_eq!(s, "12");
+
+        let mut items = ParsedItems::new();
+        let mut s = "+0200é";
+        assert_eq!(parse_z(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::hours(2)));
+        assert_eq!(s, "é");
+
+        let mut items = ParsedItems::new();
+        let mut s = "+053x";
+        assert_eq!(parse_z(&mut items, &mut s), Err(ParseError::InvalidOffset));
+        assert_eq!(items.offset, None);
+        assert_eq!(s, "+053x");
+    }
+
+    #[test]
+    fn parse_z_strict() {
+        for &input in &["Z", "z", "+05:30", "-00:00", "+5:30", "0530"] {
+            let mut items = ParsedItems::new();
+            let mut s = input;
+            assert_eq!(
+                parse_z(&mut items, &mut s),
+                Err(ParseError::InvalidOffset),
+                "{}",
+                input
+            );
+            assert_eq!(items.offset, None);
+            assert_eq!(s, input);
+        }
+    }
+
+    #[test]
+    fn parse_offset_prefix_lenient() {
+        assert_eq!(
+            parse_offset_prefix(b"+00:00[Europe/London]"),
+            Ok((UtcOffset::UTC, 6))
+        );
+        assert_eq!(
+            parse_offset_prefix(b"-0530:00"),
+            Ok((UtcOffset::minutes(-330), 5))
+        );
+        assert_eq!(parse_offset_prefix(b"Z+01:00"), Ok((UtcOffset::UTC, 1)));
+        assert_eq!(parse_offset_prefix(b"z"), Ok((UtcOffset::UTC, 1)));
+        assert_eq!(
+            parse_offset_prefix("+02:00é".as_bytes()),
+            Ok((UtcOffset::hours(2), 6))
+        );
+        assert_eq!(
+            parse_offset_prefix("\u{2212}05:30".as_bytes()),
+            Ok((UtcOffset::minutes(-330), 8))
+        );
+        assert_eq!(
+            parse_offset_prefix(b"+05:3x"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            parse_offset_prefix(b"+05::30"),
+            Err(ParseError::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn parse_optional_offset_present() {
+        let mut items = ParsedItems::new();
+        let mut s = "Z";
+        assert_eq!(parse_optional_offset(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::UTC));
+        assert_eq!(s, "");
+
+        let mut items = ParsedItems::new();
+        let mut s = "-05:30 rest";
+        assert_eq!(parse_optional_offset(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::minutes(-330)));
+        assert_eq!(s, " rest");
+
+        let mut items = ParsedItems::new();
+        let mut s = "\u{2212}0200";
+        assert_eq!(parse_optional_offset(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::hours(-2)));
+        assert_eq!(s, "");
+
+        let mut items = ParsedItems::new();
+        let mut s = "+05:3x";
+        assert_eq!(
+            parse_optional_offset(&mut items, &mut s),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(items.offset, None);
+        assert_eq!(s, "+05:3x");
+    }
+
+    #[test]
+    fn parse_optional_offset_absent() {
+        for &input in &["", " ", "[Europe/London]", "05:30", "UTC"] {
+            let mut items = ParsedItems::new();
+            let mut s = input;
+            assert_eq!(parse_optional_offset(&mut items, &mut s), Ok(()));
+            assert_eq!(items.offset, None);
+            assert_eq!(s, input);
+        }
+    }
+
+    #[test]
+    fn parse_z_unicode_sign() {
+        let mut items = ParsedItems::new();
+        let mut s = "\u{2212}0530";
+        assert_eq!(parse_z(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::minutes(-330)));
+        assert_eq!(s, "");
+
+        let mut items = ParsedItems::new();
+        let mut s = "\u{ff0b}0530 UTC";
+        assert_eq!(parse_z(&mut items, &mut s), Ok(()));
+        assert_eq!(items.offset, Some(UtcOffset::minutes(330)));
+        assert_eq!(s, " UTC");
+
+        let mut items = ParsedItems::new();
+        let mut s = "\u{2212}530";
+        assert_eq!(parse_z(&mut items, &mut s), Err(ParseError::InvalidOffset));
+        assert_eq!(s, "\u{2212}530");
+
+        let mut items = ParsedItems::new();
+        let mut s = "\u{2213}0530";
+        assert_eq!(parse_z(&mut items, &mut s), Err(ParseError::InvalidOffset));
+    }
 }
@@ -43,6 +43,8 @@ pub enum ParseError {
     InvalidDayOfYear,
     /// The UTC offset present was not valid.
     InvalidOffset,
+    /// The duration present was not valid.
+    InvalidDuration,
     /// There was no character following a `%`.
     MissingFormatSpecifier,
     /// The character following `%` is not valid.
@@ -89,6 +91,7 @@ impl Display for ParseError {
             InvalidDayOfMonth => f.write_str("invalid day of month"),
             InvalidDayOfYear => f.write_str("invalid day of year"),
             InvalidOffset => f.write_str("invalid offset"),
+            InvalidDuration => f.write_str("invalid duration"),
             MissingFormatSpecifier => f.write_str("missing format specifier after `%`"),
             InvalidFormatSpecifier(c) => write!(f, "invalid format specifier `{}` after `%`", c),
             UnexpectedCharacter { expected, actual } => {
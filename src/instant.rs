@@ -4,6 +4,7 @@ use core::{
     ops::{Add, AddAssign, Sub, SubAssign},
     time::Duration as StdDuration,
 };
+use lazy_static::lazy_static;
 use std::time::Instant as StdInstant;
 
 /// A measurement of a monotonically non-decreasing clock. Opaque and useful
@@ -25,7 +26,9 @@ use std::time::Instant as StdInstant;
 /// instants).
 ///
 /// This implementation allows for operations with signed [`Duration`]s, but is
-/// otherwise identical to [`std::time::Instant`].
+/// otherwise identical to [`std::time::Instant`]. When using rustc >= 1.34.0,
+/// adding or subtracting a [`Duration`] saturates to the nearest representable
+/// instant rather than panicking; see [`Instant::saturating_add`].
 #[cfg_attr(docs, doc(cfg(feature = "std")))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Instant {
@@ -33,6 +36,19 @@ pub struct Instant {
     inner: StdInstant,
 }
 
+lazy_static! {
+    /// The reference instant of the process. This is not the instant the
+    /// process started, but rather the first time the reference is requested.
+    static ref REFERENCE: StdInstant = StdInstant::now();
+}
+
+/// The reference instant of the process, captured the first time this is
+/// called.
+#[inline]
+pub(crate) fn reference_instant() -> StdInstant {
+    *REFERENCE
+}
+
 impl Instant {
     /// Returns an `Instant` corresponding to "now".
     ///
@@ -63,6 +79,30 @@ impl Instant {
         Self::now() - self
     }
 
+    /// Returns the amount of time elapsed since the reference instant of the
+    /// process.
+    ///
+    /// Despite the name, the reference is not the instant the process
+    /// started. It is captured lazily, the first time this method is called
+    /// (or an `Instant` is serialized with `time::serde::instant`), so the
+    /// first call always returns a duration that is zero or very close to it.
+    /// Successive calls are monotonically non-decreasing, making this suitable
+    /// for cheap relative timestamps that do not depend on the system clock.
+    ///
+    /// ```rust
+    /// # use time::Instant;
+    /// let first = Instant::duration_since_start();
+    /// let second = Instant::duration_since_start();
+    /// assert!(second >= first);
+    /// ```
+    #[inline(always)]
+    pub fn duration_since_start() -> Duration {
+        // The reference must be captured before the current instant, lest the
+        // first call return a negative duration.
+        let start = reference_instant();
+        Self::now() - start
+    }
+
     /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be
     /// represented as `Instant` (which means it's inside the bounds of the
     /// underlying data structure), `None` otherwise.
@@ -117,6 +157,86 @@ impl Instant {
     pub fn checked_sub(self, duration: Duration) -> Option<Self> {
         self.checked_add(-duration)
     }
+
+    /// Computes `self + duration`, saturating to the nearest representable
+    /// `Instant` if the result would overflow the underlying data structure.
+    ///
+    /// ```rust
+    /// # use time::{Instant, Duration, prelude::*};
+    /// let now = Instant::now();
+    /// assert_eq!(now.saturating_add(5.seconds()), now + 5.seconds());
+    /// assert_eq!(now.saturating_add((-5).seconds()), now - 5.seconds());
+    /// assert!(now.saturating_add(Duration::max_value()) > now);
+    /// ```
+    ///
+    /// If the result is representable, this costs a single `checked_add` or
+    /// `checked_sub` on the underlying [`std::time::Instant`]. Otherwise the
+    /// nearest representable instant is found by binary search over the
+    /// magnitude of `duration`, which is less than 2<sup>93</sup> nanoseconds,
+    /// taking at most 95 such calls in total.
+    ///
+    /// This function is only present when using rustc >= 1.34.0.
+    #[inline]
+    #[cfg(instant_checked_ops)]
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        if let Some(instant) = self.checked_add(duration) {
+            return instant;
+        }
+
+        let shift: fn(&StdInstant, StdDuration) -> Option<StdInstant> = if duration.is_positive() {
+            StdInstant::checked_add
+        } else {
+            StdInstant::checked_sub
+        };
+
+        // Binary search for the largest magnitude that can still be applied.
+        // `low` is always representable and `high` never is.
+        let mut low = 0;
+        let mut high = duration.whole_nanoseconds().abs() as u128;
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if shift(&self.inner, std_duration_from_nanos(mid)).is_some() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        shift(&self.inner, std_duration_from_nanos(low)).map_or(self, From::from)
+    }
+
+    /// Computes `self - duration`, saturating to the nearest representable
+    /// `Instant` if the result would overflow the underlying data structure.
+    ///
+    /// ```rust
+    /// # use time::{Instant, Duration, prelude::*};
+    /// let now = Instant::now();
+    /// assert_eq!(now.saturating_sub(5.seconds()), now - 5.seconds());
+    /// assert_eq!(now.saturating_sub((-5).seconds()), now + 5.seconds());
+    /// assert!(now.saturating_sub(Duration::max_value()) < now);
+    /// ```
+    ///
+    /// This function is only present when using rustc >= 1.34.0.
+    #[inline]
+    #[cfg(instant_checked_ops)]
+    pub fn saturating_sub(self, duration: Duration) -> Self {
+        if duration == Duration::min_value() {
+            // `-Duration::min_value()` is not representable, but anything that
+            // large saturates regardless.
+            return self.saturating_add(Duration::max_value());
+        }
+        self.saturating_add(-duration)
+    }
+}
+
+/// Construct a `StdDuration` from a number of nanoseconds known to fit.
+#[inline(always)]
+#[cfg(instant_checked_ops)]
+fn std_duration_from_nanos(nanoseconds: u128) -> StdDuration {
+    StdDuration::new(
+        (nanoseconds / 1_000_000_000) as u64,
+        (nanoseconds % 1_000_000_000) as u32,
+    )
 }
 
 #[allow(clippy::missing_docs_in_private_items)]
@@ -182,6 +302,13 @@ impl Add<Duration> for Instant {
     type Output = Self;
 
     #[inline(always)]
+    #[cfg(instant_checked_ops)]
+    fn add(self, duration: Duration) -> Self::Output {
+        self.saturating_add(duration)
+    }
+
+    #[inline(always)]
+    #[cfg(not(instant_checked_ops))]
     fn add(self, duration: Duration) -> Self::Output {
         if duration.is_positive() {
             (self.inner + duration.abs_std()).into()
@@ -238,6 +365,13 @@ impl Sub<Duration> for Instant {
     type Output = Self;
 
     #[inline(always)]
+    #[cfg(instant_checked_ops)]
+    fn sub(self, duration: Duration) -> Self::Output {
+        self.saturating_sub(duration)
+    }
+
+    #[inline(always)]
+    #[cfg(not(instant_checked_ops))]
     fn sub(self, duration: Duration) -> Self::Output {
         self + -duration
     }
@@ -325,6 +459,17 @@ mod test {
         assert!(instant.elapsed() >= 100.milliseconds());
     }
 
+    #[test]
+    fn duration_since_start() {
+        let first = Instant::duration_since_start();
+        let second = Instant::duration_since_start();
+        assert!(first >= 0.seconds());
+        assert!(second >= first);
+
+        thread::sleep(10.std_milliseconds());
+        assert!(Instant::duration_since_start() >= second + 10.milliseconds());
+    }
+
     #[test]
     #[cfg(instant_checked_ops)]
     fn checked_add() {
@@ -341,6 +486,53 @@ mod test {
         assert_eq!(now.checked_sub((-5).seconds()), Some(now - (-5).seconds()));
     }
 
+    #[test]
+    #[cfg(instant_checked_ops)]
+    fn saturating_add() {
+        let now = Instant::now();
+        assert_eq!(now.saturating_add(5.seconds()), now + 5.seconds());
+        assert_eq!(now.saturating_add((-5).seconds()), now + (-5).seconds());
+        assert_eq!(now.saturating_add(Duration::zero()), now);
+
+        let d = 1_234.milliseconds();
+        assert_eq!(now.saturating_add(d).saturating_sub(d), now);
+
+        // A single shift may not reach the bounds, depending on the platform.
+        let max = now
+            .saturating_add(Duration::max_value())
+            .saturating_add(Duration::max_value());
+        assert!(max > now);
+        assert_eq!(max.checked_add(1.nanoseconds()), None);
+        let min = now
+            .saturating_add(Duration::min_value())
+            .saturating_add(Duration::min_value());
+        assert!(min < now);
+        assert_eq!(min.checked_sub(1.nanoseconds()), None);
+    }
+
+    #[test]
+    #[cfg(instant_checked_ops)]
+    fn saturating_sub() {
+        let now = Instant::now();
+        assert_eq!(now.saturating_sub(5.seconds()), now - 5.seconds());
+        assert_eq!(now.saturating_sub((-5).seconds()), now - (-5).seconds());
+        assert_eq!(now.saturating_sub(Duration::zero()), now);
+
+        let d = 1_234.milliseconds();
+        assert_eq!(now.saturating_sub(d).saturating_add(d), now);
+
+        let min = now
+            .saturating_sub(Duration::max_value())
+            .saturating_sub(Duration::max_value());
+        assert!(min < now);
+        assert_eq!(min.checked_sub(1.nanoseconds()), None);
+        let max = now
+            .saturating_sub(Duration::min_value())
+            .saturating_sub(Duration::min_value());
+        assert!(max > now);
+        assert_eq!(max.checked_add(1.nanoseconds()), None);
+    }
+
     #[test]
     fn from_std() {
         let now_time = Instant::now();
@@ -421,12 +613,60 @@ mod test {
         assert!(start <= Instant::now());
     }
 
+    #[test]
+    #[cfg(instant_checked_ops)]
+    fn add_duration_saturates() {
+        let now = Instant::now();
+        assert_eq!(
+            now + Duration::max_value(),
+            now.saturating_add(Duration::max_value())
+        );
+        assert_eq!(
+            now + Duration::min_value(),
+            now.saturating_add(Duration::min_value())
+        );
+
+        let std_now = StdInstant::from(now);
+        assert_eq!(
+            std_now + Duration::max_value(),
+            now.saturating_add(Duration::max_value())
+        );
+
+        let mut instant = now;
+        instant += Duration::max_value();
+        assert_eq!(instant, now.saturating_add(Duration::max_value()));
+    }
+
     #[test]
     fn sub_duration() {
         let instant = Instant::now();
         assert!(instant - 100.milliseconds() <= Instant::now());
     }
 
+    #[test]
+    #[cfg(instant_checked_ops)]
+    fn sub_duration_saturates() {
+        let now = Instant::now();
+        assert_eq!(
+            now - Duration::max_value(),
+            now.saturating_sub(Duration::max_value())
+        );
+        assert_eq!(
+            now - Duration::min_value(),
+            now.saturating_sub(Duration::min_value())
+        );
+
+        let std_now = StdInstant::from(now);
+        assert_eq!(
+            std_now - Duration::min_value(),
+            now.saturating_sub(Duration::min_value())
+        );
+
+        let mut instant = now;
+        instant -= Duration::max_value();
+        assert_eq!(instant, now.saturating_sub(Duration::max_value()));
+    }
+
     #[test]
     fn std_sub_duration() {
         let instant = StdInstant::now();
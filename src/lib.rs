@@ -54,6 +54,19 @@
 //! time = { version = "0.2", default-features = false, features = ["rand"] }
 //! ```
 //!
+//! ## `arbitrary`
+//!
+//! [Arbitrary](https://github.com/rust-fuzz/arbitrary) support is behind a
+//! feature flag, allowing `Duration` and `UtcOffset` to be generated when
+//! fuzzing. To enable it, use the `arbitrary` feature. This is not enabled by
+//! default. This feature requires a more recent compiler than the rest of the
+//! crate.
+//!
+//! ```toml
+//! [dependencies]
+//! time = { version = "0.2", features = ["arbitrary"] }
+//! ```
+//!
 //! ## `deprecated`
 //!
 //! Using the `deprecated` feature allows using deprecated v0.1 methods. Enabled
@@ -288,6 +301,58 @@ macro_rules! ensure_value_in_range {
     };
 }
 
+/// Construct a [`Duration`] from a sequence of values and units.
+///
+/// Each value must be an integer literal, and is followed by one of the units
+/// `w`, `d`, `h`, `min`, `s`, `ms`, `us`, or `ns`. The components are summed.
+/// An unknown unit is rejected at compile time.
+///
+/// ```rust
+/// # use time::{duration, prelude::*};
+/// assert_eq!(duration!(1 h 30 min), 90.minutes());
+/// assert_eq!(duration!(2 s 500 ms), 2_500.milliseconds());
+/// assert_eq!(duration!(1 w 1 d), 8.days());
+/// assert_eq!(duration!(5 ns), 5.nanoseconds());
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::duration;
+/// let _ = duration!(1 fortnight);
+/// ```
+#[macro_export]
+macro_rules! duration {
+    (@unit $value:literal w) => {
+        $crate::Duration::weeks($value)
+    };
+    (@unit $value:literal d) => {
+        $crate::Duration::days($value)
+    };
+    (@unit $value:literal h) => {
+        $crate::Duration::hours($value)
+    };
+    (@unit $value:literal min) => {
+        $crate::Duration::minutes($value)
+    };
+    (@unit $value:literal s) => {
+        $crate::Duration::seconds($value)
+    };
+    (@unit $value:literal ms) => {
+        $crate::Duration::milliseconds($value)
+    };
+    (@unit $value:literal us) => {
+        $crate::Duration::microseconds($value)
+    };
+    (@unit $value:literal ns) => {
+        $crate::Duration::nanoseconds($value)
+    };
+    (@unit $value:literal $unit:ident) => {
+        compile_error!(concat!("unknown duration unit `", stringify!($unit), "`"))
+    };
+    ($($value:literal $unit:ident)+) => {
+        $crate::Duration::zero() $(+ $crate::duration!(@unit $value $unit))+
+    };
+}
+
 #[cfg(all(test, std))]
 macro_rules! assert_panics {
     ($e:expr $(, $message:literal)?) => {
@@ -354,12 +419,21 @@ macro_rules! date {
     };
 }
 
+#[cfg(arbitrary)]
+mod arbitrary;
+/// The `Clock` trait and its implementations.
+#[cfg(std)]
+mod clock;
 /// The `Date` struct and its associated `impl`s.
 mod date;
 /// The `Duration` struct and its associated `impl`s.
 mod duration;
+/// The `DurationRange` struct and its associated `impl`s.
+mod duration_range;
 /// Various error types returned by methods in the time crate.
 mod error;
+/// Extension traits for types in the standard library.
+mod ext;
 mod format;
 /// The `Instant` struct and its associated `impl`s.
 #[cfg(std)]
@@ -373,6 +447,9 @@ mod offset_date_time;
 mod primitive_date_time;
 #[cfg(rand)]
 mod rand;
+/// The `RollingWindow` struct and its associated `impl`s.
+#[cfg(std)]
+mod rolling_window;
 #[cfg(serde)]
 #[allow(missing_copy_implementations, missing_debug_implementations)]
 pub mod serde;
@@ -380,22 +457,38 @@ pub mod serde;
 mod sign;
 /// The `Time` struct and its associated `impl`s.
 mod time_mod;
+pub mod units;
 /// The `UtcOffset` struct and its associated `impl`s.
 mod utc_offset;
 /// Days of the week.
 mod weekday;
 
+#[cfg(std)]
+pub use clock::{Clock, MockClock, SystemClock};
 pub use date::{days_in_year, is_leap_year, weeks_in_year, Date};
-pub use duration::Duration;
-pub use error::{ComponentRangeError, ConversionRangeError, Error, IndeterminateOffsetError};
+pub use duration::{
+    DisplayStyle, Duration, DurationComponents, HumanizeOptions, RoundMode, Rounding, UnitLabel,
+    UnitLabels,
+};
+pub use duration_range::DurationRange;
+pub use error::{
+    ComponentRangeError, ConversionRangeError, DurationParseError, Error, IndeterminateOffsetError,
+    NegativeDurationError,
+};
+pub use ext::{FloatSeconds, StdDurationExt};
 pub(crate) use format::DeferredFormat;
-pub use format::{validate_format_string, Format, ParseError};
+pub use format::{
+    validate_format_string, write_offset, DurationFormatItem, Format, OffsetFormatOptions,
+    ParseError,
+};
 #[cfg(std)]
 pub use instant::Instant;
 use internal_prelude::*;
 pub use numerical_traits::{NumericalDuration, NumericalStdDuration, NumericalStdDurationShort};
 pub use offset_date_time::OffsetDateTime;
 pub use primitive_date_time::PrimitiveDateTime;
+#[cfg(std)]
+pub use rolling_window::RollingWindow;
 #[allow(deprecated)]
 pub use sign::Sign;
 /// Construct a [`Date`] with a statically known value.
@@ -465,7 +558,7 @@ pub use time_macros::offset;
 /// ```
 pub use time_macros::time;
 pub use time_mod::Time;
-pub use utc_offset::UtcOffset;
+pub use utc_offset::{BoundaryMode, UtcOffset};
 pub use weekday::Weekday;
 
 /// An alias for `Result` with a generic error from the time crate.
@@ -484,9 +577,9 @@ pub type Result<T> = core::result::Result<T, Error>;
 pub mod prelude {
     // Rename traits to `_` if possible to avoid any potential name conflicts.
     #[cfg(not(use_trait_as_underscore))]
-    pub use crate::{NumericalDuration, NumericalStdDuration};
+    pub use crate::{NumericalDuration, NumericalStdDuration, StdDurationExt};
     #[cfg(use_trait_as_underscore)]
-    pub use crate::{NumericalDuration as _, NumericalStdDuration as _};
+    pub use crate::{NumericalDuration as _, NumericalStdDuration as _, StdDurationExt as _};
     // We need to re-export from the macros crate again (and not just do
     // `crate::foo`) because of the way name resolution works in Rust. It's not
     // currently possible to import _only_ the macro, so doing `use crate::time`
@@ -509,8 +602,8 @@ mod internal_prelude {
     pub(crate) use crate::{
         format::{ParseError, ParseResult},
         ComponentRangeError, ConversionRangeError, Date, DeferredFormat, Duration,
-        IndeterminateOffsetError, NumericalDuration, NumericalStdDuration, OffsetDateTime,
-        PrimitiveDateTime, Time, UtcOffset,
+        IndeterminateOffsetError, NegativeDurationError, NumericalDuration, NumericalStdDuration,
+        OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
         Weekday::{self, Friday, Monday, Saturday, Sunday, Thursday, Tuesday, Wednesday},
     };
     #[cfg(not(std))]
@@ -1374,6 +1374,18 @@ mod test {
                 .with_time(time!(3:04:05))
                 .assume_offset(offset!(+6)))
         );
+        assert_eq!(
+            OffsetDateTime::parse("2024-01-01T00:00:00+0000[UTC]", "%FT%T%z[UTC]"),
+            Ok(date!(2024-01-01).midnight().assume_utc())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2020-01-01 00:00:00 Z", "%F %T %z"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2020-01-01 00:00:00 +05:30", "%F %T %z"),
+            Err(ParseError::InvalidOffset)
+        );
         Ok(())
     }
 
@@ -0,0 +1,248 @@
+use crate::{internal_prelude::*, Clock, SystemClock};
+use std::collections::VecDeque;
+
+/// The average of the durations recorded within a trailing window of time,
+/// such as the latency of requests over the last minute.
+///
+/// Each sample is timestamped using a [`Clock`] when it is added. Samples that
+/// are older than the window are evicted whenever a sample is added or the
+/// average is computed. By default the [`SystemClock`] is used; any other clock
+/// may be provided via [`RollingWindow::with_clock`].
+///
+/// ```rust
+/// # use time::{MockClock, RollingWindow, prelude::*};
+/// let clock = MockClock::default();
+/// let mut window = RollingWindow::with_clock(1.minutes(), &clock);
+/// window.add(100.milliseconds());
+/// clock.advance(30.seconds());
+/// window.add(300.milliseconds());
+/// assert_eq!(window.average(), Some(200.milliseconds()));
+///
+/// clock.advance(45.seconds());
+/// assert_eq!(window.average(), Some(300.milliseconds()));
+/// ```
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone)]
+pub struct RollingWindow<C: Clock = SystemClock> {
+    /// The length of time for which a sample is retained.
+    window: Duration,
+    /// The clock used to timestamp and evict samples.
+    clock: C,
+    /// The samples within the window, along with the instant each was added,
+    /// oldest first.
+    samples: VecDeque<(Instant, Duration)>,
+}
+
+impl RollingWindow {
+    /// Create an empty `RollingWindow` that retains samples for the provided
+    /// duration, as measured by the [`SystemClock`].
+    ///
+    /// ```rust
+    /// # use time::{RollingWindow, prelude::*};
+    /// let mut window = RollingWindow::new(1.minutes());
+    /// assert_eq!(window.average(), None);
+    /// window.add(5.milliseconds());
+    /// assert_eq!(window.average(), Some(5.milliseconds()));
+    /// ```
+    #[inline(always)]
+    pub fn new(window: Duration) -> Self {
+        Self::with_clock(window, SystemClock)
+    }
+}
+
+impl<C: Clock> RollingWindow<C> {
+    /// Create an empty `RollingWindow` that retains samples for the provided
+    /// duration, as measured by `clock`.
+    ///
+    /// ```rust
+    /// # use time::{MockClock, RollingWindow, prelude::*};
+    /// let clock = MockClock::default();
+    /// let window = RollingWindow::with_clock(1.minutes(), &clock);
+    /// assert_eq!(window.window(), 1.minutes());
+    /// ```
+    #[inline(always)]
+    pub fn with_clock(window: Duration, clock: C) -> Self {
+        Self {
+            window,
+            clock,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Get the length of time for which a sample is retained.
+    ///
+    /// ```rust
+    /// # use time::{RollingWindow, prelude::*};
+    /// assert_eq!(RollingWindow::new(5.seconds()).window(), 5.seconds());
+    /// ```
+    #[inline(always)]
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Record a sample at the current instant of the clock, evicting any
+    /// samples that have fallen out of the window.
+    ///
+    /// ```rust
+    /// # use time::{RollingWindow, prelude::*};
+    /// let mut window = RollingWindow::new(1.minutes());
+    /// window.add(1.seconds());
+    /// window.add(3.seconds());
+    /// assert_eq!(window.average(), Some(2.seconds()));
+    /// ```
+    #[inline]
+    pub fn add(&mut self, value: Duration) {
+        self.evict();
+        self.samples.push_back((self.clock.now(), value));
+    }
+
+    /// Get the mean of the samples within the window, rounded towards zero to
+    /// the nearest nanosecond, or `None` if there are none. Samples that have
+    /// fallen out of the window are evicted first.
+    ///
+    /// ```rust
+    /// # use time::{MockClock, RollingWindow, prelude::*};
+    /// let clock = MockClock::default();
+    /// let mut window = RollingWindow::with_clock(10.seconds(), &clock);
+    /// window.add(1.seconds());
+    /// clock.advance(11.seconds());
+    /// assert_eq!(window.average(), None);
+    /// ```
+    #[inline]
+    pub fn average(&mut self) -> Option<Duration> {
+        self.evict();
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        // Even the maximum number of samples, each of the largest magnitude,
+        // cannot overflow an `i128`. The mean is always within range.
+        let total: i128 = self
+            .samples
+            .iter()
+            .map(|&(_, value)| value.whole_nanoseconds())
+            .sum();
+        Some(Duration::nanoseconds_i128(
+            total / self.samples.len() as i128,
+        ))
+    }
+
+    /// Get the number of samples retained, including any that have fallen out
+    /// of the window but have not yet been evicted.
+    ///
+    /// ```rust
+    /// # use time::{RollingWindow, prelude::*};
+    /// let mut window = RollingWindow::new(1.minutes());
+    /// assert_eq!(window.len(), 0);
+    /// window.add(1.seconds());
+    /// assert_eq!(window.len(), 1);
+    /// ```
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check if no samples are retained.
+    ///
+    /// ```rust
+    /// # use time::{RollingWindow, prelude::*};
+    /// let mut window = RollingWindow::new(1.minutes());
+    /// assert!(window.is_empty());
+    /// window.add(1.seconds());
+    /// assert!(!window.is_empty());
+    /// ```
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Remove all samples that were added longer ago than the window.
+    #[inline]
+    fn evict(&mut self) {
+        while let Some(&(instant, _)) = self.samples.front() {
+            if self.clock.elapsed(instant) <= self.window {
+                break;
+            }
+            self.samples.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MockClock;
+
+    #[test]
+    fn average() {
+        let clock = MockClock::default();
+        let mut window = RollingWindow::with_clock(1.minutes(), &clock);
+        assert_eq!(window.average(), None);
+
+        window.add(100.milliseconds());
+        assert_eq!(window.average(), Some(100.milliseconds()));
+
+        clock.advance(20.seconds());
+        window.add(200.milliseconds());
+        clock.advance(20.seconds());
+        window.add(600.milliseconds());
+        assert_eq!(window.average(), Some(300.milliseconds()));
+        assert_eq!(window.len(), 3);
+
+        // A sample exactly as old as the window is retained.
+        clock.advance(20.seconds());
+        assert_eq!(window.average(), Some(300.milliseconds()));
+
+        clock.advance(1.nanoseconds());
+        assert_eq!(window.average(), Some(400.milliseconds()));
+        assert_eq!(window.len(), 2);
+
+        clock.advance(20.seconds());
+        assert_eq!(window.average(), Some(600.milliseconds()));
+
+        clock.advance(20.seconds());
+        assert_eq!(window.average(), None);
+        assert!(window.is_empty());
+    }
+
+    #[test]
+    fn add_evicts() {
+        let clock = MockClock::default();
+        let mut window = RollingWindow::with_clock(10.seconds(), &clock);
+        for _ in 0..5 {
+            window.add(1.seconds());
+            clock.advance(5.seconds());
+        }
+        // Only the samples added at most ten seconds before the last sample
+        // remain.
+        assert_eq!(window.len(), 3);
+    }
+
+    #[test]
+    fn negative_and_mixed() {
+        let clock = MockClock::default();
+        let mut window = RollingWindow::with_clock(1.seconds(), &clock);
+        window.add((-1).seconds());
+        window.add(2.seconds());
+        window.add(1.nanoseconds());
+        assert_eq!(window.average(), Some(333_333_333.nanoseconds()));
+
+        let mut window = RollingWindow::with_clock(1.seconds(), &clock);
+        window.add(Duration::max_value());
+        window.add(Duration::max_value());
+        assert_eq!(window.average(), Some(Duration::max_value()));
+        // The minimum is one second further from zero than the maximum.
+        window.add(Duration::min_value());
+        window.add(Duration::min_value());
+        assert_eq!(window.average(), Some((-500).milliseconds()));
+    }
+
+    #[test]
+    fn system_clock() {
+        let mut window = RollingWindow::new(1.hours());
+        assert_eq!(window.window(), 1.hours());
+        window.add(2.seconds());
+        window.add(4.seconds());
+        assert_eq!(window.average(), Some(3.seconds()));
+    }
+}
@@ -0,0 +1,157 @@
+//! Treat an [`Instant`] as the [`Duration`] elapsed since the reference instant
+//! of the process for the purposes of serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! An `Instant` has no meaningful absolute value, so it is instead represented
+//! relative to the same reference instant used by
+//! [`Instant::duration_since_start`]. Deserialization reconstructs the
+//! `Instant` relative to the current process's reference. As such, only
+//! values serialized and deserialized within the same process are
+//! meaningful; deserializing a value from another process will succeed, but
+//! the resulting `Instant` is arbitrary.
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct S {
+//!     #[serde(with = "time::serde::instant")]
+//!     instant: Instant,
+//! }
+//!
+//! let s = S {
+//!     instant: Instant::now(),
+//! };
+//! let v = serde_json::to_value(&s)?;
+//! assert_eq!(s, serde_json::from_value(v)?);
+//! ```
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use crate::{instant::reference_instant, Duration, Instant};
+#[cfg(instant_checked_ops)]
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+    super::Duration::from(*instant - reference_instant()).serialize(serializer)
+}
+
+#[allow(single_use_lifetimes)]
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Instant, D::Error> {
+    let duration = Duration::from(super::Duration::deserialize(deserializer)?);
+
+    #[cfg(instant_checked_ops)]
+    return Instant::from(reference_instant())
+        .checked_add(duration)
+        .ok_or_else(|| D::Error::custom("instant is out of range"));
+
+    #[cfg(not(instant_checked_ops))]
+    return Ok(Instant::from(reference_instant()) + duration);
+}
+
+/// Treat an `Option<Instant>` as the [`Duration`] elapsed since the reference
+/// instant of the process for the purposes of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// Only values serialized and deserialized within the same process are
+/// meaningful.
+///
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct S {
+///     #[serde(with = "time::serde::instant::option")]
+///     instant: Option<Instant>,
+/// }
+///
+/// let s = S {
+///     instant: Some(Instant::now()),
+/// };
+/// let v = serde_json::to_value(&s)?;
+/// assert_eq!(s, serde_json::from_value(v)?);
+///
+/// let s = S { instant: None };
+/// let v = json!({ "instant": null });
+/// assert_eq!(v, serde_json::to_value(&s)?);
+/// assert_eq!(s, serde_json::from_value(v)?);
+/// ```
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Wrapper(#[serde(with = "super")] Instant);
+
+    pub fn serialize<S: Serializer>(
+        option: &Option<Instant>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.map(Wrapper).serialize(serializer)
+    }
+
+    #[allow(single_use_lifetimes)]
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Instant>, D::Error> {
+        Option::deserialize(deserializer).map(|opt| opt.map(|Wrapper(instant)| instant))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde(with = "super")]
+        instant: Instant,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SOption {
+        #[serde(with = "super::option")]
+        instant: Option<Instant>,
+    }
+
+    #[test]
+    fn round_trip() -> serde_json::Result<()> {
+        let now = Instant::now();
+        for &instant in &[now, now + 1.seconds(), now - 1.seconds()] {
+            let s = S { instant };
+            let v = serde_json::to_value(&s)?;
+            assert_eq!(s, serde_json::from_value(v)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn relative_to_reference() -> serde_json::Result<()> {
+        let s = S {
+            instant: Instant::from(reference_instant()) + 1_500.milliseconds(),
+        };
+        let v = json!({ "instant": [1, 500_000_000] });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+        Ok(())
+    }
+
+    #[test]
+    fn option() -> serde_json::Result<()> {
+        let s = SOption {
+            instant: Some(Instant::now()),
+        };
+        let v = serde_json::to_value(&s)?;
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        let s = SOption { instant: None };
+        let v = json!({ "instant": null });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        Ok(())
+    }
+}
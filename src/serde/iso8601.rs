@@ -0,0 +1,199 @@
+//! Treat a [`Duration`] as an ISO 8601 duration string, such as `PT1H30M`,
+//! for the purposes of serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! Durations are serialized using [`Duration::to_iso8601`] and deserialized
+//! using [`Duration::parse_iso8601`], so the full precision of the duration is
+//! retained. This representation is larger than the default, but is readily
+//! understood by other languages and by humans reading the output.
+//!
+//! ```rust,ignore
+//! use serde_json::json;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct S {
+//!     #[serde(with = "time::serde::iso8601")]
+//!     duration: Duration,
+//! }
+//!
+//! let s = S {
+//!     duration: 90.minutes(),
+//! };
+//! let v = json!({ "duration": "PT1H30M" });
+//! assert_eq!(v, serde_json::to_value(&s)?);
+//! assert_eq!(s, serde_json::from_value(v)?);
+//! ```
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use crate::internal_prelude::*;
+use core::fmt;
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+
+/// A visitor that parses an ISO 8601 duration from either a borrowed or an
+/// owned string.
+struct Iso8601Visitor;
+
+impl Visitor<'_> for Iso8601Visitor {
+    type Value = Duration;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an ISO 8601 duration")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Duration, E> {
+        Duration::parse_iso8601(value).map_err(E::custom)
+    }
+}
+
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&duration.to_iso8601())
+}
+
+#[allow(single_use_lifetimes)]
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Duration, D::Error> {
+    deserializer.deserialize_str(Iso8601Visitor)
+}
+
+/// Treat an `Option<Duration>` as an ISO 8601 duration string for the
+/// purposes of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// ```rust,ignore
+/// use serde_json::json;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct S {
+///     #[serde(with = "time::serde::iso8601::option")]
+///     duration: Option<Duration>,
+/// }
+///
+/// let s = S {
+///     duration: Some(90.minutes()),
+/// };
+/// let v = json!({ "duration": "PT1H30M" });
+/// assert_eq!(v, serde_json::to_value(&s)?);
+/// assert_eq!(s, serde_json::from_value(v)?);
+///
+/// let s = S { duration: None };
+/// let v = json!({ "duration": null });
+/// assert_eq!(v, serde_json::to_value(&s)?);
+/// assert_eq!(s, serde_json::from_value(v)?);
+/// ```
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Wrapper(#[serde(with = "super")] Duration);
+
+    pub fn serialize<S: Serializer>(
+        option: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.map(Wrapper).serialize(serializer)
+    }
+
+    #[allow(single_use_lifetimes)]
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::deserialize(deserializer).map(|opt| opt.map(|Wrapper(duration)| duration))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde(with = "super")]
+        duration: Duration,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SOption {
+        #[serde(with = "super::option")]
+        duration: Option<Duration>,
+    }
+
+    #[test]
+    fn round_trip() -> serde_json::Result<()> {
+        for &(duration, string) in &[
+            (90.minutes(), "PT1H30M"),
+            ((-90).minutes(), "-PT1H30M"),
+            (0.seconds(), "PT0S"),
+            (1.days() + 1.5.seconds(), "P1DT1.5S"),
+            ((-1).nanoseconds(), "-PT0.000000001S"),
+        ] {
+            let s = S { duration };
+            let v = json!({ "duration": string });
+            assert_eq!(v, serde_json::to_value(&s)?);
+            assert_eq!(s, serde_json::from_value(v)?);
+            assert_eq!(s, serde_json::from_str(&serde_json::to_string(&s)?)?);
+        }
+
+        for &duration in &[Duration::max_value(), Duration::min_value()] {
+            let s = S { duration };
+            assert_eq!(s, serde_json::from_str(&serde_json::to_string(&s)?)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn alternate_spellings() -> serde_json::Result<()> {
+        let s = S {
+            duration: (-90).minutes(),
+        };
+        assert_eq!(
+            s,
+            serde_json::from_value(json!({ "duration": "PT-1H-30M" }))?
+        );
+        assert_eq!(s, serde_json::from_value(json!({ "duration": "-PT90M" }))?);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for v in &[
+            json!({ "duration": "1h30m" }),
+            json!({ "duration": "P1Y" }),
+            json!({ "duration": "" }),
+            json!({ "duration": 5_400 }),
+            json!({ "duration": null }),
+        ] {
+            assert!(serde_json::from_value::<S>(v.clone()).is_err());
+        }
+    }
+
+    #[test]
+    fn option() -> serde_json::Result<()> {
+        let s = SOption {
+            duration: Some((-90).minutes()),
+        };
+        let v = json!({ "duration": "-PT1H30M" });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        let s = SOption { duration: None };
+        let v = json!({ "duration": null });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        Ok(())
+    }
+}
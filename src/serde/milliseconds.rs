@@ -0,0 +1,170 @@
+//! Treat a [`Duration`] as a whole number of milliseconds for the purposes of
+//! serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! Any precision finer than a millisecond is truncated when serializing.
+//! Durations whose millisecond count does not fit in an `i64` fail to
+//! serialize.
+//!
+//! ```rust,ignore
+//! use serde_json::json;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct S {
+//!     #[serde(with = "time::serde::milliseconds")]
+//!     duration: Duration,
+//! }
+//!
+//! let s = S {
+//!     duration: 1_500.milliseconds(),
+//! };
+//! let v = json!({ "duration": 1_500 });
+//! assert_eq!(v, serde_json::to_value(&s)?);
+//! assert_eq!(s, serde_json::from_value(v)?);
+//! ```
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use crate::internal_prelude::*;
+use serde::{ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Wrapper(i64);
+
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    i64::try_from(duration.whole_milliseconds())
+        .map_err(|_| S::Error::custom("duration is out of range for milliseconds as `i64`"))
+        .and_then(|milliseconds| Wrapper(milliseconds).serialize(serializer))
+}
+
+#[allow(single_use_lifetimes)]
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Duration, D::Error> {
+    Wrapper::deserialize(deserializer)
+        .map(|Wrapper(milliseconds)| milliseconds)
+        .map(Duration::milliseconds)
+}
+
+/// Treat an `Option<Duration>` as a whole number of milliseconds for the
+/// purposes of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// Any precision finer than a millisecond is truncated when serializing.
+///
+/// ```rust,ignore
+/// use serde_json::json;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct S {
+///     #[serde(with = "time::serde::milliseconds::option")]
+///     duration: Option<Duration>,
+/// }
+///
+/// let s = S {
+///     duration: Some(1_500.milliseconds()),
+/// };
+/// let v = json!({ "duration": 1_500 });
+/// assert_eq!(v, serde_json::to_value(&s)?);
+/// assert_eq!(s, serde_json::from_value(v)?);
+///
+/// let s = S { duration: None };
+/// let v = json!({ "duration": null });
+/// assert_eq!(v, serde_json::to_value(&s)?);
+/// assert_eq!(s, serde_json::from_value(v)?);
+/// ```
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Wrapper(#[serde(with = "super")] Duration);
+
+    pub fn serialize<S: Serializer>(
+        option: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.map(Wrapper).serialize(serializer)
+    }
+
+    #[allow(single_use_lifetimes)]
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::deserialize(deserializer).map(|opt| opt.map(|Wrapper(duration)| duration))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde(with = "super")]
+        duration: Duration,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SOption {
+        #[serde(with = "super::option")]
+        duration: Option<Duration>,
+    }
+
+    #[test]
+    fn round_trip() -> serde_json::Result<()> {
+        let s = S {
+            duration: 1_500.milliseconds(),
+        };
+        let v = json!({ "duration": 1_500 });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        let s = S {
+            duration: (-1_500).milliseconds(),
+        };
+        let v = json!({ "duration": -1_500 });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncates_submillisecond() -> serde_json::Result<()> {
+        let s = S {
+            duration: 1_500_999.microseconds(),
+        };
+        assert_eq!(json!({ "duration": 1_500 }), serde_json::to_value(&s)?);
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_range() {
+        let s = S {
+            duration: Duration::max_value(),
+        };
+        assert!(serde_json::to_value(&s).is_err());
+    }
+
+    #[test]
+    fn option() -> serde_json::Result<()> {
+        let s = SOption {
+            duration: Some((-1_500).milliseconds()),
+        };
+        let v = json!({ "duration": -1_500 });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        let s = SOption { duration: None };
+        let v = json!({ "duration": null });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        Ok(())
+    }
+}
@@ -14,7 +14,13 @@
 
 mod date;
 mod duration;
+#[cfg(std)]
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+pub mod instant;
+pub mod iso8601;
+pub mod milliseconds;
 mod primitive_date_time;
+pub mod seconds_f64;
 mod sign;
 mod time;
 pub mod timestamp;
@@ -0,0 +1,198 @@
+//! Treat a [`Duration`] as a fractional number of seconds for the purposes of
+//! serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! The duration is serialized using [`Duration::as_seconds_f64`], so
+//! precision is lost for durations whose number of nanoseconds cannot be
+//! represented exactly by an `f64`, which is roughly any duration longer than
+//! 104 days. Deserializing uses [`Duration::seconds_f64`], rounding to the
+//! nearest nanosecond. Values that are not finite or that are out of range
+//! for a `Duration` fail to deserialize.
+//!
+//! ```rust,ignore
+//! use serde_json::json;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct S {
+//!     #[serde(with = "time::serde::seconds_f64")]
+//!     duration: Duration,
+//! }
+//!
+//! let s = S {
+//!     duration: 1.5.seconds(),
+//! };
+//! let v = json!({ "duration": 1.5 });
+//! assert_eq!(v, serde_json::to_value(&s)?);
+//! assert_eq!(s, serde_json::from_value(v)?);
+//! ```
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use crate::internal_prelude::*;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Wrapper(f64);
+
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    Wrapper(duration.as_seconds_f64()).serialize(serializer)
+}
+
+#[allow(single_use_lifetimes)]
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Duration, D::Error> {
+    let Wrapper(seconds) = Wrapper::deserialize(deserializer)?;
+
+    if !seconds.is_finite() {
+        return Err(D::Error::custom("duration in seconds is not finite"));
+    }
+    if seconds >= i64::max_value() as f64 || seconds < i64::min_value() as f64 {
+        return Err(D::Error::custom("duration is out of range"));
+    }
+
+    Ok(Duration::seconds_f64(seconds))
+}
+
+/// Treat an `Option<Duration>` as a fractional number of seconds for the
+/// purposes of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// Precision is lost in the same manner as for
+/// [`time::serde::seconds_f64`](super).
+///
+/// ```rust,ignore
+/// use serde_json::json;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct S {
+///     #[serde(with = "time::serde::seconds_f64::option")]
+///     duration: Option<Duration>,
+/// }
+///
+/// let s = S {
+///     duration: Some(1.5.seconds()),
+/// };
+/// let v = json!({ "duration": 1.5 });
+/// assert_eq!(v, serde_json::to_value(&s)?);
+/// assert_eq!(s, serde_json::from_value(v)?);
+///
+/// let s = S { duration: None };
+/// let v = json!({ "duration": null });
+/// assert_eq!(v, serde_json::to_value(&s)?);
+/// assert_eq!(s, serde_json::from_value(v)?);
+/// ```
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Wrapper(#[serde(with = "super")] Duration);
+
+    pub fn serialize<S: Serializer>(
+        option: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.map(Wrapper).serialize(serializer)
+    }
+
+    #[allow(single_use_lifetimes)]
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::deserialize(deserializer).map(|opt| opt.map(|Wrapper(duration)| duration))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde(with = "super")]
+        duration: Duration,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SOption {
+        #[serde(with = "super::option")]
+        duration: Option<Duration>,
+    }
+
+    #[test]
+    fn round_trip() -> serde_json::Result<()> {
+        let s = S {
+            duration: 1.5.seconds(),
+        };
+        let v = json!({ "duration": 1.5 });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        let s = S {
+            duration: (-250).milliseconds(),
+        };
+        let v = json!({ "duration": -0.25 });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        let s = S {
+            duration: 123_456_789.nanoseconds(),
+        };
+        assert_eq!(s, serde_json::from_value(serde_json::to_value(&s)?)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_integer() -> serde_json::Result<()> {
+        let s: S = serde_json::from_value(json!({ "duration": 3600 }))?;
+        assert_eq!(s.duration, 1.hours());
+        Ok(())
+    }
+
+    #[test]
+    fn rounds_to_nanosecond() -> serde_json::Result<()> {
+        let s: S = serde_json::from_value(json!({ "duration": 6e-10 }))?;
+        assert_eq!(s.duration, 1.nanoseconds());
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(serde_json::from_value::<S>(json!({ "duration": 1e19 })).is_err());
+        assert!(serde_json::from_value::<S>(json!({ "duration": -1e19 })).is_err());
+        assert!(serde_json::from_value::<S>(json!({ "duration": std::f64::MAX })).is_err());
+    }
+
+    #[test]
+    fn non_finite() {
+        use serde::de::{value::Error, value::F64Deserializer, IntoDeserializer};
+
+        for &value in &[std::f64::NAN, std::f64::INFINITY, std::f64::NEG_INFINITY] {
+            let deserializer: F64Deserializer<Error> = value.into_deserializer();
+            assert!(deserialize(deserializer).is_err());
+        }
+    }
+
+    #[test]
+    fn option() -> serde_json::Result<()> {
+        let s = SOption {
+            duration: Some((-1.5).seconds()),
+        };
+        let v = json!({ "duration": -1.5 });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        let s = SOption { duration: None };
+        let v = json!({ "duration": null });
+        assert_eq!(v, serde_json::to_value(&s)?);
+        assert_eq!(s, serde_json::from_value(v)?);
+
+        Ok(())
+    }
+}
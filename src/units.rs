@@ -0,0 +1,190 @@
+//! Wrappers tagging an integer with the unit of time it represents.
+//!
+//! Each wrapper converts into a [`Duration`] via [`From`], allowing APIs to
+//! accept a specific unit without risk of the caller confusing one for
+//! another. The [`Unit`] enum names a unit without an associated value.
+//!
+//! ```rust
+//! # use time::{Duration, units::Millis, prelude::*};
+//! fn timeout(duration: impl Into<Duration>) -> Duration {
+//!     duration.into()
+//! }
+//!
+//! assert_eq!(timeout(Millis(1_500)), 1_500.milliseconds());
+//! ```
+
+use crate::Duration;
+
+/// A whole number of seconds.
+///
+/// ```rust
+/// # use time::{Duration, units::Seconds, prelude::*};
+/// assert_eq!(Duration::from(Seconds(5)), 5.seconds());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Seconds(pub i64);
+
+/// A whole number of milliseconds.
+///
+/// ```rust
+/// # use time::{Duration, units::Millis, prelude::*};
+/// assert_eq!(Duration::from(Millis(5)), 5.milliseconds());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Millis(pub i64);
+
+/// A whole number of microseconds.
+///
+/// ```rust
+/// # use time::{Duration, units::Micros, prelude::*};
+/// assert_eq!(Duration::from(Micros(5)), 5.microseconds());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Micros(pub i64);
+
+/// A whole number of nanoseconds.
+///
+/// ```rust
+/// # use time::{Duration, units::Nanos, prelude::*};
+/// assert_eq!(Duration::from(Nanos(5)), 5.nanoseconds());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nanos(pub i64);
+
+/// A unit of time, used to select the granularity of an operation.
+///
+/// ```rust
+/// # use time::{units::Unit, prelude::*};
+/// assert_eq!(Unit::Minute.duration(), 1.minutes());
+/// ```
+#[cfg_attr(supports_non_exhaustive, non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    /// One nanosecond.
+    Nanosecond,
+    /// One microsecond, or 1,000 nanoseconds.
+    Microsecond,
+    /// One millisecond, or 1,000 microseconds.
+    Millisecond,
+    /// One second, or 1,000 milliseconds.
+    Second,
+    /// One minute, or 60 seconds.
+    Minute,
+    /// One hour, or 60 minutes.
+    Hour,
+    /// One day, or 24 hours.
+    Day,
+    /// One week, or 7 days.
+    Week,
+    #[cfg(not(supports_non_exhaustive))]
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+impl Unit {
+    /// Get the length of the unit as a `Duration`.
+    ///
+    /// ```rust
+    /// # use time::{units::Unit, prelude::*};
+    /// assert_eq!(Unit::Millisecond.duration(), 1.milliseconds());
+    /// assert_eq!(Unit::Week.duration(), 7.days());
+    /// ```
+    #[inline]
+    pub fn duration(self) -> Duration {
+        Duration::nanoseconds_i128(self.nanoseconds())
+    }
+
+    /// Get the number of nanoseconds in the unit.
+    #[inline]
+    pub(crate) fn nanoseconds(self) -> i128 {
+        match self {
+            Unit::Nanosecond => 1,
+            Unit::Microsecond => 1_000,
+            Unit::Millisecond => 1_000_000,
+            Unit::Second => 1_000_000_000,
+            Unit::Minute => 60_000_000_000,
+            Unit::Hour => 3_600_000_000_000,
+            Unit::Day => 86_400_000_000_000,
+            Unit::Week => 604_800_000_000_000,
+            #[cfg(not(supports_non_exhaustive))]
+            Unit::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+impl From<Seconds> for Duration {
+    #[inline(always)]
+    fn from(Seconds(seconds): Seconds) -> Self {
+        Self::seconds(seconds)
+    }
+}
+
+impl From<Millis> for Duration {
+    #[inline(always)]
+    fn from(Millis(milliseconds): Millis) -> Self {
+        Self::milliseconds(milliseconds)
+    }
+}
+
+impl From<Micros> for Duration {
+    #[inline(always)]
+    fn from(Micros(microseconds): Micros) -> Self {
+        Self::microseconds(microseconds)
+    }
+}
+
+impl From<Nanos> for Duration {
+    #[inline(always)]
+    fn from(Nanos(nanoseconds): Nanos) -> Self {
+        Self::nanoseconds(nanoseconds)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn unit_duration() {
+        assert_eq!(Unit::Nanosecond.duration(), 1.nanoseconds());
+        assert_eq!(Unit::Microsecond.duration(), 1.microseconds());
+        assert_eq!(Unit::Millisecond.duration(), 1.milliseconds());
+        assert_eq!(Unit::Second.duration(), 1.seconds());
+        assert_eq!(Unit::Minute.duration(), 1.minutes());
+        assert_eq!(Unit::Hour.duration(), 1.hours());
+        assert_eq!(Unit::Day.duration(), 1.days());
+        assert_eq!(Unit::Week.duration(), 1.weeks());
+    }
+
+    #[test]
+    fn seconds() {
+        assert_eq!(Duration::from(Seconds(5)), 5.seconds());
+        assert_eq!(Duration::from(Seconds(-5)), (-5).seconds());
+        assert_eq!(Duration::from(Seconds(0)), Duration::zero());
+    }
+
+    #[test]
+    fn millis() {
+        assert_eq!(Duration::from(Millis(1_500)), 1.5.seconds());
+        assert_eq!(Duration::from(Millis(-1_500)), (-1.5).seconds());
+        assert_eq!(Duration::from(Millis(0)), Duration::zero());
+    }
+
+    #[test]
+    fn micros() {
+        assert_eq!(Duration::from(Micros(1_500)), 1_500_000.nanoseconds());
+        assert_eq!(Duration::from(Micros(-1_500)), (-1_500_000).nanoseconds());
+        assert_eq!(Duration::from(Micros(0)), Duration::zero());
+    }
+
+    #[test]
+    fn nanos() {
+        assert_eq!(Duration::from(Nanos(1_500)), 1_500.nanoseconds());
+        assert_eq!(Duration::from(Nanos(-1_500)), (-1_500).nanoseconds());
+        assert_eq!(
+            Duration::from(Nanos(i64::min_value())),
+            Duration::nanoseconds(i64::min_value())
+        );
+    }
+}
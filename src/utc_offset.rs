@@ -1,8 +1,13 @@
 use crate::{
-    format::{parse, ParsedItems},
+    format::{offset, parse, ParsedItems},
     internal_prelude::*,
 };
-use core::fmt::{self, Display};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
 
 /// An offset from UTC.
 ///
@@ -10,6 +15,19 @@ use core::fmt::{self, Display};
 /// may have incidental support that can change at any time without notice. If
 /// you need support outside this range, please file an issue with your use
 /// case.
+///
+/// Adding or subtracting a [`Duration`] shifts the offset, clamping the result
+/// to ±23:59:59. Any fractional second in the `Duration` is ignored.
+///
+/// Offsets are ordered by their total number of seconds, as returned by
+/// [`UtcOffset::as_seconds`], so western offsets sort before eastern ones.
+///
+/// ```rust
+/// # use time::offset;
+/// let mut offsets = [offset!(+5:30), offset!(UTC), offset!(-5)];
+/// offsets.sort();
+/// assert_eq!(offsets, [offset!(-5), offset!(UTC), offset!(+5:30)]);
+/// ```
 #[cfg_attr(serde, derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     serde,
@@ -22,6 +40,32 @@ pub struct UtcOffset {
     pub(crate) seconds: i32,
 }
 
+/// The largest magnitude of offset, in seconds, that the arithmetic operators
+/// produce. This corresponds to ±23:59:59.
+const MAX_ARITHMETIC_SECONDS: i64 = 86_399;
+
+/// How [`UtcOffset::normalized_add`] handles a result that reaches or exceeds
+/// ±24 hours.
+///
+/// ```rust
+/// # use time::{offset, BoundaryMode, prelude::*};
+/// assert_eq!(offset!(+23).normalized_add(2.hours(), BoundaryMode::Clamp), offset!(+23:59:59));
+/// assert_eq!(offset!(+23).normalized_add(2.hours(), BoundaryMode::Wrap), offset!(+1));
+/// ```
+#[cfg_attr(supports_non_exhaustive, non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundaryMode {
+    /// Clamp the result to ±23:59:59. This is the behavior of the `+` and `-`
+    /// operators.
+    Clamp,
+    /// Wrap the result around a 24 hour day, keeping its sign. An offset of
+    /// +25:00 becomes +01:00, and -24:00 becomes UTC.
+    Wrap,
+    #[cfg(not(supports_non_exhaustive))]
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
 impl UtcOffset {
     /// A `UtcOffset` that is UTC.
     ///
@@ -107,6 +151,40 @@ impl UtcOffset {
         Self::seconds(minutes as i32 * 60)
     }
 
+    /// Attempt to create a `UtcOffset` from a number of hours and minutes, such
+    /// as `+5:45`. The hours must be in the range `-23..=23` and the minutes
+    /// in the range `-59..=59`.
+    ///
+    /// The minutes must have the same sign as the hours, or either may be
+    /// zero. An offset of less than an hour west is expressed with zero hours
+    /// and negative minutes.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::hours_minutes(5, 30), Ok(UtcOffset::minutes(330)));
+    /// assert_eq!(UtcOffset::hours_minutes(-3, -30), Ok(UtcOffset::minutes(-210)));
+    /// assert_eq!(UtcOffset::hours_minutes(0, -30), Ok(UtcOffset::minutes(-30)));
+    /// assert!(UtcOffset::hours_minutes(5, 60).is_err());
+    /// assert!(UtcOffset::hours_minutes(-3, 30).is_err());
+    /// ```
+    #[inline]
+    pub fn hours_minutes(hours: i8, minutes: i8) -> Result<Self, ComponentRangeError> {
+        ensure_value_in_range!(hours in -23 => 23);
+        match hours.cmp(&0) {
+            Ordering::Greater => {
+                ensure_value_in_range!(minutes in 0 => 59, given hours);
+            }
+            Ordering::Less => {
+                ensure_value_in_range!(minutes in -59 => 0, given hours);
+            }
+            Ordering::Equal => {
+                ensure_value_in_range!(minutes in -59 => 59);
+            }
+        }
+
+        Ok(Self::minutes(hours as i16 * 60 + minutes as i16))
+    }
+
     /// Create a `UtcOffset` representing an easterly offset by the number of
     /// seconds provided.
     ///
@@ -194,6 +272,49 @@ impl UtcOffset {
         Duration::seconds(self.seconds as i64)
     }
 
+    /// Get the signed gap between two offsets, computed as `self - other`.
+    /// The result is positive when `self` is further east than `other`.
+    ///
+    /// This is the amount by which local wall-clock time changes when moving
+    /// from `other` to `self`, such as across a daylight saving transition.
+    ///
+    /// ```rust
+    /// # use time::{offset, prelude::*};
+    /// assert_eq!(offset!(+5:30).difference(offset!(UTC)), 5.5.hours());
+    /// assert_eq!(offset!(UTC).difference(offset!(+5:30)), (-5.5).hours());
+    /// assert_eq!(offset!(-4).difference(offset!(-5)), 1.hours());
+    /// ```
+    #[inline(always)]
+    pub fn difference(self, other: Self) -> Duration {
+        self.as_duration() - other.as_duration()
+    }
+
+    /// Add a `Duration` to the offset, handling a result beyond ±23:59:59
+    /// according to the provided [`BoundaryMode`]. Any fractional second in
+    /// the `Duration` is ignored.
+    ///
+    /// ```rust
+    /// # use time::{offset, BoundaryMode, prelude::*};
+    /// assert_eq!(offset!(+5:30).normalized_add(1.hours(), BoundaryMode::Wrap), offset!(+6:30));
+    /// assert_eq!(offset!(-23).normalized_add((-3).hours(), BoundaryMode::Clamp), offset!(-23:59:59));
+    /// assert_eq!(offset!(-23).normalized_add((-3).hours(), BoundaryMode::Wrap), offset!(-2));
+    /// ```
+    #[inline]
+    pub fn normalized_add(self, duration: Duration, mode: BoundaryMode) -> Self {
+        let seconds = self.seconds as i128 + duration.whole_seconds() as i128;
+
+        let seconds = match mode {
+            BoundaryMode::Clamp => seconds
+                .max(-MAX_ARITHMETIC_SECONDS as i128)
+                .min(MAX_ARITHMETIC_SECONDS as i128),
+            BoundaryMode::Wrap => seconds % (MAX_ARITHMETIC_SECONDS as i128 + 1),
+            #[cfg(not(supports_non_exhaustive))]
+            BoundaryMode::__NonExhaustive => unreachable!(),
+        };
+
+        Self::seconds(seconds as i32)
+    }
+
     /// Obtain the system's UTC offset at a known moment in time. If the offset
     /// cannot be determined, UTC is returned.
     ///
@@ -283,6 +404,28 @@ impl UtcOffset {
             .to_owned()
     }
 
+    /// Format the `UtcOffset` in its shortest unambiguous form. The hours are
+    /// not padded, and the minutes and seconds are only present when needed.
+    /// An offset of zero is written as `Z`.
+    ///
+    /// This is identical to the [`Display`] implementation, other than the
+    /// handling of zero.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::hours(5).format_compact(), "+5");
+    /// assert_eq!(UtcOffset::minutes(330).format_compact(), "+5:30");
+    /// assert_eq!(UtcOffset::UTC.format_compact(), "Z");
+    /// ```
+    #[inline]
+    pub fn format_compact(self) -> String {
+        if self == Self::UTC {
+            "Z".to_owned()
+        } else {
+            self.to_string()
+        }
+    }
+
     /// Attempt to parse the `UtcOffset` using the provided string.
     ///
     /// ```rust
@@ -295,6 +438,119 @@ impl UtcOffset {
         Self::try_from_parsed_items(parse(s.as_ref(), &format.into())?)
     }
 
+    /// Attempt to parse a `UtcOffset` from the start of the provided input,
+    /// returning the offset and the number of bytes consumed. Any input after
+    /// the offset is ignored, allowing this to be interleaved with other
+    /// parsers.
+    ///
+    /// `Z` (either case), `±HHMM`, and `±HH:MM` are accepted.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::parse_prefix("+05:30"), Ok((UtcOffset::minutes(330), 6)));
+    /// assert_eq!(UtcOffset::parse_prefix("-0200 rest"), Ok((UtcOffset::hours(-2), 5)));
+    /// assert_eq!(UtcOffset::parse_prefix(b"Z"), Ok((UtcOffset::UTC, 1)));
+    /// ```
+    #[inline(always)]
+    pub fn parse_prefix(bytes: impl AsRef<[u8]>) -> ParseResult<(Self, usize)> {
+        offset::parse_offset_prefix(bytes.as_ref())
+    }
+
+    /// Attempt to parse an optional `UtcOffset` from the start of the provided
+    /// input, returning the offset, if any, and the number of bytes consumed.
+    ///
+    /// This accepts the same forms as [`UtcOffset::parse_prefix`]. If the
+    /// input does not begin with a sign or `Z`, no offset is present and
+    /// `(None, 0)` is returned, allowing timestamps both with and without a
+    /// trailing offset to be handled uniformly. A sign that is not followed by
+    /// a valid offset is still an error.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::parse_optional_prefix("Z"), Ok((Some(UtcOffset::UTC), 1)));
+    /// assert_eq!(
+    ///     UtcOffset::parse_optional_prefix("+05:30"),
+    ///     Ok((Some(UtcOffset::minutes(330)), 6))
+    /// );
+    /// assert_eq!(UtcOffset::parse_optional_prefix(""), Ok((None, 0)));
+    /// assert_eq!(UtcOffset::parse_optional_prefix(" rest"), Ok((None, 0)));
+    /// assert!(UtcOffset::parse_optional_prefix("+5").is_err());
+    /// ```
+    #[inline]
+    pub fn parse_optional_prefix(s: impl AsRef<str>) -> ParseResult<(Option<Self>, usize)> {
+        let mut items = ParsedItems::new();
+        let original = s.as_ref();
+        let mut remaining = original;
+        offset::parse_optional_offset(&mut items, &mut remaining)?;
+        Ok((items.offset, original.len() - remaining.len()))
+    }
+
+    /// Attempt to parse a `UtcOffset` that makes up the entirety of the
+    /// provided string, without needing a format string.
+    ///
+    /// `Z` (either case), `±HHMM`, and `±HH:MM` are accepted. The sign may also
+    /// be `−` (U+2212 MINUS SIGN) or `＋` (U+FF0B FULLWIDTH PLUS SIGN). Any
+    /// trailing characters result in an error.
+    ///
+    /// This is also available via [`str::parse`].
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::parse_offset("+05:30"), Ok(UtcOffset::minutes(330)));
+    /// assert_eq!(UtcOffset::parse_offset("Z"), Ok(UtcOffset::UTC));
+    /// assert_eq!(UtcOffset::parse_offset("\u{2212}05:30"), Ok(UtcOffset::minutes(-330)));
+    /// assert!(UtcOffset::parse_offset("+05:30 ").is_err());
+    /// ```
+    #[inline]
+    pub fn parse_offset(s: impl AsRef<str>) -> ParseResult<Self> {
+        let bytes = s.as_ref().as_bytes();
+        let (offset, consumed) = offset::parse_offset_prefix(bytes)?;
+
+        if consumed != bytes.len() {
+            return Err(ParseError::InvalidOffset);
+        }
+
+        Ok(offset)
+    }
+
+    /// Attempt to parse a `UtcOffset` from a signed integer number of minutes,
+    /// such as `+330` for an offset of `+05:30`. The sign is optional, and the
+    /// magnitude must be less than a day (1,440 minutes).
+    ///
+    /// ```rust
+    /// # use time::{UtcOffset, ParseError};
+    /// assert_eq!(UtcOffset::parse_total_minutes("+330"), Ok(UtcOffset::minutes(330)));
+    /// assert_eq!(UtcOffset::parse_total_minutes("-90"), Ok(UtcOffset::minutes(-90)));
+    /// assert_eq!(UtcOffset::parse_total_minutes("0"), Ok(UtcOffset::UTC));
+    /// assert_eq!(
+    ///     UtcOffset::parse_total_minutes("+1440"),
+    ///     Err(ParseError::InvalidOffset)
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_total_minutes(s: impl AsRef<str>) -> ParseResult<Self> {
+        let s = s.as_ref();
+        let (sign, digits) = match s.as_bytes().first() {
+            Some(b'+') => (1, &s[1..]),
+            Some(b'-') => (-1, &s[1..]),
+            _ => (1, s),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ParseError::InvalidOffset);
+        }
+
+        let minutes = digits
+            .bytes()
+            .try_fold(0_i16, |minutes, digit| {
+                minutes.checked_mul(10)?.checked_add((digit - b'0') as i16)
+            })
+            .filter(|&minutes| minutes < 24 * 60)
+            .ok_or(ParseError::InvalidOffset)?;
+
+        Ok(Self::minutes(sign * minutes))
+    }
+
     /// Given the items already parsed, attempt to create a `UtcOffset`.
     #[inline(always)]
     pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
@@ -324,6 +580,66 @@ impl Display for UtcOffset {
     }
 }
 
+impl FromStr for UtcOffset {
+    type Err = ParseError;
+
+    #[inline(always)]
+    fn from_str(s: &str) -> ParseResult<Self> {
+        Self::parse_offset(s)
+    }
+}
+
+impl Neg for UtcOffset {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::seconds(-self.seconds)
+    }
+}
+
+impl Add<Duration> for UtcOffset {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, duration: Duration) -> Self::Output {
+        let seconds = (self.seconds as i64)
+            .saturating_add(duration.whole_seconds())
+            .max(-MAX_ARITHMETIC_SECONDS)
+            .min(MAX_ARITHMETIC_SECONDS);
+
+        Self::seconds(seconds as i32)
+    }
+}
+
+impl AddAssign<Duration> for UtcOffset {
+    #[inline(always)]
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for UtcOffset {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, duration: Duration) -> Self::Output {
+        let seconds = (self.seconds as i64)
+            .saturating_sub(duration.whole_seconds())
+            .max(-MAX_ARITHMETIC_SECONDS)
+            .min(MAX_ARITHMETIC_SECONDS);
+
+        Self::seconds(seconds as i32)
+    }
+}
+
+impl SubAssign<Duration> for UtcOffset {
+    #[inline(always)]
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
 /// Attempt to obtain the system's UTC offset. If the offset cannot be
 /// determined, `None` is returned.
 #[cfg(std)]
@@ -525,6 +841,37 @@ mod test {
         assert_eq!(UtcOffset::west_hours(1), offset!(-1));
     }
 
+    #[test]
+    fn ordering() {
+        assert!(offset!(-5) < offset!(UTC));
+        assert!(offset!(UTC) < offset!(+5:30));
+        assert!(offset!(-5) < offset!(+5:30));
+        assert!(offset!(+5:30) < offset!(+5:45));
+        assert!(offset!(-0:30) < offset!(UTC));
+        assert!(UtcOffset::seconds(-1) < UtcOffset::seconds(0));
+        assert_eq!(offset!(+5:30).cmp(&offset!(+5:30)), Ordering::Equal);
+        assert_eq!(offset!(+12).max(offset!(-12)), offset!(+12));
+
+        let mut offsets = [
+            offset!(+5:30),
+            offset!(UTC),
+            offset!(-5),
+            offset!(+14),
+            offset!(-12),
+        ];
+        offsets.sort();
+        assert_eq!(
+            offsets,
+            [
+                offset!(-12),
+                offset!(-5),
+                offset!(UTC),
+                offset!(+5:30),
+                offset!(+14)
+            ]
+        );
+    }
+
     #[test]
     fn minutes() {
         assert_eq!(UtcOffset::minutes(1).as_seconds(), 60);
@@ -533,6 +880,22 @@ mod test {
         assert_eq!(UtcOffset::minutes(-1_439).as_seconds(), -86_340);
     }
 
+    #[test]
+    fn hours_minutes() {
+        assert_eq!(UtcOffset::hours_minutes(5, 30), Ok(offset!(+5:30)));
+        assert_eq!(UtcOffset::hours_minutes(5, 45), Ok(offset!(+5:45)));
+        assert_eq!(UtcOffset::hours_minutes(-9, -30), Ok(offset!(-9:30)));
+        assert_eq!(UtcOffset::hours_minutes(0, -30), Ok(offset!(-0:30)));
+        assert_eq!(UtcOffset::hours_minutes(0, 0), Ok(offset!(UTC)));
+        assert_eq!(UtcOffset::hours_minutes(-5, 0), Ok(offset!(-5)));
+        assert_eq!(UtcOffset::hours_minutes(23, 59), Ok(offset!(+23:59)));
+        assert!(UtcOffset::hours_minutes(5, 60).is_err());
+        assert!(UtcOffset::hours_minutes(5, -30).is_err());
+        assert!(UtcOffset::hours_minutes(-5, 30).is_err());
+        assert!(UtcOffset::hours_minutes(24, 0).is_err());
+        assert!(UtcOffset::hours_minutes(0, -60).is_err());
+    }
+
     #[test]
     fn directional_minutes() {
         assert_eq!(UtcOffset::east_minutes(1), offset!(+0:01));
@@ -581,6 +944,79 @@ mod test {
         assert_eq!(offset!(-0:00:01).as_seconds(), -1);
     }
 
+    #[test]
+    fn difference() {
+        assert_eq!(offset!(+5:30).difference(offset!(+0:00)), 5.5.hours());
+        assert_eq!(offset!(+0:00).difference(offset!(+5:30)), (-5.5).hours());
+        assert_eq!(offset!(-4).difference(offset!(-5)), 1.hours());
+        assert_eq!(offset!(+1).difference(offset!(+1)), Duration::zero());
+        assert_eq!(
+            offset!(+23:59:59).difference(offset!(-23:59:59)),
+            (2 * 86_399).seconds()
+        );
+    }
+
+    #[test]
+    fn normalized_add_clamp() {
+        use BoundaryMode::Clamp;
+
+        assert_eq!(
+            offset!(+5:30).normalized_add(1.hours(), Clamp),
+            offset!(+6:30)
+        );
+        assert_eq!(
+            offset!(-0:30).normalized_add(1.hours(), Clamp),
+            offset!(+0:30)
+        );
+        assert_eq!(
+            offset!(+23).normalized_add(1.hours(), Clamp),
+            offset!(+23:59:59)
+        );
+        assert_eq!(
+            offset!(-23).normalized_add((-3).hours(), Clamp),
+            offset!(-23:59:59)
+        );
+        assert_eq!(
+            offset!(UTC).normalized_add(Duration::max_value(), Clamp),
+            offset!(+23:59:59)
+        );
+        assert_eq!(
+            offset!(-1).normalized_add(Duration::min_value(), Clamp),
+            offset!(-23:59:59)
+        );
+        assert_eq!(
+            offset!(+23).normalized_add(2.hours(), Clamp),
+            offset!(+23) + 2.hours()
+        );
+    }
+
+    #[test]
+    fn normalized_add_wrap() {
+        use BoundaryMode::Wrap;
+
+        assert_eq!(
+            offset!(+5:30).normalized_add(1.hours(), Wrap),
+            offset!(+6:30)
+        );
+        assert_eq!(offset!(+23).normalized_add(2.hours(), Wrap), offset!(+1));
+        assert_eq!(offset!(+23).normalized_add(1.hours(), Wrap), offset!(UTC));
+        assert_eq!(
+            offset!(+23:59:59).normalized_add(1.5.seconds(), Wrap),
+            offset!(UTC)
+        );
+        assert_eq!(offset!(-23).normalized_add((-3).hours(), Wrap), offset!(-2));
+        assert_eq!(offset!(-23).normalized_add(1.hours(), Wrap), offset!(-22));
+        assert_eq!(offset!(+12).normalized_add(48.hours(), Wrap), offset!(+12));
+        assert_eq!(
+            offset!(+23:59:59).normalized_add(Duration::max_value(), Wrap),
+            UtcOffset::seconds(((86_399 + i64::max_value() as i128) % 86_400) as i32)
+        );
+        assert_eq!(
+            offset!(UTC).normalized_add(Duration::min_value(), Wrap),
+            UtcOffset::seconds((i64::min_value() % 86_400) as i32)
+        );
+    }
+
     #[test]
     fn as_duration() {
         assert_eq!(offset!(+1).as_duration(), 1.hours());
@@ -606,6 +1042,86 @@ mod test {
         // Seconds are not displayed, but the sign can still change.
         assert_eq!(offset!(+0:00:01).format("%z"), "+0000");
         assert_eq!(offset!(-0:00:01).format("%z"), "-0000");
+        assert_eq!(offset!(+0:19:32).format("%z"), "+0019");
+        assert_eq!(offset!(-23:59:59).format("%z"), "-2359");
+    }
+
+    #[test]
+    fn format_compact() {
+        assert_eq!(offset!(+5:00).format_compact(), "+5");
+        assert_eq!(offset!(+5:30).format_compact(), "+5:30");
+        assert_eq!(offset!(-5:30).format_compact(), "-5:30");
+        assert_eq!(offset!(+0:00).format_compact(), "Z");
+        assert_eq!(offset!(-0:00).format_compact(), "Z");
+        assert_eq!(offset!(+0:30).format_compact(), "+0:30");
+        assert_eq!(offset!(-0:00:01).format_compact(), "-0:00:01");
+        assert_eq!(offset!(+23:59:59).format_compact(), "+23:59:59");
+        assert_eq!(offset!(-12).format_compact(), "-12");
+    }
+
+    #[test]
+    fn write_offset() -> fmt::Result {
+        use crate::{write_offset, OffsetFormatOptions};
+
+        fn render(offset: UtcOffset, options: OffsetFormatOptions) -> Result<String, fmt::Error> {
+            let mut s = String::new();
+            write_offset(&mut s, offset, options)?;
+            Ok(s)
+        }
+
+        let offsets = [
+            offset!(UTC),
+            offset!(+5:30),
+            offset!(-0:19:32),
+            offset!(+23:59:59),
+        ];
+        let expected = [
+            // separator, include seconds, zero as `Z`
+            ((None, false, false), ["+0000", "+0530", "-0019", "+2359"]),
+            ((None, false, true), ["Z", "+0530", "-0019", "+2359"]),
+            (
+                (None, true, false),
+                ["+000000", "+053000", "-001932", "+235959"],
+            ),
+            ((None, true, true), ["Z", "+053000", "-001932", "+235959"]),
+            (
+                (Some(':'), false, false),
+                ["+00:00", "+05:30", "-00:19", "+23:59"],
+            ),
+            (
+                (Some(':'), false, true),
+                ["Z", "+05:30", "-00:19", "+23:59"],
+            ),
+            (
+                (Some(':'), true, false),
+                ["+00:00:00", "+05:30:00", "-00:19:32", "+23:59:59"],
+            ),
+            (
+                (Some(':'), true, true),
+                ["Z", "+05:30:00", "-00:19:32", "+23:59:59"],
+            ),
+        ];
+
+        for &((separator, include_seconds, zero_as_z), outputs) in &expected {
+            let options = OffsetFormatOptions {
+                separator,
+                include_seconds,
+                zero_as_z,
+            };
+            for (&offset, &output) in offsets.iter().zip(outputs.iter()) {
+                assert_eq!(render(offset, options)?, output);
+            }
+        }
+
+        // The default options are identical to `%z`.
+        for &offset in &offsets {
+            assert_eq!(
+                render(offset, OffsetFormatOptions::default())?,
+                offset.format("%z")
+            );
+        }
+
+        Ok(())
     }
 
     #[test]
@@ -617,6 +1133,182 @@ mod test {
 
         assert_eq!(UtcOffset::parse("+0001", "%z"), Ok(offset!(+0:01)));
         assert_eq!(UtcOffset::parse("-0001", "%z"), Ok(offset!(-0:01)));
+
+        assert_eq!(
+            UtcOffset::parse("+05:30", "%z"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(UtcOffset::parse("Z", "%z"), Err(ParseError::InvalidOffset));
+    }
+
+    #[test]
+    fn parse_prefix() {
+        assert_eq!(UtcOffset::parse_prefix("+05:30"), Ok((offset!(+5:30), 6)));
+        assert_eq!(UtcOffset::parse_prefix("+0530"), Ok((offset!(+5:30), 5)));
+        assert_eq!(UtcOffset::parse_prefix("-05:30"), Ok((offset!(-5:30), 6)));
+        assert_eq!(UtcOffset::parse_prefix("Z"), Ok((offset!(UTC), 1)));
+        assert_eq!(UtcOffset::parse_prefix("z"), Ok((offset!(UTC), 1)));
+        assert_eq!(UtcOffset::parse_prefix(b"+01:00abc"), Ok((offset!(+1), 6)));
+        assert_eq!(UtcOffset::parse_prefix("Zabc"), Ok((offset!(UTC), 1)));
+
+        assert_eq!(UtcOffset::parse_prefix(""), Err(ParseError::InvalidOffset));
+        assert_eq!(
+            UtcOffset::parse_prefix("05:30"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse_prefix("+24:00"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse_prefix("+05:60"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse_prefix("+05:3"),
+            Err(ParseError::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn parse_optional_prefix() {
+        assert_eq!(
+            UtcOffset::parse_optional_prefix("Z"),
+            Ok((Some(offset!(UTC)), 1))
+        );
+        assert_eq!(
+            UtcOffset::parse_optional_prefix("-05:30"),
+            Ok((Some(offset!(-5:30)), 6))
+        );
+        assert_eq!(
+            UtcOffset::parse_optional_prefix("+0100[Europe/Paris]"),
+            Ok((Some(offset!(+1)), 5))
+        );
+        assert_eq!(UtcOffset::parse_optional_prefix(""), Ok((None, 0)));
+        assert_eq!(UtcOffset::parse_optional_prefix("05:30"), Ok((None, 0)));
+        assert_eq!(
+            UtcOffset::parse_optional_prefix("[Europe/Paris]"),
+            Ok((None, 0))
+        );
+        assert_eq!(
+            UtcOffset::parse_optional_prefix("+24:00"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse_optional_prefix("-"),
+            Err(ParseError::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn parse_offset() {
+        assert_eq!(UtcOffset::parse_offset("+05:30"), Ok(offset!(+5:30)));
+        assert_eq!(UtcOffset::parse_offset("-0530"), Ok(offset!(-5:30)));
+        assert_eq!(UtcOffset::parse_offset("Z"), Ok(offset!(UTC)));
+        assert_eq!(UtcOffset::parse_offset("z"), Ok(offset!(UTC)));
+        assert_eq!(UtcOffset::parse_offset("-00:00"), Ok(offset!(UTC)));
+        assert_eq!("+01:00".parse(), Ok(offset!(+1)));
+        assert_eq!(
+            UtcOffset::parse_offset("\u{2212}05:30"),
+            UtcOffset::parse_offset("-05:30")
+        );
+        assert_eq!(UtcOffset::parse_offset("\u{ff0b}05:30"), Ok(offset!(+5:30)));
+
+        for &input in &[
+            "",
+            "+05:30 ",
+            "+05:30:00",
+            "Zabc",
+            "+0530x",
+            " +05:30",
+            "+05",
+            "05:30",
+        ] {
+            assert_eq!(
+                UtcOffset::parse_offset(input),
+                Err(ParseError::InvalidOffset),
+                "{}",
+                input
+            );
+            assert_eq!(input.parse::<UtcOffset>(), Err(ParseError::InvalidOffset));
+        }
+    }
+
+    #[test]
+    fn parse_total_minutes() {
+        assert_eq!(UtcOffset::parse_total_minutes("+330"), Ok(offset!(+5:30)));
+        assert_eq!(UtcOffset::parse_total_minutes("330"), Ok(offset!(+5:30)));
+        assert_eq!(UtcOffset::parse_total_minutes("-330"), Ok(offset!(-5:30)));
+        assert_eq!(UtcOffset::parse_total_minutes("+345"), Ok(offset!(+5:45)));
+        assert_eq!(UtcOffset::parse_total_minutes("-0"), Ok(offset!(UTC)));
+        assert_eq!(UtcOffset::parse_total_minutes("0060"), Ok(offset!(+1)));
+        assert_eq!(UtcOffset::parse_total_minutes("+1439"), Ok(offset!(+23:59)));
+        assert_eq!(UtcOffset::parse_total_minutes("-1439"), Ok(offset!(-23:59)));
+
+        for &input in &[
+            "",
+            "+",
+            "-",
+            "+1440",
+            "-1440",
+            "99999999999",
+            "+5:30",
+            "+330 ",
+            " 330",
+            "+-330",
+            "3e2",
+        ] {
+            assert_eq!(
+                UtcOffset::parse_total_minutes(input),
+                Err(ParseError::InvalidOffset),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(-offset!(+5:30), offset!(-5:30));
+        assert_eq!(-offset!(-5:30), offset!(+5:30));
+        assert_eq!(-offset!(+23:59:59), offset!(-23:59:59));
+        assert_eq!(-offset!(UTC), offset!(UTC));
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(offset!(+5:30) + 1.hours(), offset!(+6:30));
+        assert_eq!(offset!(+5:30) + (-1).hours(), offset!(+4:30));
+        assert_eq!(offset!(-0:30) + 1.hours(), offset!(+0:30));
+        assert_eq!(offset!(UTC) + 1_500.milliseconds(), offset!(+0:00:01));
+        assert_eq!(offset!(+23) + 1.hours(), offset!(+23:59:59));
+        assert_eq!(offset!(-23) + (-1).hours(), offset!(-23:59:59));
+        assert_eq!(offset!(UTC) + Duration::max_value(), offset!(+23:59:59));
+        assert_eq!(offset!(UTC) + Duration::min_value(), offset!(-23:59:59));
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut offset = offset!(+5:30);
+        offset += 1.hours();
+        assert_eq!(offset, offset!(+6:30));
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(offset!(+5:30) - 1.hours(), offset!(+4:30));
+        assert_eq!(offset!(+5:30) - (-1).hours(), offset!(+6:30));
+        assert_eq!(offset!(+0:30) - 1.hours(), offset!(-0:30));
+        assert_eq!(offset!(-23) - 1.hours(), offset!(-23:59:59));
+        assert_eq!(offset!(UTC) - Duration::max_value(), offset!(-23:59:59));
+        assert_eq!(offset!(UTC) - Duration::min_value(), offset!(+23:59:59));
+    }
+
+    #[test]
+    fn sub_assign() {
+        let mut offset = offset!(+5:30);
+        offset -= 1.hours();
+        assert_eq!(offset, offset!(+4:30));
     }
 
     #[test]